@@ -14,14 +14,165 @@ impl ManagerConfig {
 #[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
 pub struct CollectorConfig {
     pub network: NetworkKind,
-    pub client_url: String,
+    /// Every provider the collector subscribes to in parallel
+    pub client_urls: Vec<String>,
+    /// Number of providers that have to report a block/log before it's processed
+    pub quorum: usize,
+    pub connection: ConnectionConfig,
+    /// ABIs whose events are decoded into `decoded_event`
+    pub abis: Vec<AbiConfig>,
+    /// The wrapped native token whose `Deposit`/`Withdrawal` events are decoded, the network's
+    /// canonical one if unset
+    pub wrapped_token: Option<alloy_primitives::Address>,
+    /// Chainlink feeds whose aggregators' `AnswerUpdated`/`NewRound` events are decoded
+    pub price_feeds: Vec<PriceFeedConfig>,
+    /// Safes whose events (executions, owner and threshold changes, received funds) are decoded
+    pub safes: Vec<alloy_primitives::Address>,
 }
 
 impl CollectorConfig {
-    pub fn new(network: NetworkKind, client_url: String) -> Self {
+    pub fn wrapped_token(&self) -> alloy_primitives::Address {
+        self.wrapped_token
+            .unwrap_or_else(|| self.network.wrapped_native())
+    }
+
+    /// The feed `aggregator` reports the answers of, if configured.
+    pub fn price_feed(&self, aggregator: &alloy_primitives::Address) -> Option<&PriceFeedConfig> {
+        self.price_feeds
+            .iter()
+            .find(|feed| feed.aggregators.contains(aggregator))
+    }
+
+    /// Whether `address` is one of the configured Safes.
+    pub fn is_safe(&self, address: &alloy_primitives::Address) -> bool {
+        self.safes.contains(address)
+    }
+}
+
+impl From<(NetworkKind, &crate::NetworkDetail)> for CollectorConfig {
+    fn from((network, detail): (NetworkKind, &crate::NetworkDetail)) -> Self {
         Self {
             network,
-            client_url,
+            client_urls: detail.providers(),
+            quorum: detail.quorum,
+            connection: detail.connection.clone(),
+            abis: detail.abis.clone(),
+            wrapped_token: detail.wrapped_token,
+            price_feeds: detail.price_feeds.clone(),
+            safes: detail.safes.clone(),
+        }
+    }
+}
+
+/// A JSON ABI file whose events are decoded by the collector.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct AbiConfig {
+    /// Path to either a bare JSON ABI or a hardhat/foundry build artifact
+    pub path: String,
+    /// Contracts the ABI is bound to; when empty its events are decoded from any contract
+    /// emitting a matching topic0
+    #[serde(default)]
+    pub addresses: Vec<alloy_primitives::Address>,
+}
+
+/// A Chainlink price feed, e.g. `ETH/USD`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct PriceFeedConfig {
+    /// The name prices are looked up by
+    pub name: String,
+    /// Number of decimals of the answers
+    pub decimals: u8,
+    /// Every aggregator the feed's proxy has pointed to, as each emits the answers of its rounds
+    pub aggregators: Vec<alloy_primitives::Address>,
+}
+
+/// How the collector talks to the providers of a network.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ConnectionConfig {
+    /// How often `http(s)://` providers are polled for new blocks/logs, in milliseconds
+    pub poll_interval_ms: u64,
+    /// Delay before the first reconnection attempt, doubled on every consecutive failure
+    pub retry_initial_ms: u64,
+    /// Upper bound of the delay between two reconnection attempts
+    pub retry_max_delay_ms: u64,
+    /// Whether to randomize the reconnection delays, so that collectors don't reconnect in lockstep
+    pub retry_jitter: bool,
+    /// Number of consecutive failed reconnection attempts after which the provider is given up on;
+    /// retries forever if unset
+    pub max_retries: Option<usize>,
+    /// How often the websocket connection is pinged
+    pub ping_interval_ms: u64,
+    /// How long the websocket connection may go without any message before it's considered dead
+    pub inactive_limit_ms: u64,
+    /// How long a single request may take
+    pub request_timeout_ms: u64,
+    /// How long establishing a connection may take
+    pub connection_timeout_ms: u64,
+    /// Whether missed logs are backfilled from the blocks' receipts instead of `eth_getLogs`, for
+    /// providers that cap or don't serve the latter
+    pub logs_from_receipts: bool,
+    /// Which tracing API the providers serve, used when collecting traces
+    pub trace_api: TraceApi,
+}
+
+/// The JSON-RPC method call traces are requested with.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceApi {
+    /// `debug_traceBlockByNumber` with the `callTracer`, served by geth/reth/nethermind
+    #[default]
+    Debug,
+    /// `trace_block`, served by erigon/nethermind/reth
+    Parity,
+}
+
+impl ConnectionConfig {
+    pub fn poll_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn retry_initial(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.retry_initial_ms)
+    }
+
+    pub fn retry_max_delay(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.retry_max_delay_ms)
+    }
+
+    pub fn ping_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.ping_interval_ms)
+    }
+
+    pub fn inactive_limit(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.inactive_limit_ms)
+    }
+
+    pub fn request_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.request_timeout_ms)
+    }
+
+    pub fn connection_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.connection_timeout_ms)
+    }
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: 2000,
+            retry_initial_ms: 500,
+            retry_max_delay_ms: 30_000,
+            retry_jitter: true,
+            max_retries: None,
+            ping_interval_ms: 6000,
+            inactive_limit_ms: 30_000,
+            request_timeout_ms: 60_000,
+            connection_timeout_ms: 10_000,
+            logs_from_receipts: false,
+            trace_api: TraceApi::Debug,
         }
     }
 }
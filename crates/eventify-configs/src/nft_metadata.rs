@@ -0,0 +1,65 @@
+/// The worker fetching the JSON metadata ERC721 `tokenURI`s and ERC1155 `URI`s point to.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NftMetadataConfig {
+    /// IPFS gateways `ipfs://` URIs are fetched through, in order, e.g. `https://ipfs.io/ipfs/`
+    pub gateways: Vec<String>,
+    /// How long fetching a single URI may take, in milliseconds
+    pub timeout_ms: u64,
+    /// Largest metadata document accepted, in bytes
+    pub max_size_bytes: usize,
+    /// Number of failed attempts after which a token's metadata is given up on
+    pub max_attempts: u32,
+    /// Delay before retrying a failed fetch, doubled on every consecutive failure
+    pub retry_initial_ms: u64,
+    /// Upper bound of the delay between two attempts
+    pub retry_max_delay_ms: u64,
+    /// How often tokens without metadata are looked for when there were none left
+    pub poll_interval_ms: u64,
+    /// Number of tokens whose URI is resolved or whose metadata is fetched per round
+    pub batch_size: i64,
+    /// Number of URIs fetched in parallel
+    pub concurrency: usize,
+    /// Whether token URIs may point to loopback, link-local and private addresses, which they're
+    /// kept from by default; the gateways are reached wherever they are
+    pub allow_private_targets: bool,
+}
+
+impl NftMetadataConfig {
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.timeout_ms)
+    }
+
+    pub fn poll_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.poll_interval_ms)
+    }
+
+    /// The delay before the attempt following `attempts` failed ones.
+    pub fn retry_delay(&self, attempts: u32) -> std::time::Duration {
+        let delay = self
+            .retry_initial_ms
+            .saturating_mul(1 << attempts.saturating_sub(1).min(32));
+
+        std::time::Duration::from_millis(delay.min(self.retry_max_delay_ms))
+    }
+}
+
+impl Default for NftMetadataConfig {
+    fn default() -> Self {
+        Self {
+            gateways: vec![
+                "https://ipfs.io/ipfs/".to_string(),
+                "https://dweb.link/ipfs/".to_string(),
+            ],
+            timeout_ms: 10_000,
+            max_size_bytes: 1 << 20,
+            max_attempts: 5,
+            retry_initial_ms: 60_000,
+            retry_max_delay_ms: 3_600_000,
+            poll_interval_ms: 5_000,
+            batch_size: 100,
+            concurrency: 8,
+            allow_private_targets: false,
+        }
+    }
+}
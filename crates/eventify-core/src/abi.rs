@@ -0,0 +1,299 @@
+//! Decoding of logs against user supplied ABIs, registered either in the collector's config or,
+//! when indexing, in the `abi` table the API writes to.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+#[cfg(feature = "index")]
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::{Address, B256};
+#[cfg(feature = "index")]
+use sqlx::PgPool;
+use tracing::debug;
+#[cfg(feature = "index")]
+use tracing::warn;
+
+use crate::metrics;
+use eventify_configs::core::{AbiConfig, CollectorConfig};
+use eventify_primitives::{
+    abi::{Abi, AbiError, Event},
+    events::decoded::DecodedEvent,
+    networks::{core::CoreLog, NetworkKind},
+};
+
+/// How often the ABIs registered through the API are reloaded.
+#[cfg(feature = "index")]
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Events by the contract they're bound to and by topic0 alone.
+///
+/// Several events may share a key (e.g. the ERC20 and ERC721 `Transfer`, which differ only in
+/// which parameters are indexed), the first one a log decodes against wins.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    bound: HashMap<(Address, B256), Vec<Arc<Event>>>,
+    global: HashMap<B256, Vec<Arc<Event>>>,
+}
+
+impl Registry {
+    /// Reads the configured ABI files.
+    pub fn from_configs(configs: &[AbiConfig]) -> eyre::Result<Self> {
+        let mut registry = Self::default();
+        for config in configs {
+            let json = std::fs::read_to_string(&config.path)
+                .map_err(|e| eyre::eyre!("failed to read ABI {}: {}", config.path, e))?;
+            let abi = Abi::from_json(&json)
+                .map_err(|e| eyre::eyre!("failed to parse ABI {}: {}", config.path, e))?;
+            registry.add(abi, &config.addresses);
+        }
+
+        Ok(registry)
+    }
+
+    /// Registers the events of `abi` for the given contracts, or for any contract if empty.
+    ///
+    /// Anonymous events are skipped, there's no topic0 to recognize their logs by.
+    pub fn add(&mut self, abi: Abi, addresses: &[Address]) {
+        for event in abi.events.into_iter().filter(|e| !e.anonymous) {
+            let selector = event.selector();
+            let event = Arc::new(event);
+
+            if addresses.is_empty() {
+                push(self.global.entry(selector).or_default(), &event);
+            }
+            for address in addresses {
+                push(self.bound.entry((*address, selector)).or_default(), &event);
+            }
+        }
+    }
+
+    /// Decodes `log` against the events bound to its contract first, the global ones otherwise.
+    pub fn decode(&self, network: NetworkKind, log: &CoreLog) -> Option<DecodedEvent> {
+        let selector = *log.topics.first()?;
+        let bound = self.bound.get(&(log.address, selector));
+        let global = self.global.get(&selector);
+
+        for event in bound.into_iter().chain(global).flatten() {
+            match DecodedEvent::new(network, log, event) {
+                Ok(decoded) => return Some(decoded),
+                Err(err) => {
+                    debug!(kind = "abi_decode", signature = event.signature(), tx_hash = ?log.tx_hash, ?err);
+                    // the signatures come from the registered ABIs, they'd make for an unbounded
+                    // number of series
+                    let reason = match err {
+                        AbiError::NonCanonical(_) => "non_canonical",
+                        _ => "decode",
+                    };
+                    metrics::ABI_DECODE_FAILURES
+                        .with_label_values(&[&network.to_string(), reason])
+                        .inc();
+                }
+            }
+        }
+
+        None
+    }
+
+    #[cfg(feature = "index")]
+    fn merge(&mut self, other: &Registry) {
+        for (key, events) in &other.bound {
+            self.bound
+                .entry(*key)
+                .or_default()
+                .extend(events.iter().cloned());
+        }
+        for (key, events) in &other.global {
+            self.global
+                .entry(*key)
+                .or_default()
+                .extend(events.iter().cloned());
+        }
+    }
+}
+
+fn push(events: &mut Vec<Arc<Event>>, event: &Arc<Event>) {
+    if !events.contains(event) {
+        events.push(event.clone());
+    }
+}
+
+/// The registry of a network's collector.
+#[derive(Debug, Clone)]
+pub struct Abis {
+    network: NetworkKind,
+    #[cfg(feature = "index")]
+    configured: Arc<Registry>,
+    registry: Arc<RwLock<Arc<Registry>>>,
+
+    #[cfg(feature = "index")]
+    pool: PgPool,
+    #[cfg(feature = "index")]
+    refreshed: Arc<Mutex<Option<Instant>>>,
+}
+
+impl Abis {
+    pub fn new(
+        config: &CollectorConfig,
+        #[cfg(feature = "index")] pool: PgPool,
+    ) -> eyre::Result<Self> {
+        let configured = Arc::new(Registry::from_configs(&config.abis)?);
+
+        Ok(Self {
+            network: config.network,
+            registry: Arc::new(RwLock::new(configured.clone())),
+            #[cfg(feature = "index")]
+            configured,
+            #[cfg(feature = "index")]
+            pool,
+            #[cfg(feature = "index")]
+            refreshed: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Decodes `log` if any of the registered ABIs has a matching event.
+    pub async fn decode(&self, log: &CoreLog) -> Option<DecodedEvent> {
+        #[cfg(feature = "index")]
+        self.refresh().await;
+
+        let registry = self.registry.read().expect("abi registry poisoned").clone();
+        registry.decode(self.network, log)
+    }
+
+    /// Reloads the ABIs registered through the API once they're older than
+    /// [`REFRESH_INTERVAL`], keeping the previous ones if the table can't be read.
+    #[cfg(feature = "index")]
+    async fn refresh(&self) {
+        {
+            let mut refreshed = self.refreshed.lock().expect("abi refresh poisoned");
+            if refreshed.is_some_and(|at| at.elapsed() < REFRESH_INTERVAL) {
+                return;
+            }
+            *refreshed = Some(Instant::now());
+        }
+
+        match load(&self.pool, self.network).await {
+            Ok(stored) => {
+                let mut registry = (*self.configured).clone();
+                registry.merge(&stored);
+                *self.registry.write().expect("abi registry poisoned") = Arc::new(registry);
+            }
+            Err(err) => {
+                warn!(kind = "abi_refresh", network = ?self.network, ?err);
+            }
+        }
+    }
+}
+
+/// The ABIs of the `abi` table applying to `network`, skipping those that don't parse.
+#[cfg(feature = "index")]
+pub async fn load(pool: &PgPool, network: NetworkKind) -> Result<Registry, sqlx::Error> {
+    let rows: Vec<(i32, Option<Vec<u8>>, String)> = sqlx::query_as(
+        "SELECT id, address, abi::text FROM abi WHERE network = $1 OR network IS NULL",
+    )
+    .bind(network)
+    .fetch_all(pool)
+    .await?;
+
+    let mut registry = Registry::default();
+    for (id, address, json) in rows {
+        let address = match address {
+            Some(address) if address.len() == 20 => vec![Address::from_slice(&address)],
+            Some(_) => {
+                warn!(kind = "abi_refresh", id, "invalid address");
+                continue;
+            }
+            None => vec![],
+        };
+        match Abi::from_json(&json) {
+            Ok(abi) => registry.add(abi, &address),
+            Err(err) => warn!(kind = "abi_refresh", id, ?err),
+        }
+    }
+
+    Ok(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U256};
+
+    use super::*;
+
+    const TRANSFERS: &str = r#"[
+        {"type": "event", "name": "Transfer", "inputs": [
+            {"name": "from", "type": "address", "indexed": true},
+            {"name": "to", "type": "address", "indexed": true},
+            {"name": "value", "type": "uint256", "indexed": false}
+        ]}
+    ]"#;
+
+    const NFT_TRANSFERS: &str = r#"[
+        {"type": "event", "name": "Transfer", "inputs": [
+            {"name": "from", "type": "address", "indexed": true},
+            {"name": "to", "type": "address", "indexed": true},
+            {"name": "tokenId", "type": "uint256", "indexed": true}
+        ]}
+    ]"#;
+
+    fn transfer(address: Address, indexed_value: bool) -> CoreLog {
+        let event = &Abi::from_json(TRANSFERS).unwrap().events[0];
+        let value = B256::from(U256::from(42));
+        let mut topics = vec![
+            event.selector(),
+            B256::left_padding_from(&[0x11; 20]),
+            B256::left_padding_from(&[0x22; 20]),
+        ];
+        let mut data = Bytes::default();
+        if indexed_value {
+            topics.push(value);
+        } else {
+            data = Bytes::from(value.to_vec());
+        }
+
+        CoreLog {
+            address,
+            topics,
+            data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decode_against_matching_layout() {
+        let mut registry = Registry::default();
+        registry.add(Abi::from_json(TRANSFERS).unwrap(), &[]);
+        registry.add(Abi::from_json(NFT_TRANSFERS).unwrap(), &[]);
+
+        let token = registry
+            .decode(NetworkKind::Ethereum, &transfer(Address::ZERO, false))
+            .unwrap();
+        assert_eq!(token.params["value"], "42");
+
+        let nft = registry
+            .decode(NetworkKind::Ethereum, &transfer(Address::ZERO, true))
+            .unwrap();
+        assert_eq!(nft.params["tokenId"], "42");
+    }
+
+    #[test]
+    fn bound_abis_only_apply_to_their_contracts() {
+        let nft = Address::repeat_byte(0xaa);
+        let mut registry = Registry::default();
+        registry.add(Abi::from_json(NFT_TRANSFERS).unwrap(), &[nft]);
+
+        assert!(registry
+            .decode(NetworkKind::Ethereum, &transfer(nft, true))
+            .is_some());
+        assert!(registry
+            .decode(NetworkKind::Ethereum, &transfer(Address::ZERO, true))
+            .is_none());
+        assert!(registry
+            .decode(NetworkKind::Ethereum, &transfer(nft, false))
+            .is_none());
+    }
+}
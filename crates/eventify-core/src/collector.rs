@@ -1,22 +1,59 @@
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
-use alloy_primitives::{Address, FixedBytes, U256};
-use alloy_sol_types::SolEvent;
+use alloy_primitives::{Address, FixedBytes, B256, U256, U64};
+use alloy_sol_types::{
+    sol_data::{Array, Uint},
+    SolEvent, SolType,
+};
+use futures::StreamExt;
+use reconnecting_jsonrpsee_ws_client::RpcError;
+use serde_json::value::RawValue;
 #[cfg(feature = "index")]
 use sqlx::PgPool;
 use tokio::sync::{mpsc, watch};
-use tracing::{info, trace, warn};
+use tracing::{
+    debug, field, info, info_span, instrument::Instrumented, trace, warn, Instrument, Span,
+};
 
-use crate::{networks::NetworkClient, CollectT, NetworkT};
-use eventify_configs::core::CollectorConfig;
+use crate::{
+    abi::Abis,
+    metrics,
+    providers::{Providers, Quorum, Seen},
+    token::Tokens,
+    CollectT, NetworkResource, NetworkT,
+};
 #[cfg(feature = "index")]
-use eventify_primitives::InsertT;
+use crate::{
+    event_sig::{self, EventSigs},
+    fn_sig::FnSigs,
+};
+use eventify_configs::core::{CollectorConfig, TraceApi};
+#[cfg(feature = "propagate")]
+use eventify_primitives::networks::Alert;
+#[cfg(feature = "index")]
+use eventify_primitives::{events::decoded::DecodedEvent, InsertT};
 use eventify_primitives::{
-    events::{ERC1155, ERC20, ERC4626, ERC721, ERC777},
-    networks::{Logs, Resource},
-    BlockT as _, LogT,
+    events::{
+        chainlink::PriceUpdate, entry_point::ENTRY_POINTS, safe::SAFE_PROXY_FACTORIES,
+        AccessControl, Chainlink, ERC6551Registry, Emitted, EntryPoint, Ownable, Permit2, Safe,
+        UniswapV2, UniswapV3, EIP1967, ERC1155, ERC20, ERC4626, ERC4906, ERC5192, ERC721, ERC777,
+        WETH,
+    },
+    networks::{
+        trace::{BlockCallTrace, ParityTrace, Trace},
+        LogKind, Logs, Resource,
+    },
+    token::Standard,
+    BlockT as _, LogT, ReceiptT as _, TransactionT as _,
 };
 
+/// Number of blocks/logs remembered for deduplicating the items delivered by several providers.
+const DEDUP_CAPACITY: usize = 8192;
+
+/// Maximum amount of blocks whose logs are requested with a single `eth_getLogs` call while
+/// backfilling.
+const MAX_BACKFILL_RANGE: u64 = 100;
+
 #[derive(Debug, Clone)]
 pub struct Collector<N>
 where
@@ -24,13 +61,19 @@ where
 {
     #[allow(unused)]
     config: CollectorConfig,
-    node: N,
+    providers: Providers<N>,
+    abis: Abis,
+    tokens: Tokens,
 
     #[cfg(feature = "index")]
     pool: PgPool,
+    #[cfg(feature = "index")]
+    fn_sigs: FnSigs,
+    #[cfg(feature = "index")]
+    event_sigs: EventSigs,
 
     #[cfg(feature = "propagate")]
-    queue_rx: mpsc::Sender<Resource<N::Block, N::Log>>,
+    queue_rx: mpsc::Sender<Instrumented<NetworkResource<N>>>,
 }
 
 impl<N> Collector<N>
@@ -41,14 +84,35 @@ where
         config: CollectorConfig,
         #[cfg(feature = "index")] pool: PgPool,
 
-        #[cfg(feature = "propagate")] queue_rx: mpsc::Sender<Resource<N::Block, N::Log>>,
+        #[cfg(feature = "propagate")] queue_rx: mpsc::Sender<Instrumented<NetworkResource<N>>>,
     ) -> eyre::Result<Self> {
-        let client = NetworkClient::new(config.client_url.clone()).await?;
-        let node = N::new(client);
+        let providers = Providers::connect(
+            config.network.to_string(),
+            &config.client_urls,
+            &config.connection,
+            config.quorum,
+        )
+        .await?;
+        let abis = Abis::new(
+            &config,
+            #[cfg(feature = "index")]
+            pool.clone(),
+        )?;
+        let tokens = Tokens::new(
+            config.network,
+            #[cfg(feature = "index")]
+            pool.clone(),
+        );
 
         Ok(Self {
             config,
-            node,
+            providers,
+            abis,
+            tokens,
+            #[cfg(feature = "index")]
+            fn_sigs: FnSigs::new(pool.clone()),
+            #[cfg(feature = "index")]
+            event_sigs: EventSigs::new(pool.clone()),
             #[cfg(feature = "index")]
             pool,
             #[cfg(feature = "propagate")]
@@ -57,88 +121,745 @@ where
     }
 }
 
-impl<N> CollectT<crate::Error> for Collector<N>
+impl<N> Collector<N>
 where
     N: NetworkT,
 {
-    async fn stream_blocks(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
-        let mut stream = self.node.sub_blocks().await?;
-        info!(subscribed = true, kind = "blocks");
+    /// Inserts/propagates a block once it's been accepted.
+    async fn process_block(&self, block: N::Block, span: Span) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        if let Some(number) = block.core().number {
+            span.record("number", number.to::<u64>());
+        }
+        if let Some(hash) = block.core().hash {
+            span.record("hash", field::display(hash));
+        }
 
-        while let Some(block) = stream.next().await {
-            if stop_signal.has_changed()? {
-                break;
+        info!(parent: &span, kind="block", number=?block.core().number, hash=?block.core().hash);
+        metrics::BLOCKS_RECEIVED
+            .with_label_values(&[&network])
+            .inc();
+        observe_head(&network, &block);
+
+        #[cfg(feature = "index")]
+        {
+            let timer = metrics::INSERT_DURATION
+                .with_label_values(&[&network, "block"])
+                .start_timer();
+            let inserted = block
+                .insert(&self.pool, &None)
+                .instrument(info_span!(parent: &span, "insert"))
+                .await;
+            timer.observe_duration();
+            if inserted.is_err() {
+                metrics::INSERT_ERRORS
+                    .with_label_values(&[&network, "block"])
+                    .inc();
             }
+            inserted?;
+        }
 
-            trace!(block=?block);
-            let block = match block {
-                Ok(block) => serde_json::from_str::<<N as NetworkT>::Block>(block.get())?,
+        #[cfg(feature = "propagate")]
+        {
+            match self
+                .queue_rx
+                .send(Resource::Block(block).instrument(span))
+                .await
+            {
+                Ok(_) => {}
                 Err(err) => {
-                    warn!(kind="block_error", err=?err);
-                    continue;
+                    warn!(kind="propagate_error", err=?err);
                 }
-            };
-            info!(kind="block", number=?block.core().number, hash=?block.core().hash);
+            }
+            observe_channel_depth(&network, &self.queue_rx);
+        }
+
+        Ok(())
+    }
+
+    /// Decodes and inserts/propagates a log once it's been accepted, along with its decoding
+    /// against the registered ABIs if any matches.
+    async fn process_log(&self, log: N::Log, span: Span) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let mut event = info_span!(parent: &span, "decode")
+            .in_scope(|| match_events(log.clone(), &self.config));
+        let decoded = self
+            .abis
+            .decode(log.core())
+            .instrument(info_span!(parent: &span, "abi_decode"))
+            .await;
+        self.with_token(&mut event)
+            .instrument(info_span!(parent: &span, "token"))
+            .await;
+        #[cfg(feature = "index")]
+        let (event, decoded) = match event {
+            Logs::Raw(mut raw) => {
+                let inferred = self.with_event_sig(&mut raw, &span).await;
+                (Logs::Raw(raw), decoded.or(inferred))
+            }
+            event => (event, decoded),
+        };
+        if let Some(number) = log.core().block_number {
+            span.record("block_number", number.to::<u64>());
+        }
+        if let Some(tx_hash) = log.core().tx_hash {
+            span.record("tx_hash", field::display(tx_hash));
+        }
+        span.record("kind", field::display(LogKind::from(&event)));
+
+        metrics::LOGS_RECEIVED.with_label_values(&[&network]).inc();
+
+        for event in std::iter::once(event).chain(decoded.map(Logs::Decoded)) {
+            metrics::EVENTS_DECODED
+                .with_label_values(&[&network, &LogKind::from(&event).to_string()])
+                .inc();
 
             #[cfg(feature = "index")]
             {
-                block
-                    .insert(&self.pool, &self.config.network.to_string(), &None)
-                    .await?;
+                let timer = metrics::INSERT_DURATION
+                    .with_label_values(&[&network, "log"])
+                    .start_timer();
+                let inserted = event
+                    .insert(&self.pool, &log.core().tx_hash)
+                    .instrument(info_span!(parent: &span, "insert"))
+                    .await;
+                timer.observe_duration();
+                if inserted.is_err() {
+                    metrics::INSERT_ERRORS
+                        .with_label_values(&[&network, "log"])
+                        .inc();
+                }
+                inserted?;
             }
 
             #[cfg(feature = "propagate")]
             {
-                match self.queue_rx.send(Resource::Block(block)).await {
+                let alert = Alert::new(&event);
+                for rsrc in std::iter::once(Resource::Log(event)).chain(alert.map(Resource::Alert))
+                {
+                    match self.queue_rx.send(rsrc.instrument(span.clone())).await {
+                        Ok(_) => {}
+                        Err(err) => {
+                            warn!(kind="propagate_error", err=?err);
+                        }
+                    }
+                }
+                observe_channel_depth(&network, &self.queue_rx);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the transactions of a block once it's been accepted, inserting/propagating each.
+    async fn process_transactions(&self, block: N::Block, span: Span) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let Some(hash) = block.core().hash else {
+            return Ok(());
+        };
+        span.record("hash", field::display(hash));
+        if let Some(number) = block.core().number {
+            span.record("number", number.to::<u64>());
+        }
+
+        let transactions = match self
+            .providers
+            .healthiest()
+            .client()
+            .transactions(hash)
+            .instrument(info_span!(parent: &span, "fetch"))
+            .await
+        {
+            Ok(transactions) => transactions,
+            Err(err) => {
+                warn!(parent: &span, kind = "fetch_error", resource = "transaction", err = %err);
+                metrics::FETCH_ERRORS
+                    .with_label_values(&[&network, "transaction"])
+                    .inc();
+                return Ok(());
+            }
+        };
+        info!(parent: &span, kind="transactions", number=?block.core().number, count=transactions.len());
+
+        for tx in transactions {
+            let tx = serde_json::from_str::<<N as NetworkT>::Transaction>(tx.get())?;
+            let tx_span = info_span!(
+                parent: &span,
+                "transaction",
+                hash = %tx.core().hash,
+                fn_sig = field::Empty
+            );
+            #[cfg(feature = "index")]
+            let tx = self.with_fn_sig(tx, &tx_span).await;
+            metrics::TRANSACTIONS_RECEIVED
+                .with_label_values(&[&network])
+                .inc();
+
+            #[cfg(feature = "index")]
+            {
+                let timer = metrics::INSERT_DURATION
+                    .with_label_values(&[&network, "transaction"])
+                    .start_timer();
+                let inserted = tx
+                    .insert(&self.pool, &Some(tx.core().hash))
+                    .instrument(info_span!(parent: &tx_span, "insert"))
+                    .await;
+                timer.observe_duration();
+                if inserted.is_err() {
+                    metrics::INSERT_ERRORS
+                        .with_label_values(&[&network, "transaction"])
+                        .inc();
+                }
+                inserted?;
+            }
+
+            #[cfg(feature = "propagate")]
+            {
+                match self
+                    .queue_rx
+                    .send(Resource::Transaction(tx).instrument(tx_span))
+                    .await
+                {
                     Ok(_) => {}
                     Err(err) => {
                         warn!(kind="propagate_error", err=?err);
                     }
                 }
+                observe_channel_depth(&network, &self.queue_rx);
             }
         }
 
         Ok(())
     }
 
-    async fn stream_logs(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
-        let mut stream = self.node.sub_logs().await?;
-        info!(subscribed = true, kind = "logs");
+    /// Fetches the receipts of a block once it's been accepted, inserting/propagating each.
+    async fn process_receipts(&self, block: N::Block, span: Span) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let Some(number) = block.core().number.map(|n| n.to::<u64>()) else {
+            return Ok(());
+        };
+        span.record("number", number);
+        if let Some(hash) = block.core().hash {
+            span.record("hash", field::display(hash));
+        }
 
-        while let Some(log) = stream.next().await {
-            if stop_signal.has_changed()? {
-                break;
+        let receipts = match self
+            .receipts(number)
+            .instrument(info_span!(parent: &span, "fetch"))
+            .await
+        {
+            Ok(receipts) => receipts,
+            Err(err) => {
+                warn!(parent: &span, kind = "fetch_error", resource = "receipt", err = %err);
+                metrics::FETCH_ERRORS
+                    .with_label_values(&[&network, "receipt"])
+                    .inc();
+                return Ok(());
             }
+        };
+        info!(parent: &span, kind="receipts", number, count=receipts.len());
 
-            trace!(log=?log);
-            let log = match log {
-                Ok(log) => serde_json::from_str::<<N as NetworkT>::Log>(log.get())?,
-                Err(err) => {
-                    warn!(kind="log_error", err=?err);
-                    continue;
+        for receipt in receipts {
+            let receipt = serde_json::from_str::<<N as NetworkT>::Receipt>(receipt.get())?;
+            let receipt_span = info_span!(
+                parent: &span,
+                "receipt",
+                tx_hash = %receipt.core().tx_hash,
+                status = ?receipt.core().status
+            );
+            metrics::RECEIPTS_RECEIVED
+                .with_label_values(&[&network])
+                .inc();
+
+            #[cfg(feature = "index")]
+            {
+                let timer = metrics::INSERT_DURATION
+                    .with_label_values(&[&network, "receipt"])
+                    .start_timer();
+                let inserted = receipt
+                    .insert(&self.pool, &Some(receipt.core().tx_hash))
+                    .instrument(info_span!(parent: &receipt_span, "insert"))
+                    .await;
+                timer.observe_duration();
+                if inserted.is_err() {
+                    metrics::INSERT_ERRORS
+                        .with_label_values(&[&network, "receipt"])
+                        .inc();
                 }
-            };
-            let event = match_events(log.clone());
+                inserted?;
+            }
+
+            #[cfg(feature = "propagate")]
+            {
+                match self
+                    .queue_rx
+                    .send(Resource::Receipt(receipt).instrument(receipt_span))
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(err) => {
+                        warn!(kind="propagate_error", err=?err);
+                    }
+                }
+                observe_channel_depth(&network, &self.queue_rx);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Traces the transactions of a block once it's been accepted, inserting/propagating every
+    /// call of their call trees.
+    async fn process_traces(&self, block: N::Block, span: Span) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let Some(number) = block.core().number.map(|n| n.to::<u64>()) else {
+            return Ok(());
+        };
+        span.record("number", number);
+        if let Some(hash) = block.core().hash {
+            span.record("hash", field::display(hash));
+        }
+
+        let traces = match self
+            .traces(number)
+            .instrument(info_span!(parent: &span, "fetch"))
+            .await
+        {
+            Ok(traces) => traces,
+            Err(err) => {
+                warn!(parent: &span, kind = "fetch_error", resource = "trace", err = %err);
+                metrics::FETCH_ERRORS
+                    .with_label_values(&[&network, "trace"])
+                    .inc();
+                return Ok(());
+            }
+        };
+        info!(parent: &span, kind="traces", number, count=traces.len());
+
+        for trace in traces {
+            let trace_span = info_span!(
+                parent: &span,
+                "trace",
+                tx_hash = %trace.tx_hash,
+                trace_address = ?trace.trace_address
+            );
+            metrics::TRACES_RECEIVED
+                .with_label_values(&[&network])
+                .inc();
 
             #[cfg(feature = "index")]
             {
-                event
-                    .insert(
-                        &self.pool,
-                        &self.config.network.to_string(),
-                        &log.core().tx_hash,
-                    )
-                    .await?;
+                let timer = metrics::INSERT_DURATION
+                    .with_label_values(&[&network, "trace"])
+                    .start_timer();
+                let inserted = trace
+                    .insert(&self.pool, &Some(trace.tx_hash))
+                    .instrument(info_span!(parent: &trace_span, "insert"))
+                    .await;
+                timer.observe_duration();
+                if inserted.is_err() {
+                    metrics::INSERT_ERRORS
+                        .with_label_values(&[&network, "trace"])
+                        .inc();
+                }
+                inserted?;
             }
 
             #[cfg(feature = "propagate")]
             {
-                match self.queue_rx.send(Resource::Log(event)).await {
+                match self
+                    .queue_rx
+                    .send(Resource::Trace(trace).instrument(trace_span))
+                    .await
+                {
                     Ok(_) => {}
                     Err(err) => {
                         warn!(kind="propagate_error", err=?err);
                     }
                 }
+                observe_channel_depth(&network, &self.queue_rx);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Every call made in the block `number`, flattened.
+    async fn traces(&self, number: u64) -> crate::Result<Vec<Trace>> {
+        let network = self.config.network;
+        let api = self.config.connection.trace_api;
+        let client = self.providers.healthiest().client();
+        let raw = client.traces(number, api).await?;
+
+        let mut traces = vec![];
+        match api {
+            TraceApi::Debug => {
+                // older nodes don't include the hashes, the traces are in the block's order
+                let mut hashes = None;
+                for (i, trace) in raw.iter().enumerate() {
+                    let trace = serde_json::from_str::<BlockCallTrace>(trace.get())?;
+                    let Some(frame) = trace.result else {
+                        warn!(kind = "trace_error", number, index = i, err = ?trace.error);
+                        continue;
+                    };
+                    let tx_hash = match trace.tx_hash {
+                        Some(hash) => hash,
+                        None => {
+                            if hashes.is_none() {
+                                hashes = Some(client.transaction_hashes(number).await?);
+                            }
+                            match hashes.as_ref().and_then(|h| h.get(i)) {
+                                Some(hash) => *hash,
+                                None => continue,
+                            }
+                        }
+                    };
+                    traces.extend(Trace::flatten(network, U64::from(number), tx_hash, &frame));
+                }
+            }
+            TraceApi::Parity => {
+                for trace in raw {
+                    let trace = serde_json::from_str::<ParityTrace>(trace.get())?;
+                    traces.extend(Trace::from_parity(network, &trace));
+                }
+            }
+        }
+
+        Ok(traces)
+    }
+
+    /// The receipts of the block `number`, through `eth_getBlockReceipts` when the provider
+    /// serves it, otherwise requested one transaction at a time.
+    async fn receipts(&self, number: u64) -> Result<Vec<Box<RawValue>>, RpcError> {
+        let client = self.providers.healthiest().client();
+        match client.block_receipts(number).await {
+            Ok(receipts) => return Ok(receipts),
+            Err(err) => {
+                debug!(kind = "receipt_fallback", number, err = %err);
+                metrics::RECEIPT_FALLBACKS
+                    .with_label_values(&[&self.config.network.to_string()])
+                    .inc();
+            }
+        }
+
+        let mut receipts = vec![];
+        for hash in client.transaction_hashes(number).await? {
+            match client.receipt(hash).await? {
+                Some(receipt) => receipts.push(receipt),
+                None => warn!(kind = "missing_receipt", number, tx_hash = %hash),
+            }
+        }
+
+        Ok(receipts)
+    }
+
+    /// Every log emitted in the blocks `from..=to`, taken from their receipts when
+    /// `logs_from_receipts` is set, otherwise through `eth_getLogs`.
+    async fn logs(&self, from: u64, to: u64) -> Result<Vec<Box<RawValue>>, RpcError> {
+        if !self.config.connection.logs_from_receipts {
+            return self.providers.healthiest().client().logs(from, to).await;
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ReceiptLogs {
+            logs: Vec<Box<RawValue>>,
+        }
+
+        let mut logs = vec![];
+        for number in from..=to {
+            for receipt in self.receipts(number).await? {
+                let receipt = serde_json::from_str::<ReceiptLogs>(receipt.get())
+                    .map_err(RpcError::ParseError)?;
+                logs.extend(receipt.logs);
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /// Attaches the metadata of the emitting contract to the events of token contracts,
+    /// resolving it the first time the contract is seen, along with the ERC20 amounts in the
+    /// token's display unit.
+    async fn with_token(&self, event: &mut Logs<N::Log>) {
+        let client = self.providers.healthiest().client();
+        match event {
+            Logs::ERC20_Transfer(e) => {
+                let token = self
+                    .tokens
+                    .resolve(client, e.address, Standard::Erc20)
+                    .await;
+                e.formatted_value = token.format(e.event.value);
+                e.token = Some(token);
+            }
+            Logs::ERC20_Approval(e) => {
+                let token = self
+                    .tokens
+                    .resolve(client, e.address, Standard::Erc20)
+                    .await;
+                e.formatted_value = token.format(e.event.value);
+                e.token = Some(token);
+            }
+            Logs::ERC721_Transfer(e) => {
+                e.token = Some(
+                    self.tokens
+                        .resolve(client, e.address, Standard::Erc721)
+                        .await,
+                )
+            }
+            Logs::ERC1155_TransferSingle(e) => {
+                e.token = Some(
+                    self.tokens
+                        .resolve(client, e.address, Standard::Erc1155)
+                        .await,
+                )
+            }
+            Logs::ERC1155_TransferBatch(e) => {
+                e.token = Some(
+                    self.tokens
+                        .resolve(client, e.address, Standard::Erc1155)
+                        .await,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    /// Labels a log no known event matched with the signature its topic0 resolves to, leaving
+    /// it unset if the lookup fails, and decodes it against that signature when its layout is
+    /// unambiguous.
+    #[cfg(feature = "index")]
+    async fn with_event_sig(&self, log: &mut N::Log, span: &Span) -> Option<DecodedEvent> {
+        match self.event_sigs.resolve(log.core()).await {
+            Ok(event_sig) => {
+                if let Some(event_sig) = &event_sig {
+                    span.record("event_sig", event_sig.as_str());
+                }
+                log.core_mut().event_sig = event_sig;
+            }
+            Err(err) => warn!(parent: span, kind = "event_sig_error", err = %err),
+        }
+
+        let event_sig = log.core().event_sig.as_deref()?;
+        event_sig::decode(self.config.network, log.core(), event_sig)
+    }
+
+    /// Resolves the function the transaction calls, leaving it unset if the lookup fails.
+    #[cfg(feature = "index")]
+    async fn with_fn_sig(&self, mut tx: N::Transaction, span: &Span) -> N::Transaction {
+        match self.fn_sigs.resolve(&tx.core().input).await {
+            Ok(fn_sig) => {
+                if let Some(fn_sig) = &fn_sig {
+                    span.record("fn_sig", fn_sig.as_str());
+                }
+                tx.core_mut().fn_sig = fn_sig;
+            }
+            Err(err) => warn!(parent: span, kind = "fn_sig_error", err = %err),
+        }
+
+        tx
+    }
+
+    async fn process_head(&self, block: N::Block, span: Span, heads: Heads) -> crate::Result<()> {
+        match heads {
+            Heads::Blocks => self.process_block(block, span).await,
+            Heads::Transactions => self.process_transactions(block, span).await,
+            Heads::Receipts => self.process_receipts(block, span).await,
+            Heads::Traces => self.process_traces(block, span).await,
+        }
+    }
+
+    /// Follows the new heads, processing every accepted one as `heads`.
+    async fn follow_heads(
+        &self,
+        stop_signal: watch::Receiver<bool>,
+        heads: Heads,
+    ) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let mut stream = self.providers.sub_blocks().await?;
+        info!(
+            subscribed = true,
+            kind = heads.label(),
+            providers = self.providers.len()
+        );
+
+        let mut quorum = Quorum::new(self.config.quorum, DEDUP_CAPACITY);
+        let mut accepted: BTreeMap<u64, B256> = BTreeMap::new();
+        let mut gaps = Gaps::default();
+
+        while let Some((provider, block)) = stream.next().await {
+            if stop_signal.has_changed()? {
+                return Ok(());
+            }
+
+            trace!(block=?block);
+            let block = match block {
+                Ok(block) => block,
+                Err(err) => {
+                    self.providers.record_error(provider, heads.label(), &err.0);
+                    continue;
+                }
+            };
+            let span = info_span!(
+                "block",
+                network = %network,
+                number = field::Empty,
+                hash = field::Empty
+            );
+            let block = info_span!(parent: &span, "decode")
+                .in_scope(|| serde_json::from_str::<<N as NetworkT>::Block>(block.get()))?;
+
+            let number = block.core().number.map(|n| n.to::<u64>());
+            let key = (number, block.core().hash);
+            self.providers
+                .record_delivery(provider, number, quorum.is_new(&key));
+            if let (Some(number), Some(hash)) = key {
+                if accepted.get(&number).is_some_and(|h| *h != hash) {
+                    let name = self.providers.name(provider);
+                    warn!(kind="provider_disagreement", provider=name, number, hash=?hash);
+                    metrics::PROVIDER_DISAGREEMENTS
+                        .with_label_values(&[&network, &name])
+                        .inc();
+                }
+            }
+            match quorum.observe(key, provider) {
+                Seen::Accepted => {}
+                Seen::Pending => continue,
+                Seen::Duplicate => {
+                    metrics::DUPLICATES_DROPPED
+                        .with_label_values(&[&network, heads.label()])
+                        .inc();
+                    continue;
+                }
+            }
+            if let (Some(number), Some(hash)) = key {
+                // the subscription only delivers the new heads, anything between the last one
+                // and this one was missed
+                if let Some((from, to)) = accepted
+                    .last_key_value()
+                    .and_then(|(&last, _)| missed_heads(last, number))
+                {
+                    warn!(
+                        kind = "block_gap",
+                        network,
+                        from,
+                        to,
+                        stream = heads.label()
+                    );
+                    metrics::GAPS_DETECTED
+                        .with_label_values(&[&network, heads.label()])
+                        .inc();
+                    gaps.insert(from, to);
+                }
+                if !gaps.is_empty() {
+                    self.backfill_blocks(&mut gaps, &mut quorum, heads).await?;
+                }
+                accepted.insert(number, hash);
+                while accepted.len() > 256 {
+                    accepted.pop_first();
+                }
+            }
+
+            self.process_head(block, span, heads).await?;
+        }
+
+        Err(crate::Error::ProvidersExhausted(network))
+    }
+
+    /// Fetches and processes the blocks in `gaps` the subscription didn't deliver, e.g. because
+    /// they were produced while the providers were reconnecting. The ones that couldn't be
+    /// fetched are kept in `gaps`, to be retried with the next head.
+    async fn backfill_blocks(
+        &self,
+        gaps: &mut Gaps,
+        quorum: &mut Quorum<BlockKey>,
+        heads: Heads,
+    ) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+
+        for number in gaps.take().into_iter().flat_map(|(from, to)| from..=to) {
+            let block = match self.providers.healthiest().client().block(number).await {
+                // the node may not have caught up with the head yet
+                Ok(block) if block.get() != "null" => block,
+                fetched => {
+                    warn!(kind = "backfill_error", network, number, err = ?fetched.err());
+                    metrics::BACKFILL_ERRORS
+                        .with_label_values(&[&network, heads.label()])
+                        .inc();
+                    gaps.insert(number, number);
+                    continue;
+                }
+            };
+            let block = serde_json::from_str::<<N as NetworkT>::Block>(block.get())?;
+
+            let key = (
+                block.core().number.map(|n| n.to::<u64>()),
+                block.core().hash,
+            );
+            if !quorum.accept(key) {
+                continue;
+            }
+            let span = info_span!(
+                "block",
+                network = %network,
+                number = field::Empty,
+                hash = field::Empty,
+                backfill = true
+            );
+            metrics::BACKFILLED
+                .with_label_values(&[&network, heads.label()])
+                .inc();
+            self.process_head(block, span, heads).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Fetches and processes the logs of the blocks in `gaps` that weren't delivered yet. The
+    /// ranges that couldn't be fetched are kept in `gaps`, to be retried with the next block.
+    async fn backfill_logs(
+        &self,
+        gaps: &mut Gaps,
+        quorum: &mut Quorum<LogKey>,
+    ) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+
+        for (from, to) in gaps.take() {
+            let mut start = from;
+            while start <= to {
+                let end = to.min(start + MAX_BACKFILL_RANGE - 1);
+                let logs = match self.logs(start, end).await {
+                    Ok(logs) => logs,
+                    Err(err) => {
+                        warn!(kind = "backfill_error", network, from = start, to = end, err = %err);
+                        metrics::BACKFILL_ERRORS
+                            .with_label_values(&[&network, "logs"])
+                            .inc();
+                        gaps.insert(start, end);
+                        start = end + 1;
+                        continue;
+                    }
+                };
+
+                for log in logs {
+                    let log = serde_json::from_str::<<N as NetworkT>::Log>(log.get())?;
+                    if !quorum.accept(log_key(&log)) {
+                        continue;
+                    }
+                    let span = info_span!(
+                        "log",
+                        network = %network,
+                        block_number = field::Empty,
+                        tx_hash = field::Empty,
+                        kind = field::Empty,
+                        event_sig = field::Empty,
+                        backfill = true
+                    );
+                    metrics::BACKFILLED
+                        .with_label_values(&[&network, "logs"])
+                        .inc();
+                    self.process_log(log, span).await?;
+                }
+                start = end + 1;
             }
         }
 
@@ -146,7 +867,201 @@ where
     }
 }
 
-pub fn match_events<L: LogT>(log: L) -> Logs<L> {
+impl<N> CollectT<crate::Error> for Collector<N>
+where
+    N: NetworkT,
+{
+    async fn stream_blocks(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
+        self.follow_heads(stop_signal, Heads::Blocks).await
+    }
+
+    async fn stream_transactions(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
+        self.follow_heads(stop_signal, Heads::Transactions).await
+    }
+
+    async fn stream_receipts(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
+        self.follow_heads(stop_signal, Heads::Receipts).await
+    }
+
+    async fn stream_traces(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
+        self.follow_heads(stop_signal, Heads::Traces).await
+    }
+
+    async fn stream_logs(&self, stop_signal: watch::Receiver<bool>) -> crate::Result<()> {
+        let network = self.config.network.to_string();
+        let mut stream = self.providers.sub_logs().await?;
+        info!(
+            subscribed = true,
+            kind = "logs",
+            providers = self.providers.len()
+        );
+
+        let mut quorum = Quorum::new(self.config.quorum, DEDUP_CAPACITY);
+        let mut last_block: Option<u64> = None;
+        let mut disconnected = false;
+        let mut gaps = Gaps::default();
+
+        while let Some((provider, log)) = stream.next().await {
+            if stop_signal.has_changed()? {
+                return Ok(());
+            }
+
+            trace!(log=?log);
+            let log = match log {
+                Ok(log) => log,
+                Err(err) => {
+                    self.providers.record_error(provider, "logs", &err.0);
+                    disconnected = true;
+                    continue;
+                }
+            };
+            let span = info_span!(
+                "log",
+                network = %network,
+                block_number = field::Empty,
+                tx_hash = field::Empty,
+                kind = field::Empty,
+                event_sig = field::Empty
+            );
+            let log = info_span!(parent: &span, "parse")
+                .in_scope(|| serde_json::from_str::<<N as NetworkT>::Log>(log.get()))?;
+
+            let key = log_key(&log);
+            let number = log.core().block_number.map(|n| n.to::<u64>());
+            self.providers
+                .record_delivery(provider, number, quorum.is_new(&key));
+            match quorum.observe(key, provider) {
+                Seen::Accepted => {}
+                Seen::Pending => continue,
+                Seen::Duplicate => {
+                    metrics::DUPLICATES_DROPPED
+                        .with_label_values(&[&network, "logs"])
+                        .inc();
+                    continue;
+                }
+            }
+            if let Some(number) = number {
+                // blocks without logs are common, so a gap in the block numbers only means
+                // something was missed if a provider disconnected in the meantime
+                if let (true, Some(last)) = (disconnected, last_block) {
+                    if number > last {
+                        warn!(kind = "log_gap", network, from = last, to = number);
+                        metrics::GAPS_DETECTED
+                            .with_label_values(&[&network, "logs"])
+                            .inc();
+                        gaps.insert(last, number);
+                    }
+                    disconnected = false;
+                }
+                // the gaps left by failed backfills are retried once per block
+                if !gaps.is_empty() && last_block.map_or(true, |last| number > last) {
+                    self.backfill_logs(&mut gaps, &mut quorum).await?;
+                }
+                last_block = Some(last_block.map_or(number, |last| last.max(number)));
+            }
+
+            self.process_log(log, span).await?;
+        }
+
+        Err(crate::Error::ProvidersExhausted(network))
+    }
+}
+
+/// What the accepted new heads are processed as.
+#[derive(Clone, Copy, Debug)]
+enum Heads {
+    Blocks,
+    Transactions,
+    Receipts,
+    Traces,
+}
+
+impl Heads {
+    fn label(&self) -> &'static str {
+        match self {
+            Heads::Blocks => "blocks",
+            Heads::Transactions => "transactions",
+            Heads::Receipts => "receipts",
+            Heads::Traces => "traces",
+        }
+    }
+}
+
+/// Inclusive ranges of blocks that were missed and still have to be backfilled, kept until
+/// they're fetched so that a failed backfill is retried rather than lost.
+#[derive(Debug, Default)]
+struct Gaps(BTreeMap<u64, u64>);
+
+impl Gaps {
+    /// Records `from..=to` as missed, merged with the overlapping and adjacent ranges.
+    fn insert(&mut self, mut from: u64, mut to: u64) {
+        let merged: Vec<u64> = self
+            .0
+            .range(..=to.saturating_add(1))
+            .filter(|(_, &end)| end.saturating_add(1) >= from)
+            .map(|(&start, _)| start)
+            .collect();
+        for start in merged {
+            if let Some(end) = self.0.remove(&start) {
+                from = from.min(start);
+                to = to.max(end);
+            }
+        }
+        self.0.insert(from, to);
+    }
+
+    /// Takes every missed range, oldest first.
+    fn take(&mut self) -> Vec<(u64, u64)> {
+        std::mem::take(&mut self.0).into_iter().collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The blocks missed between the last accepted head `last` and the new head `number`.
+fn missed_heads(last: u64, number: u64) -> Option<(u64, u64)> {
+    (number > last + 1).then(|| (last + 1, number - 1))
+}
+
+type BlockKey = (Option<u64>, Option<B256>);
+type LogKey = (Option<B256>, Option<B256>, Option<U64>, bool);
+
+fn log_key<L: LogT>(log: &L) -> LogKey {
+    (
+        log.core().block_hash,
+        log.core().tx_hash,
+        log.core().log_index,
+        log.core().removed,
+    )
+}
+
+fn observe_head<B: eventify_primitives::BlockT>(network: &str, block: &B) {
+    if let Some(number) = block.core().number {
+        metrics::HEAD_BLOCK
+            .with_label_values(&[network])
+            .set(number.to::<i64>());
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    metrics::HEAD_LAG
+        .with_label_values(&[network])
+        .set(now.saturating_sub(block.core().time.to::<i64>()));
+}
+
+#[cfg(feature = "propagate")]
+fn observe_channel_depth<T>(network: &str, queue: &mpsc::Sender<T>) {
+    metrics::CHANNEL_DEPTH
+        .with_label_values(&[network])
+        .set((queue.max_capacity() - queue.capacity()) as i64);
+}
+
+pub fn match_events<L: LogT>(log: L, config: &CollectorConfig) -> Logs<L> {
+    let network = config.network;
     let mut topics = log.core().topics.clone();
     let topics = if log.core().topics.len() == 4 {
         &log.core().topics
@@ -160,57 +1075,59 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
     let signature = topics[0];
 
     match signature {
-        // ERC20 Transfer && ERC721 Transfer share signature
+        // ERC20 Transfer && ERC721 Transfer share signature, only the latter indexes its last
+        // parameter
         ERC20::Transfer::SIGNATURE_HASH => {
-            match (
-                (log.core().data.len() == 0),
-                (topics[3] != FixedBytes::repeat_byte(0)),
-            ) {
+            match (log.core().data.is_empty(), log.core().topics.len() == 4) {
                 (true, true) => {
                     // ERC721
                     let from = Address::left_padding_from(&topics[1][12..32]);
                     let to = Address::left_padding_from(&topics[2][12..32]);
                     #[allow(non_snake_case)]
-                    let tokenId = U256::from_le_slice(&topics[3][..32]);
+                    let tokenId = U256::from_be_slice(&topics[3][..32]);
                     let e = ERC721::Transfer { from, to, tokenId };
 
                     info!(kind="log_erc721_transfer", tx_hash=?log.core().tx_hash);
-                    Logs::ERC721_Transfer(e)
+                    Logs::ERC721_Transfer(Emitted::from_log(network, log.core(), e))
                 }
                 (false, false) => {
                     if log.core().data.len() != 32 {
                         warn!(kind="log_raw", sig=ERC20::Transfer::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                        metrics::RAW_FALLBACKS
+                            .with_label_values(&[ERC20::Transfer::SIGNATURE])
+                            .inc();
                         return Logs::Raw(log);
                     }
 
                     // ERC20
                     let from = Address::left_padding_from(&topics[1][12..32]);
                     let to = Address::left_padding_from(&topics[2][12..32]);
-                    let value = U256::from_le_slice(&data[..32]);
+                    let value = U256::from_be_slice(&data[..32]);
                     let e = ERC20::Transfer { from, to, value };
 
                     info!(kind="log_erc20_transfer", tx_hash=?log.core().tx_hash);
-                    Logs::ERC20_Transfer(e)
+                    Logs::ERC20_Transfer(Emitted::from_log(network, log.core(), e))
                 }
                 _ => {
                     warn!(kind="log_raw", sig=ERC20::Transfer::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                    metrics::RAW_FALLBACKS
+                        .with_label_values(&[ERC20::Transfer::SIGNATURE])
+                        .inc();
                     Logs::Raw(log)
                 }
             }
         }
 
-        // ERC20 Approval && ERC721 Approval share signature
+        // ERC20 Approval && ERC721 Approval share signature, only the latter indexes its last
+        // parameter
         ERC20::Approval::SIGNATURE_HASH => {
-            match (
-                (log.core().data.len() == 0),
-                (topics[3] != FixedBytes::repeat_byte(0)),
-            ) {
+            match (log.core().data.is_empty(), log.core().topics.len() == 4) {
                 (true, true) => {
                     // ERC721
                     let owner = Address::left_padding_from(&topics[1][12..32]);
                     let approved = Address::left_padding_from(&topics[2][12..32]);
                     #[allow(non_snake_case)]
-                    let tokenId = U256::from_le_slice(&topics[3][..32]);
+                    let tokenId = U256::from_be_slice(&topics[3][..32]);
                     let e = ERC721::Approval {
                         owner,
                         approved,
@@ -224,12 +1141,15 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
                     // ERC20
                     if log.core().data.len() != 32 {
                         warn!(kind="log_raw", sig=ERC20::Approval::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                        metrics::RAW_FALLBACKS
+                            .with_label_values(&[ERC20::Approval::SIGNATURE])
+                            .inc();
                         return Logs::Raw(log);
                     }
 
                     let owner = Address::left_padding_from(&topics[1][12..32]);
                     let spender = Address::left_padding_from(&topics[2][12..32]);
-                    let value = U256::from_le_slice(&data[..32]);
+                    let value = U256::from_be_slice(&data[..32]);
                     let e = ERC20::Approval {
                         owner,
                         spender,
@@ -237,10 +1157,13 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
                     };
 
                     info!(kind="log_erc20_approval" , tx_hash=?log.core().tx_hash);
-                    Logs::ERC20_Approval(e)
+                    Logs::ERC20_Approval(Emitted::from_log(network, log.core(), e))
                 }
                 _ => {
                     warn!(kind="log_raw", sig=ERC20::Approval::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                    metrics::RAW_FALLBACKS
+                        .with_label_values(&[ERC20::Approval::SIGNATURE])
+                        .inc();
                     Logs::Raw(log)
                 }
             }
@@ -249,6 +1172,9 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         ERC721::ApprovalForAll::SIGNATURE_HASH => {
             if log.core().data.len() != 32 {
                 warn!(kind="log_raw", sig=ERC721::ApprovalForAll::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC721::ApprovalForAll::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
@@ -268,13 +1194,16 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         ERC777::Sent::SIGNATURE_HASH => {
             if log.core().data.len() != 96 {
                 warn!(kind="log_raw", sig=ERC777::Sent::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC777::Sent::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let operator = Address::left_padding_from(&topics[1][12..32]);
             let from = Address::left_padding_from(&topics[2][12..32]);
             let to = Address::left_padding_from(&topics[3][12..32]);
-            let amount = U256::from_le_slice(&log.core().data[..32]);
+            let amount = U256::from_be_slice(&log.core().data[..32]);
             let data = log.core().data[32..64].to_vec();
             #[allow(non_snake_case)]
             let operatorData = log.core().data[64..96].to_vec();
@@ -294,12 +1223,15 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         ERC777::Minted::SIGNATURE_HASH => {
             if log.core().data.len() != 96 {
                 warn!(kind="log_raw", sig=ERC777::Minted::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC777::Minted::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let operator = Address::left_padding_from(&topics[1][12..32]);
             let to = Address::left_padding_from(&topics[2][12..32]);
-            let amount = U256::from_le_slice(&log.core().data[..32]);
+            let amount = U256::from_be_slice(&log.core().data[..32]);
             let data = log.core().data[32..64].to_vec();
             #[allow(non_snake_case)]
             let operatorData = log.core().data[64..96].to_vec();
@@ -318,12 +1250,15 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         ERC777::Burned::SIGNATURE_HASH => {
             if log.core().data.len() != 96 {
                 warn!(kind="log_raw", sig=ERC777::Burned::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC777::Burned::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let operator = Address::left_padding_from(&topics[1][12..32]);
             let from = Address::left_padding_from(&topics[2][12..32]);
-            let amount = U256::from_le_slice(&log.core().data[..32]);
+            let amount = U256::from_be_slice(&log.core().data[..32]);
             let data = log.core().data[32..64].to_vec();
             #[allow(non_snake_case)]
             let operatorData = log.core().data[64..96].to_vec();
@@ -360,14 +1295,17 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         ERC1155::TransferSingle::SIGNATURE_HASH => {
             if log.core().data.len() != 64 {
                 warn!(kind="log_raw", sig=ERC1155::TransferSingle::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC1155::TransferSingle::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let operator = Address::left_padding_from(&topics[1][12..32]);
             let from = Address::left_padding_from(&topics[2][12..32]);
             let to = Address::left_padding_from(&topics[3][12..32]);
-            let id = U256::from_le_slice(&log.core().data[..32]);
-            let value = U256::from_le_slice(&log.core().data[32..64]);
+            let id = U256::from_be_slice(&log.core().data[..32]);
+            let value = U256::from_be_slice(&log.core().data[32..64]);
             let e = ERC1155::TransferSingle {
                 operator,
                 from,
@@ -377,26 +1315,26 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
             };
 
             info!(kind="erc1155_transfer_single", tx_hash=?log.core().tx_hash);
-            Logs::ERC1155_TransferSingle(e)
+            Logs::ERC1155_TransferSingle(Emitted::from_log(network, log.core(), e))
         }
 
         ERC1155::TransferBatch::SIGNATURE_HASH => {
-            if log.core().data.len() != 64 {
-                warn!(kind="log_raw", sig=ERC1155::TransferBatch::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
-                return Logs::Raw(log);
-            }
+            // the ids and values are dynamic arrays of the same length
+            type Batch = (Array<Uint<256>>, Array<Uint<256>>);
+            let (ids, values) = match Batch::abi_decode_params(&log.core().data, true) {
+                Ok((ids, values)) if ids.len() == values.len() => (ids, values),
+                _ => {
+                    warn!(kind="log_raw", sig=ERC1155::TransferBatch::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                    metrics::RAW_FALLBACKS
+                        .with_label_values(&[ERC1155::TransferBatch::SIGNATURE])
+                        .inc();
+                    return Logs::Raw(log);
+                }
+            };
 
             let operator = Address::left_padding_from(&topics[1][12..32]);
             let from = Address::left_padding_from(&topics[2][12..32]);
             let to = Address::left_padding_from(&topics[3][12..32]);
-            let ids = log.core().data[..32]
-                .chunks_exact(32)
-                .map(U256::from_le_slice)
-                .collect::<Vec<_>>();
-            let values = log.core().data[32..64]
-                .chunks_exact(32)
-                .map(U256::from_le_slice)
-                .collect::<Vec<_>>();
             let e = ERC1155::TransferBatch {
                 operator,
                 from,
@@ -406,28 +1344,30 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
             };
 
             info!(kind="erc1155_transfer_batch", tx_hash=?log.core().tx_hash);
-            Logs::ERC1155_TransferBatch(e)
+            Logs::ERC1155_TransferBatch(Emitted::from_log(network, log.core(), e))
         }
 
-        ERC1155::URI::SIGNATURE_HASH => {
-            let value = log.core().data.to_string();
-            let id = U256::from_le_slice(&topics[1][..]);
-            let e = ERC1155::URI { value, id };
-
-            info!(kind="erc1155_uri", tx_hash=?log.core().tx_hash);
-            Logs::ERC1155_URI(e)
-        }
+        ERC1155::URI::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="erc1155_uri", tx_hash=?log.core().tx_hash);
+                Logs::ERC1155_URI(e)
+            }
+            Err(err) => raw_fallback(log, ERC1155::URI::SIGNATURE, err),
+        },
 
         ERC4626::Deposit::SIGNATURE_HASH => {
             if log.core().data.len() != 64 {
                 warn!(kind="log_raw", sig=ERC4626::Deposit::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC4626::Deposit::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let sender = Address::left_padding_from(&topics[1][12..32]);
             let owner = Address::left_padding_from(&topics[2][12..32]);
-            let assets = U256::from_le_slice(&log.core().data[..32]);
-            let shares = U256::from_le_slice(&log.core().data[32..64]);
+            let assets = U256::from_be_slice(&log.core().data[..32]);
+            let shares = U256::from_be_slice(&log.core().data[32..64]);
             let e = ERC4626::Deposit {
                 sender,
                 owner,
@@ -436,20 +1376,23 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
             };
 
             info!(kind="erc4626_deposit", tx_hash=?log.core().tx_hash);
-            Logs::ERC4626_Deposit(e)
+            Logs::ERC4626_Deposit(Emitted::from_log(network, log.core(), e))
         }
 
         ERC4626::Withdraw::SIGNATURE_HASH => {
             if log.core().data.len() != 64 {
                 warn!(kind="log_raw", sig=ERC4626::Withdraw::SIGNATURE, tx_hash=?log.core().tx_hash, data_len=?log.core().data.len(), data=?log.core().data);
+                metrics::RAW_FALLBACKS
+                    .with_label_values(&[ERC4626::Withdraw::SIGNATURE])
+                    .inc();
                 return Logs::Raw(log);
             }
 
             let sender = Address::left_padding_from(&topics[1][12..32]);
             let receiver = Address::left_padding_from(&topics[2][12..32]);
             let owner = Address::left_padding_from(&topics[3][12..32]);
-            let assets = U256::from_le_slice(&log.core().data[..32]);
-            let shares = U256::from_le_slice(&log.core().data[32..64]);
+            let assets = U256::from_be_slice(&log.core().data[..32]);
+            let shares = U256::from_be_slice(&log.core().data[32..64]);
             let e = ERC4626::Withdraw {
                 sender,
                 receiver,
@@ -459,7 +1402,411 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
             };
 
             info!(kind="erc4626_withdraw", tx_hash=?log.core().tx_hash);
-            Logs::ERC4626_Withdraw(e)
+            Logs::ERC4626_Withdraw(Emitted::from_log(network, log.core(), e))
+        }
+
+        UniswapV2::Swap::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v2_swap", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV2_Swap(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV2::Swap::SIGNATURE, err),
+        },
+
+        UniswapV2::Mint::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v2_mint", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV2_Mint(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV2::Mint::SIGNATURE, err),
+        },
+
+        UniswapV2::Burn::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v2_burn", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV2_Burn(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV2::Burn::SIGNATURE, err),
+        },
+
+        UniswapV2::Sync::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v2_sync", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV2_Sync(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV2::Sync::SIGNATURE, err),
+        },
+
+        UniswapV3::Swap::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v3_swap", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV3_Swap(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV3::Swap::SIGNATURE, err),
+        },
+
+        UniswapV3::Mint::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v3_mint", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV3_Mint(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV3::Mint::SIGNATURE, err),
+        },
+
+        UniswapV3::Burn::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v3_burn", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV3_Burn(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV3::Burn::SIGNATURE, err),
+        },
+
+        UniswapV3::Collect::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v3_collect", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV3_Collect(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV3::Collect::SIGNATURE, err),
+        },
+
+        UniswapV3::Initialize::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="uniswap_v3_initialize", tx_hash=?log.core().tx_hash);
+                Logs::UniswapV3_Initialize(e)
+            }
+            Err(err) => raw_fallback(log, UniswapV3::Initialize::SIGNATURE, err),
+        },
+
+        // shared by other contracts, e.g. bridges and staking pools
+        WETH::Deposit::SIGNATURE_HASH if log.core().address == config.wrapped_token() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="weth_deposit", tx_hash=?log.core().tx_hash);
+                    Logs::WETH_Deposit(e)
+                }
+                Err(err) => raw_fallback(log, WETH::Deposit::SIGNATURE, err),
+            }
+        }
+
+        WETH::Withdrawal::SIGNATURE_HASH if log.core().address == config.wrapped_token() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="weth_withdrawal", tx_hash=?log.core().tx_hash);
+                    Logs::WETH_Withdrawal(e)
+                }
+                Err(err) => raw_fallback(log, WETH::Withdrawal::SIGNATURE, err),
+            }
+        }
+
+        EIP1967::Upgraded::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="eip1967_upgraded", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::EIP1967_Upgraded(e)
+            }
+            Err(err) => raw_fallback(log, EIP1967::Upgraded::SIGNATURE, err),
+        },
+
+        EIP1967::AdminChanged::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="eip1967_admin_changed", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::EIP1967_AdminChanged(e)
+            }
+            Err(err) => raw_fallback(log, EIP1967::AdminChanged::SIGNATURE, err),
+        },
+
+        EIP1967::BeaconUpgraded::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="eip1967_beacon_upgraded", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::EIP1967_BeaconUpgraded(e)
+            }
+            Err(err) => raw_fallback(log, EIP1967::BeaconUpgraded::SIGNATURE, err),
+        },
+
+        Ownable::OwnershipTransferred::SIGNATURE_HASH => match Emitted::decode(network, log.core())
+        {
+            Ok(e) => {
+                info!(kind="ownable_ownership_transferred", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::Ownable_OwnershipTransferred(e)
+            }
+            Err(err) => raw_fallback(log, Ownable::OwnershipTransferred::SIGNATURE, err),
+        },
+
+        AccessControl::RoleGranted::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="access_control_role_granted", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::AccessControl_RoleGranted(e)
+            }
+            Err(err) => raw_fallback(log, AccessControl::RoleGranted::SIGNATURE, err),
+        },
+
+        AccessControl::RoleRevoked::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="access_control_role_revoked", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::AccessControl_RoleRevoked(e)
+            }
+            Err(err) => raw_fallback(log, AccessControl::RoleRevoked::SIGNATURE, err),
+        },
+
+        AccessControl::RoleAdminChanged::SIGNATURE_HASH => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="access_control_role_admin_changed", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::AccessControl_RoleAdminChanged(e)
+                }
+                Err(err) => raw_fallback(log, AccessControl::RoleAdminChanged::SIGNATURE, err),
+            }
+        }
+
+        EntryPoint::UserOperationEvent::SIGNATURE_HASH
+            if ENTRY_POINTS.contains(&log.core().address) =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="entry_point_user_operation_event", tx_hash=?log.core().tx_hash);
+                    Logs::EntryPoint_UserOperationEvent(e)
+                }
+                Err(err) => raw_fallback(log, EntryPoint::UserOperationEvent::SIGNATURE, err),
+            }
+        }
+
+        EntryPoint::AccountDeployed::SIGNATURE_HASH
+            if ENTRY_POINTS.contains(&log.core().address) =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="entry_point_account_deployed", tx_hash=?log.core().tx_hash);
+                    Logs::EntryPoint_AccountDeployed(e)
+                }
+                Err(err) => raw_fallback(log, EntryPoint::AccountDeployed::SIGNATURE, err),
+            }
+        }
+
+        EntryPoint::UserOperationRevertReason::SIGNATURE_HASH
+            if ENTRY_POINTS.contains(&log.core().address) =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="entry_point_user_operation_revert_reason", tx_hash=?log.core().tx_hash);
+                    Logs::EntryPoint_UserOperationRevertReason(e)
+                }
+                Err(err) => {
+                    raw_fallback(log, EntryPoint::UserOperationRevertReason::SIGNATURE, err)
+                }
+            }
+        }
+
+        EntryPoint::PostOpRevertReason::SIGNATURE_HASH
+            if ENTRY_POINTS.contains(&log.core().address) =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="entry_point_post_op_revert_reason", tx_hash=?log.core().tx_hash);
+                    Logs::EntryPoint_PostOpRevertReason(e)
+                }
+                Err(err) => raw_fallback(log, EntryPoint::PostOpRevertReason::SIGNATURE, err),
+            }
+        }
+
+        EntryPoint::BeforeExecution::SIGNATURE_HASH
+            if ENTRY_POINTS.contains(&log.core().address) =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="entry_point_before_execution", tx_hash=?log.core().tx_hash);
+                    Logs::EntryPoint_BeforeExecution(e)
+                }
+                Err(err) => raw_fallback(log, EntryPoint::BeforeExecution::SIGNATURE, err),
+            }
+        }
+
+        ERC4906::MetadataUpdate::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="erc4906_metadata_update", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::ERC4906_MetadataUpdate(e)
+            }
+            Err(err) => raw_fallback(log, ERC4906::MetadataUpdate::SIGNATURE, err),
+        },
+
+        ERC4906::BatchMetadataUpdate::SIGNATURE_HASH => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="erc4906_batch_metadata_update", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::ERC4906_BatchMetadataUpdate(e)
+                }
+                Err(err) => raw_fallback(log, ERC4906::BatchMetadataUpdate::SIGNATURE, err),
+            }
+        }
+
+        ERC5192::Locked::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="erc5192_locked", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::ERC5192_Locked(e)
+            }
+            Err(err) => raw_fallback(log, ERC5192::Locked::SIGNATURE, err),
+        },
+
+        ERC5192::Unlocked::SIGNATURE_HASH => match Emitted::decode(network, log.core()) {
+            Ok(e) => {
+                info!(kind="erc5192_unlocked", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                Logs::ERC5192_Unlocked(e)
+            }
+            Err(err) => raw_fallback(log, ERC5192::Unlocked::SIGNATURE, err),
+        },
+
+        ERC6551Registry::ERC6551AccountCreated::SIGNATURE_HASH => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="erc6551_account_created", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::ERC6551Registry_ERC6551AccountCreated(e)
+                }
+                Err(err) => {
+                    raw_fallback(log, ERC6551Registry::ERC6551AccountCreated::SIGNATURE, err)
+                }
+            }
+        }
+
+        Permit2::Approval::SIGNATURE_HASH if log.core().address == network.permit2() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="permit2_approval", tx_hash=?log.core().tx_hash);
+                    Logs::Permit2_Approval(e)
+                }
+                Err(err) => raw_fallback(log, Permit2::Approval::SIGNATURE, err),
+            }
+        }
+
+        Permit2::Permit::SIGNATURE_HASH if log.core().address == network.permit2() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="permit2_permit", tx_hash=?log.core().tx_hash);
+                    Logs::Permit2_Permit(e)
+                }
+                Err(err) => raw_fallback(log, Permit2::Permit::SIGNATURE, err),
+            }
+        }
+
+        Permit2::Lockdown::SIGNATURE_HASH if log.core().address == network.permit2() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="permit2_lockdown", tx_hash=?log.core().tx_hash);
+                    Logs::Permit2_Lockdown(e)
+                }
+                Err(err) => raw_fallback(log, Permit2::Lockdown::SIGNATURE, err),
+            }
+        }
+
+        Permit2::NonceInvalidation::SIGNATURE_HASH if log.core().address == network.permit2() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="permit2_nonce_invalidation", tx_hash=?log.core().tx_hash);
+                    Logs::Permit2_NonceInvalidation(e)
+                }
+                Err(err) => raw_fallback(log, Permit2::NonceInvalidation::SIGNATURE, err),
+            }
+        }
+
+        // Safe v1.4 indexes the leading parameters v1.3 doesn't. Their signatures are generic
+        // enough for other contracts to share them, only the configured Safes' are decoded
+        Safe::ExecutionSuccess::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode_unindexed(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_execution_success", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_ExecutionSuccess(e)
+                }
+                Err(err) => raw_fallback(log, Safe::ExecutionSuccess::SIGNATURE, err),
+            }
+        }
+
+        Safe::ExecutionFailure::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode_unindexed(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_execution_failure", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_ExecutionFailure(e)
+                }
+                Err(err) => raw_fallback(log, Safe::ExecutionFailure::SIGNATURE, err),
+            }
+        }
+
+        Safe::AddedOwner::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode_unindexed(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_added_owner", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_AddedOwner(e)
+                }
+                Err(err) => raw_fallback(log, Safe::AddedOwner::SIGNATURE, err),
+            }
+        }
+
+        Safe::RemovedOwner::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode_unindexed(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_removed_owner", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_RemovedOwner(e)
+                }
+                Err(err) => raw_fallback(log, Safe::RemovedOwner::SIGNATURE, err),
+            }
+        }
+
+        Safe::ChangedThreshold::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_changed_threshold", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_ChangedThreshold(e)
+                }
+                Err(err) => raw_fallback(log, Safe::ChangedThreshold::SIGNATURE, err),
+            }
+        }
+
+        Safe::SafeReceived::SIGNATURE_HASH if config.is_safe(&log.core().address) => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_received", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_SafeReceived(e)
+                }
+                Err(err) => raw_fallback(log, Safe::SafeReceived::SIGNATURE, err),
+            }
+        }
+
+        // any contract could claim to have created a Safe
+        Safe::ProxyCreation::SIGNATURE_HASH
+            if SAFE_PROXY_FACTORIES.contains(&log.core().address) =>
+        {
+            match Emitted::decode_unindexed(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="safe_proxy_creation", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Safe_ProxyCreation(e)
+                }
+                Err(err) => raw_fallback(log, Safe::ProxyCreation::SIGNATURE, err),
+            }
+        }
+
+        Chainlink::AnswerUpdated::SIGNATURE_HASH
+            if config.price_feed(&log.core().address).is_some() =>
+        {
+            match Emitted::decode(network, log.core()) {
+                Ok(update) => {
+                    let feed = config
+                        .price_feed(&update.address)
+                        .expect("matched the aggregator of a feed");
+                    info!(kind="chainlink_answer_updated", feed=%feed.name, tx_hash=?log.core().tx_hash);
+                    Logs::Chainlink_AnswerUpdated(PriceUpdate {
+                        feed: feed.name.clone(),
+                        decimals: feed.decimals,
+                        update,
+                    })
+                }
+                Err(err) => raw_fallback(log, Chainlink::AnswerUpdated::SIGNATURE, err),
+            }
+        }
+
+        Chainlink::NewRound::SIGNATURE_HASH if config.price_feed(&log.core().address).is_some() => {
+            match Emitted::decode(network, log.core()) {
+                Ok(e) => {
+                    info!(kind="chainlink_new_round", address=?log.core().address, tx_hash=?log.core().tx_hash);
+                    Logs::Chainlink_NewRound(e)
+                }
+                Err(err) => raw_fallback(log, Chainlink::NewRound::SIGNATURE, err),
+            }
         }
 
         _ => {
@@ -468,3 +1815,45 @@ pub fn match_events<L: LogT>(log: L) -> Logs<L> {
         }
     }
 }
+
+/// Counts a log whose topic0 matched a known event its topics or data didn't fit, keeping it raw.
+fn raw_fallback<L: LogT>(log: L, sig: &'static str, err: alloy_sol_types::Error) -> Logs<L> {
+    warn!(kind="log_raw", sig, tx_hash=?log.core().tx_hash, topics=log.core().topics.len(), data_len=log.core().data.len(), err=%err);
+    metrics::RAW_FALLBACKS.with_label_values(&[sig]).inc();
+    Logs::Raw(log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_missed_heads() {
+        assert_eq!(missed_heads(10, 11), None);
+        assert_eq!(missed_heads(10, 10), None);
+        assert_eq!(missed_heads(10, 9), None);
+        assert_eq!(missed_heads(10, 12), Some((11, 11)));
+        assert_eq!(missed_heads(10, 15), Some((11, 14)));
+    }
+
+    #[test]
+    fn merges_gaps() {
+        let mut gaps = Gaps::default();
+        assert!(gaps.is_empty());
+
+        gaps.insert(20, 25);
+        gaps.insert(5, 5);
+        gaps.insert(10, 12);
+        gaps.insert(13, 13);
+        gaps.insert(24, 30);
+        gaps.insert(7, 7);
+        assert_eq!(gaps.take(), vec![(5, 5), (7, 7), (10, 13), (20, 30)]);
+        assert!(gaps.is_empty());
+
+        gaps.insert(1, 10);
+        gaps.insert(3, 4);
+        gaps.insert(0, 0);
+        gaps.insert(u64::MAX, u64::MAX);
+        assert_eq!(gaps.take(), vec![(0, 10), (u64::MAX, u64::MAX)]);
+    }
+}
@@ -12,12 +12,18 @@ pub enum Error {
     #[error(transparent)]
     Sql(#[from] sqlx::Error),
 
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
     #[error(transparent)]
     SerdeError(#[from] serde_json::Error),
 
     #[error("Empty stream")]
     EmptyStream,
 
+    #[error("Every provider of {0} gave up reconnecting")]
+    ProvidersExhausted(String),
+
     #[error(transparent)]
     Eyre(#[from] eyre::Error),
 
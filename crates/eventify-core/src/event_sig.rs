@@ -0,0 +1,218 @@
+//! Resolution of the topic0 of logs no known event matched against the `event_sig` table, and its
+//! import.
+
+use std::{
+    io::BufRead,
+    sync::{Arc, Mutex},
+};
+
+use alloy_primitives::{keccak256, B256};
+use sqlx::PgPool;
+
+use crate::{
+    fn_sig::{import_into, Cache, Imported},
+    metrics,
+};
+use eventify_primitives::{
+    abi::{self, Event},
+    events::decoded::DecodedEvent,
+    networks::{core::CoreLog, NetworkKind},
+};
+
+/// Resolves topics to event signatures, caching every lookup (including the misses).
+#[derive(Debug, Clone)]
+pub struct EventSigs {
+    pool: PgPool,
+    cache: Arc<Mutex<Cache<B256>>>,
+}
+
+impl EventSigs {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            cache: Arc::new(Mutex::new(Cache::default())),
+        }
+    }
+
+    /// Every known signature with the given topic0.
+    pub async fn candidates(&self, topic0: B256) -> Result<Arc<[String]>, sqlx::Error> {
+        if let Some(signatures) = self.cached(&topic0) {
+            metrics::EVENT_SIG_LOOKUPS.with_label_values(&["hit"]).inc();
+            return Ok(signatures);
+        }
+        metrics::EVENT_SIG_LOOKUPS
+            .with_label_values(&["miss"])
+            .inc();
+
+        let signatures: Vec<String> =
+            sqlx::query_scalar("SELECT text_sig FROM event_sig WHERE hex_sig = $1")
+                .bind(topic0.as_slice())
+                .fetch_all(&self.pool)
+                .await?;
+        let signatures: Arc<[String]> = signatures.into();
+
+        self.cache
+            .lock()
+            .expect("event_sig cache poisoned")
+            .insert(topic0, signatures.clone());
+
+        Ok(signatures)
+    }
+
+    /// The signature of the event `log` was emitted for, `None` for anonymous logs and unknown
+    /// topics.
+    pub async fn resolve(&self, log: &CoreLog) -> Result<Option<String>, sqlx::Error> {
+        let Some(topic0) = log.topics.first() else {
+            return Ok(None);
+        };
+        let candidates = self.candidates(*topic0).await?;
+
+        Ok(pick(*topic0, &candidates).map(str::to_string))
+    }
+
+    fn cached(&self, topic0: &B256) -> Option<Arc<[String]>> {
+        self.cache
+            .lock()
+            .expect("event_sig cache poisoned")
+            .get(topic0)
+    }
+}
+
+/// The canonical signature among those stored for `topic0`, i.e. the one it's the hash of.
+pub fn pick(topic0: B256, candidates: &[String]) -> Option<&str> {
+    candidates
+        .iter()
+        .find(|sig| keccak256(sig.as_bytes()) == topic0)
+        .map(String::as_str)
+}
+
+/// Decodes `log` as an event with the given signature, if there's only one way its inputs can
+/// be spread over the topics and data, see [`abi::infer`].
+pub fn decode(network: NetworkKind, log: &CoreLog, signature: &str) -> Option<DecodedEvent> {
+    let (event, _) = abi::infer(&Event::parse(signature).ok()?, &log.topics, &log.data)?;
+
+    DecodedEvent::new(network, log, &event).ok()
+}
+
+/// Loads signatures into `event_sig`, one per line and optionally preceded by their topic0 as in
+/// `<topic0>,"<signature>"`.
+pub async fn import(pool: &PgPool, reader: impl BufRead) -> eyre::Result<Imported> {
+    import_into(pool, "event_sig", reader, parse_line).await
+}
+
+/// Parses a single line, either a bare signature or `<topic0>,<signature>` with the topic prefixed
+/// with `0x` or the postgres `\x` and the signature optionally quoted.
+///
+/// Signatures whose hash isn't the given topic (or that aren't canonical, as hashing them
+/// wouldn't yield the topic0 of any log) are rejected.
+pub fn parse_line(line: &str) -> Option<(B256, String)> {
+    let line = line.trim();
+    let (topic0, signature) = match line.split_once(',') {
+        Some((topic0, signature)) if topic0.starts_with("0x") || topic0.starts_with("\\x") => {
+            (Some(topic0.trim()), signature.trim())
+        }
+        _ => (None, line),
+    };
+
+    let signature = signature
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(signature)
+        .replace("\"\"", "\"");
+    let event = Event::parse(&signature).ok()?;
+    if event.signature() != signature {
+        return None;
+    }
+    let hash = keccak256(signature.as_bytes());
+
+    if let Some(topic0) = topic0 {
+        let topic0 = topic0
+            .strip_prefix("0x")
+            .or_else(|| topic0.strip_prefix("\\x"))?;
+        if topic0.parse::<B256>().ok()? != hash {
+            return None;
+        }
+    }
+
+    Some((hash, signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{b256, Address, Bytes, U256};
+
+    use super::*;
+
+    const TRANSFER: &str = "Transfer(address,address,uint256)";
+    const TRANSFER_TOPIC: B256 =
+        b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+    #[test]
+    fn parse_hashed_and_bare_lines() {
+        let expected = Some((TRANSFER_TOPIC, TRANSFER.to_string()));
+        assert_eq!(
+            parse_line(
+                r#"\xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef,"Transfer(address,address,uint256)""#
+            ),
+            expected
+        );
+        assert_eq!(
+            parse_line(
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef,Transfer(address,address,uint256)"
+            ),
+            expected
+        );
+        assert_eq!(parse_line(TRANSFER), expected);
+
+        // mismatching topic, non-canonical and invalid signatures
+        assert_eq!(
+            parse_line(
+                "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925,Transfer(address,address,uint256)"
+            ),
+            None
+        );
+        assert_eq!(parse_line("Transfer(address,address,uint)"), None);
+        assert_eq!(parse_line("Transfer(address, address, uint256)"), None);
+        assert_eq!(parse_line("Transfer(address,address"), None);
+    }
+
+    #[test]
+    fn pick_the_preimage_of_the_topic() {
+        let candidates = vec![
+            "Approval(address,address,uint256)".to_string(),
+            TRANSFER.to_string(),
+        ];
+
+        assert_eq!(pick(TRANSFER_TOPIC, &candidates), Some(TRANSFER));
+        assert_eq!(pick(TRANSFER_TOPIC, &candidates[..1]), None);
+    }
+
+    #[test]
+    fn decode_with_inferred_layout() {
+        let log = CoreLog {
+            address: Address::repeat_byte(0xaa),
+            topics: vec![
+                TRANSFER_TOPIC,
+                B256::left_padding_from(&[0x11; 20]),
+                B256::left_padding_from(&[0x22; 20]),
+            ],
+            data: Bytes::from(U256::MAX.to_be_bytes::<32>().to_vec()),
+            ..Default::default()
+        };
+
+        let decoded = decode(NetworkKind::Ethereum, &log, TRANSFER).unwrap();
+        assert_eq!(decoded.signature, TRANSFER);
+        assert_eq!(
+            decoded.params["arg0"],
+            "0x1111111111111111111111111111111111111111"
+        );
+        assert_eq!(decoded.params["arg2"], U256::MAX.to_string());
+
+        // an amount that'd fit an address as well
+        let log = CoreLog {
+            data: Bytes::from(U256::from(1).to_be_bytes::<32>().to_vec()),
+            ..log
+        };
+        assert!(decode(NetworkKind::Ethereum, &log, TRANSFER).is_none());
+    }
+}
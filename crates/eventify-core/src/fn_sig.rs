@@ -0,0 +1,386 @@
+//! Resolution of the 4-byte function selectors against the `fn_sig` table, and its import from
+//! the 4byte.directory CSV dump.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    io::BufRead,
+    sync::{Arc, Mutex},
+};
+
+use alloy_primitives::{keccak256, FixedBytes};
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::metrics;
+
+/// Number of selectors/topics whose signatures are kept in memory.
+const CACHE_CAPACITY: usize = 65_536;
+
+/// Number of rows inserted with a single statement while importing.
+const IMPORT_BATCH: usize = 5_000;
+
+/// Resolves selectors to function signatures, caching every lookup (including the misses).
+#[derive(Debug, Clone)]
+pub struct FnSigs {
+    pool: PgPool,
+    cache: Arc<Mutex<Cache<FixedBytes<4>>>>,
+}
+
+/// The signatures of the most recently looked up hashes, evicted in insertion order.
+#[derive(Debug)]
+pub(crate) struct Cache<K> {
+    signatures: HashMap<K, Arc<[String]>>,
+    order: VecDeque<K>,
+}
+
+impl<K: Copy + Eq + Hash> Default for Cache<K> {
+    fn default() -> Self {
+        Self {
+            signatures: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> Cache<K> {
+    pub(crate) fn get(&self, key: &K) -> Option<Arc<[String]>> {
+        self.signatures.get(key).cloned()
+    }
+
+    pub(crate) fn insert(&mut self, key: K, signatures: Arc<[String]>) {
+        if !self.signatures.contains_key(&key) {
+            if self.order.len() == CACHE_CAPACITY {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.signatures.remove(&oldest);
+                }
+            }
+            self.order.push_back(key);
+        }
+        self.signatures.insert(key, signatures);
+    }
+}
+
+impl FnSigs {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            cache: Arc::new(Mutex::new(Cache::default())),
+        }
+    }
+
+    /// Every known signature with the given selector.
+    pub async fn candidates(&self, selector: FixedBytes<4>) -> Result<Arc<[String]>, sqlx::Error> {
+        if let Some(signatures) = self.cached(&selector) {
+            metrics::FN_SIG_LOOKUPS.with_label_values(&["hit"]).inc();
+            return Ok(signatures);
+        }
+        metrics::FN_SIG_LOOKUPS.with_label_values(&["miss"]).inc();
+
+        let signatures: Vec<String> =
+            sqlx::query_scalar("SELECT text_sig FROM fn_sig WHERE hex_sig = $1")
+                .bind(selector.as_slice())
+                .fetch_all(&self.pool)
+                .await?;
+        let signatures: Arc<[String]> = signatures.into();
+
+        self.cache
+            .lock()
+            .expect("fn_sig cache poisoned")
+            .insert(selector, signatures.clone());
+
+        Ok(signatures)
+    }
+
+    /// The signature of the function `input` calls, `None` for plain transfers and unknown
+    /// selectors.
+    pub async fn resolve(&self, input: &[u8]) -> Result<Option<String>, sqlx::Error> {
+        if input.len() < 4 {
+            return Ok(None);
+        }
+
+        let selector = FixedBytes::from_slice(&input[..4]);
+        let candidates = self.candidates(selector).await?;
+
+        Ok(pick(selector, &candidates, input).map(str::to_string))
+    }
+
+    fn cached(&self, selector: &FixedBytes<4>) -> Option<Arc<[String]>> {
+        self.cache
+            .lock()
+            .expect("fn_sig cache poisoned")
+            .get(selector)
+    }
+}
+
+/// Picks the most plausible of the signatures sharing a selector.
+///
+/// Signatures that don't hash to the selector are dropped, the rest are ranked by whether the
+/// calldata has room for their arguments and, among those that fit equally well, the shortest
+/// (usually the canonical one rather than a deliberate collision) wins.
+pub fn pick<'a>(
+    selector: FixedBytes<4>,
+    candidates: &'a [String],
+    input: &[u8],
+) -> Option<&'a str> {
+    let args = input.len().saturating_sub(4);
+    let rank = |sig: &'a String| (!fits(sig, args), sig.len(), sig.as_str());
+
+    candidates
+        .iter()
+        .filter(|sig| keccak256(sig.as_bytes())[..4] == selector[..])
+        .min_by(|a, b| rank(a).cmp(&rank(b)))
+        .map(String::as_str)
+}
+
+/// Whether `args` bytes of ABI encoded arguments can hold the parameters of `signature`.
+///
+/// Only the head of the encoding is checked, its size is exact when every parameter is an
+/// elementary static type and a lower bound otherwise.
+fn fits(signature: &str, args: usize) -> bool {
+    let Some(params) = params(signature) else {
+        return false;
+    };
+    if args % 32 != 0 {
+        return false;
+    }
+
+    let head = params.len() * 32;
+    if params.iter().all(|p| is_elementary_static(p)) {
+        args == head
+    } else {
+        args >= head
+    }
+}
+
+/// The top-level parameter types of `signature`, e.g. `["(uint256,bool)", "address[]"]`.
+fn params(signature: &str) -> Option<Vec<&str>> {
+    let open = signature.find('(')?;
+    let inner = signature.get(open + 1..signature.len().checked_sub(1)?)?;
+    if !signature.ends_with(')') {
+        return None;
+    }
+    if inner.is_empty() {
+        return Some(vec![]);
+    }
+
+    let mut params = vec![];
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                params.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    params.push(&inner[start..]);
+
+    Some(params)
+}
+
+fn is_elementary_static(ty: &str) -> bool {
+    !(ty == "string" || ty == "bytes" || ty.starts_with('(') || ty.contains('['))
+}
+
+/// Outcome of [`import`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Imported {
+    /// rows that weren't in the table yet
+    pub inserted: u64,
+    /// rows already present
+    pub existing: u64,
+    /// lines that couldn't be parsed
+    pub skipped: u64,
+}
+
+/// Loads the `<selector>,"<signature>"` lines produced by `scripts/fn-sig.lua` into `fn_sig`.
+pub async fn import(pool: &PgPool, reader: impl BufRead) -> eyre::Result<Imported> {
+    import_into(pool, "fn_sig", reader, parse_line).await
+}
+
+/// Loads the lines of `reader` parsed with `parse_line` into the `(hex_sig, text_sig)` columns
+/// of `table`, in batches of [`IMPORT_BATCH`] rows.
+pub(crate) async fn import_into<const N: usize>(
+    pool: &PgPool,
+    table: &str,
+    reader: impl BufRead,
+    parse_line: fn(&str) -> Option<(FixedBytes<N>, String)>,
+) -> eyre::Result<Imported> {
+    let mut imported = Imported::default();
+    let mut hashes: Vec<Vec<u8>> = Vec::with_capacity(IMPORT_BATCH);
+    let mut signatures: Vec<String> = Vec::with_capacity(IMPORT_BATCH);
+
+    for (n, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some((hash, signature)) = parse_line(&line) else {
+            warn!(
+                kind = %format_args!("{}_import", table),
+                line = n + 1,
+                content = line,
+                "unparsable line"
+            );
+            imported.skipped += 1;
+            continue;
+        };
+
+        hashes.push(hash.to_vec());
+        signatures.push(signature);
+        if hashes.len() == IMPORT_BATCH {
+            insert_batch(pool, table, &mut hashes, &mut signatures, &mut imported).await?;
+        }
+    }
+    insert_batch(pool, table, &mut hashes, &mut signatures, &mut imported).await?;
+
+    Ok(imported)
+}
+
+/// Inserts the `(hex_sig, text_sig)` pairs into `table`, clearing them.
+async fn insert_batch(
+    pool: &PgPool,
+    table: &str,
+    hashes: &mut Vec<Vec<u8>>,
+    signatures: &mut Vec<String>,
+    imported: &mut Imported,
+) -> Result<(), sqlx::Error> {
+    if hashes.is_empty() {
+        return Ok(());
+    }
+
+    let sql = format!(
+        r#"INSERT INTO {} (hex_sig, text_sig)
+        SELECT * FROM UNNEST($1::bytea[], $2::text[])
+        ON CONFLICT (text_sig) DO NOTHING"#,
+        table
+    );
+    let inserted = sqlx::query(&sql)
+        .bind(&*hashes)
+        .bind(&*signatures)
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+    imported.inserted += inserted;
+    imported.existing += hashes.len() as u64 - inserted;
+    hashes.clear();
+    signatures.clear();
+
+    Ok(())
+}
+
+/// Parses a single `<selector>,<signature>` line, the selector prefixed with either `0x` or the
+/// postgres `\x`, the signature optionally quoted.
+pub fn parse_line(line: &str) -> Option<(FixedBytes<4>, String)> {
+    let (selector, signature) = line.trim().split_once(',')?;
+    let selector = selector
+        .trim()
+        .strip_prefix("0x")
+        .or_else(|| selector.trim().strip_prefix("\\x"))?;
+    if selector.len() != 8 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 4];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(selector.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+
+    let signature = signature.trim();
+    let signature = signature
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(signature)
+        .replace("\"\"", "\"");
+    if signature.is_empty() || !signature.ends_with(')') {
+        return None;
+    }
+
+    Some((FixedBytes::from(bytes), signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(signature: &str) -> FixedBytes<4> {
+        FixedBytes::from_slice(&keccak256(signature.as_bytes())[..4])
+    }
+
+    fn calldata(signature: &str, words: usize) -> Vec<u8> {
+        let mut input = selector(signature).to_vec();
+        input.resize(4 + words * 32, 0);
+        input
+    }
+
+    #[test]
+    fn parse_lua_and_plain_lines() {
+        assert_eq!(
+            parse_line(r#"\xa9059cbb,"transfer(address,uint256)""#),
+            Some((
+                FixedBytes::from([0xa9, 0x05, 0x9c, 0xbb]),
+                "transfer(address,uint256)".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_line("0x095ea7b3,approve(address,uint256)").map(|(_, s)| s),
+            Some("approve(address,uint256)".to_string())
+        );
+        assert_eq!(parse_line("0x095ea7,approve(address,uint256)"), None);
+        assert_eq!(parse_line("0x095ea7zz,approve(address,uint256)"), None);
+        assert_eq!(parse_line("095ea7b3,approve(address,uint256)"), None);
+        assert_eq!(parse_line("0x095ea7b3,"), None);
+    }
+
+    #[test]
+    fn split_top_level_params() {
+        assert_eq!(params("f()"), Some(vec![]));
+        assert_eq!(
+            params("f((uint256,bool),address[],bytes)"),
+            Some(vec!["(uint256,bool)", "address[]", "bytes"])
+        );
+        assert_eq!(params("f(uint256"), None);
+        assert_eq!(params("f(uint256))"), None);
+    }
+
+    #[test]
+    fn pick_the_signature_the_calldata_fits() {
+        let transfer = "transfer(address,uint256)";
+        let candidates = vec![
+            "transfer(bytes4[9],bytes5[6],int48[11])".to_string(),
+            "many_msg_babbage(bytes1)".to_string(),
+            transfer.to_string(),
+        ];
+        for candidate in &candidates {
+            assert_eq!(selector(candidate), selector(transfer));
+        }
+
+        let input = calldata(transfer, 2);
+        assert_eq!(
+            pick(selector(transfer), &candidates, &input),
+            Some(transfer)
+        );
+
+        let input = calldata(transfer, 1);
+        assert_eq!(
+            pick(selector(transfer), &candidates, &input),
+            Some("many_msg_babbage(bytes1)")
+        );
+    }
+
+    #[test]
+    fn drop_signatures_not_hashing_to_the_selector() {
+        let candidates = vec!["approve(address,uint256)".to_string()];
+        let transfer = "transfer(address,uint256)";
+
+        assert_eq!(
+            pick(selector(transfer), &candidates, &calldata(transfer, 2)),
+            None
+        );
+    }
+}
@@ -2,19 +2,35 @@
 #![warn(missing_debug_implementations, unreachable_pub, rustdoc::all)]
 #![deny(unused_must_use, rust_2018_idioms)]
 
+pub mod abi;
 pub mod collector;
 pub mod error;
+pub mod event_sig;
+pub mod fn_sig;
 pub mod manager;
+pub mod metrics;
 pub mod networks;
+pub mod nft_metadata;
+pub mod providers;
+pub mod token;
 
 pub use collector::Collector;
 pub use error::Error;
 pub use manager::Manager;
 pub use networks::{ethereum, zksync};
+pub use providers::Providers;
 pub use traits::{Collect as CollectT, Network as NetworkT};
 
 type Result<T> = std::result::Result<T, error::Error>;
 
+/// The resources a network's collector hands over for propagation.
+pub type NetworkResource<N> = eventify_primitives::networks::Resource<
+    <N as NetworkT>::Block,
+    <N as NetworkT>::Log,
+    <N as NetworkT>::Transaction,
+    <N as NetworkT>::Receipt,
+>;
+
 mod traits {
     pub trait Collect<E>
     where
@@ -28,41 +44,47 @@ mod traits {
             &self,
             stop_signal: tokio::sync::watch::Receiver<bool>,
         ) -> impl std::future::Future<Output = Result<(), E>>;
+        fn stream_transactions(
+            &self,
+            stop_signal: tokio::sync::watch::Receiver<bool>,
+        ) -> impl std::future::Future<Output = Result<(), E>>;
+        fn stream_receipts(
+            &self,
+            stop_signal: tokio::sync::watch::Receiver<bool>,
+        ) -> impl std::future::Future<Output = Result<(), E>>;
+        fn stream_traces(
+            &self,
+            stop_signal: tokio::sync::watch::Receiver<bool>,
+        ) -> impl std::future::Future<Output = Result<(), E>>;
     }
 
     pub trait Network: 'static + Clone + std::fmt::Debug + Send + Sync {
         type Block: eventify_primitives::BlockT;
         type Log: eventify_primitives::LogT;
+        type Transaction: eventify_primitives::TransactionT;
+        type Receipt: eventify_primitives::ReceiptT;
 
         fn new(client: crate::networks::NetworkClient) -> Self;
         fn client(&self) -> &crate::networks::NetworkClient;
         fn sub_blocks(
             &self,
         ) -> impl std::future::Future<
-            Output = eyre::Result<
-                reconnecting_jsonrpsee_ws_client::Subscription,
+            Output = Result<
+                crate::networks::Subscription,
                 reconnecting_jsonrpsee_ws_client::RpcError,
             >,
         > + Send {
-            (*self.client()).subscribe(
-                "eth_subscribe".to_string(),
-                reconnecting_jsonrpsee_ws_client::rpc_params!["newHeads"],
-                "eth_unsubscribe".to_string(),
-            )
+            self.client().sub_blocks()
         }
         fn sub_logs(
             &self,
         ) -> impl std::future::Future<
-            Output = eyre::Result<
-                reconnecting_jsonrpsee_ws_client::Subscription,
+            Output = Result<
+                crate::networks::Subscription,
                 reconnecting_jsonrpsee_ws_client::RpcError,
             >,
         > + Send {
-            (*self.client()).subscribe(
-                "eth_subscribe".to_string(),
-                reconnecting_jsonrpsee_ws_client::rpc_params!["logs", {}],
-                "eth_unsubscribe".to_string(),
-            )
+            self.client().sub_logs()
         }
     }
 }
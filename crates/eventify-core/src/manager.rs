@@ -2,14 +2,15 @@ use tokio::{
     sync::{mpsc, watch::Receiver},
     task::JoinHandle,
 };
-use tracing::{error, info, warn};
+use tracing::{error, info, instrument::Instrumented, warn};
 
 use crate::{
     collector::Collector,
     traits::{Collect, Network},
+    NetworkResource,
 };
 use eventify_configs::configs::{CollectorConfig, ManagerConfig};
-use eventify_primitives::networks::{Resource, ResourceKind};
+use eventify_primitives::networks::ResourceKind;
 
 #[derive(Debug, Clone)]
 pub struct Manager<N>
@@ -18,7 +19,7 @@ where
 {
     pub manager_config: ManagerConfig,
     pub collector_config: CollectorConfig,
-    queue_rx: mpsc::Sender<Resource<N::Block, N::Log>>,
+    queue_rx: mpsc::Sender<Instrumented<NetworkResource<N>>>,
 }
 
 impl<N> Manager<N>
@@ -28,7 +29,7 @@ where
     pub fn new(
         manager_config: ManagerConfig,
         collector_config: CollectorConfig,
-        queue_rx: mpsc::Sender<Resource<N::Block, N::Log>>,
+        queue_rx: mpsc::Sender<Instrumented<NetworkResource<N>>>,
     ) -> Self {
         Self {
             manager_config,
@@ -72,7 +73,13 @@ where
         Ok(tokio::spawn(async move {
             let stream_result = match resource {
                 ResourceKind::Block => collector.stream_blocks(stop_signal).await,
-                ResourceKind::Log(_) => collector.stream_logs(stop_signal).await,
+                // alerts are raised by the logs streamed
+                ResourceKind::Log(_) | ResourceKind::Alert => {
+                    collector.stream_logs(stop_signal).await
+                }
+                ResourceKind::Transaction => collector.stream_transactions(stop_signal).await,
+                ResourceKind::Receipt => collector.stream_receipts(stop_signal).await,
+                ResourceKind::Trace => collector.stream_traces(stop_signal).await,
             };
 
             match stream_result {
@@ -83,6 +90,9 @@ where
                     crate::Error::EmptyStream => {
                         warn!(err = "Empty stream");
                     }
+                    crate::Error::ProvidersExhausted(network) => {
+                        error!(thread=?resource, network, "Stopped streaming, no provider left");
+                    }
                     crate::Error::SignalRecv(err) => {
                         info!(err = "Received stop signal", ?err);
                     }
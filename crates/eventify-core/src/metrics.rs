@@ -0,0 +1,264 @@
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, HistogramVec,
+    IntCounterVec, IntGaugeVec,
+};
+
+/// Buckets used for the database/queue latency histograms, in seconds.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5,
+];
+
+pub static BLOCKS_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_blocks_received_total",
+        "Number of blocks received from the node subscription",
+        &["network"]
+    )
+    .expect("failed to register eventify_blocks_received_total")
+});
+
+pub static LOGS_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_logs_received_total",
+        "Number of logs received from the node subscription",
+        &["network"]
+    )
+    .expect("failed to register eventify_logs_received_total")
+});
+
+pub static TRANSACTIONS_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_transactions_received_total",
+        "Number of transactions fetched for the blocks received",
+        &["network"]
+    )
+    .expect("failed to register eventify_transactions_received_total")
+});
+
+pub static RECEIPTS_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_receipts_received_total",
+        "Number of receipts fetched for the blocks received",
+        &["network"]
+    )
+    .expect("failed to register eventify_receipts_received_total")
+});
+
+pub static RECEIPT_FALLBACKS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_receipt_fallbacks_total",
+        "Number of blocks whose receipts were fetched one transaction at a time",
+        &["network"]
+    )
+    .expect("failed to register eventify_receipt_fallbacks_total")
+});
+
+pub static TRACES_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_traces_received_total",
+        "Number of calls traced for the blocks received",
+        &["network"]
+    )
+    .expect("failed to register eventify_traces_received_total")
+});
+
+pub static TOKEN_RESOLUTIONS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_token_resolutions_total",
+        "Number of token contracts whose metadata was looked up, by where it was found",
+        &["network", "source"]
+    )
+    .expect("failed to register eventify_token_resolutions_total")
+});
+
+pub static NFT_METADATA_FETCHES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_nft_metadata_fetches_total",
+        "Number of attempts at fetching the metadata of an NFT, by outcome",
+        &["network", "outcome"]
+    )
+    .expect("failed to register eventify_nft_metadata_fetches_total")
+});
+
+pub static FN_SIG_LOOKUPS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_fn_sig_lookups_total",
+        "Number of function selectors resolved, by whether they were cached",
+        &["cache"]
+    )
+    .expect("failed to register eventify_fn_sig_lookups_total")
+});
+
+pub static EVENT_SIG_LOOKUPS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_event_sig_lookups_total",
+        "Number of raw log topics resolved, by whether they were cached",
+        &["cache"]
+    )
+    .expect("failed to register eventify_event_sig_lookups_total")
+});
+
+pub static FETCH_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_fetch_errors_total",
+        "Number of failed requests for the data accompanying a block",
+        &["network", "resource"]
+    )
+    .expect("failed to register eventify_fetch_errors_total")
+});
+
+pub static EVENTS_DECODED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_events_decoded_total",
+        "Number of logs decoded, by resulting log kind",
+        &["network", "kind"]
+    )
+    .expect("failed to register eventify_events_decoded_total")
+});
+
+pub static ABI_DECODE_FAILURES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_abi_decode_failures_total",
+        "Number of logs matching the topic0 of a registered ABI event that didn't decode against it",
+        &["network", "reason"]
+    )
+    .expect("failed to register eventify_abi_decode_failures_total")
+});
+
+pub static RAW_FALLBACKS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_raw_fallbacks_total",
+        "Number of logs with a known signature that couldn't be decoded and were kept raw",
+        &["signature"]
+    )
+    .expect("failed to register eventify_raw_fallbacks_total")
+});
+
+pub static INSERT_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "eventify_insert_duration_seconds",
+        "Time spent inserting a resource into the database",
+        &["network", "resource"],
+        LATENCY_BUCKETS.to_vec()
+    )
+    .expect("failed to register eventify_insert_duration_seconds")
+});
+
+pub static INSERT_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_insert_errors_total",
+        "Number of failed database inserts",
+        &["network", "resource"]
+    )
+    .expect("failed to register eventify_insert_errors_total")
+});
+
+pub static EMIT_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "eventify_emit_duration_seconds",
+        "Time spent pushing a resource to the queue",
+        &["network", "resource"],
+        LATENCY_BUCKETS.to_vec()
+    )
+    .expect("failed to register eventify_emit_duration_seconds")
+});
+
+pub static EMIT_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_emit_errors_total",
+        "Number of failed queue pushes",
+        &["network", "resource"]
+    )
+    .expect("failed to register eventify_emit_errors_total")
+});
+
+pub static CHANNEL_DEPTH: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "eventify_channel_depth",
+        "Number of resources waiting in the collector -> propagator channel",
+        &["network"]
+    )
+    .expect("failed to register eventify_channel_depth")
+});
+
+pub static NODE_RECONNECTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_node_reconnects_total",
+        "Number of times a node subscription was dropped and reconnected, by disconnect reason",
+        &["network", "stream", "provider", "reason"]
+    )
+    .expect("failed to register eventify_node_reconnects_total")
+});
+
+pub static HEAD_BLOCK: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "eventify_head_block",
+        "Number of the latest block received",
+        &["network"]
+    )
+    .expect("failed to register eventify_head_block")
+});
+
+pub static HEAD_LAG: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "eventify_head_lag_seconds",
+        "Difference between the local time and the timestamp of the latest block received",
+        &["network"]
+    )
+    .expect("failed to register eventify_head_lag_seconds")
+});
+
+pub static PROVIDER_HEAD: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "eventify_provider_head_block",
+        "Number of the latest block delivered by each provider",
+        &["network", "provider"]
+    )
+    .expect("failed to register eventify_provider_head_block")
+});
+
+pub static PROVIDER_DISAGREEMENTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_provider_disagreements_total",
+        "Number of blocks a provider reported with a different hash than the accepted one",
+        &["network", "provider"]
+    )
+    .expect("failed to register eventify_provider_disagreements_total")
+});
+
+pub static DUPLICATES_DROPPED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_duplicates_dropped_total",
+        "Number of blocks/logs dropped because another provider already delivered them",
+        &["network", "stream"]
+    )
+    .expect("failed to register eventify_duplicates_dropped_total")
+});
+
+pub static GAPS_DETECTED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_gaps_detected_total",
+        "Number of times blocks/logs were found missing from the stream",
+        &["network", "stream"]
+    )
+    .expect("failed to register eventify_gaps_detected_total")
+});
+
+pub static BACKFILLED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_backfilled_total",
+        "Number of missed blocks/logs fetched after the stream resumed",
+        &["network", "stream"]
+    )
+    .expect("failed to register eventify_backfilled_total")
+});
+
+pub static BACKFILL_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "eventify_backfill_errors_total",
+        "Number of failed requests while fetching missed blocks/logs",
+        &["network", "stream"]
+    )
+    .expect("failed to register eventify_backfill_errors_total")
+});
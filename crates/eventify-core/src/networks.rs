@@ -8,41 +8,383 @@ pub mod optimism;
 pub mod polygon;
 pub mod zksync;
 
-use std::{ops::Deref, sync::Arc};
+use std::{pin::Pin, sync::Arc, time::Duration};
 
-use reconnecting_jsonrpsee_ws_client::{Client, RpcError};
+use alloy_primitives::{Address, Bytes, B256, U64};
+use futures::Stream;
+use jsonrpsee::{
+    core::{client::ClientT, traits::ToRpcParams},
+    http_client::{HttpClient, HttpClientBuilder},
+};
+use reconnecting_jsonrpsee_ws_client::{
+    rpc_params, Client, DisconnectWillReconnect, RpcError, SubscriptionResult,
+};
+use serde_json::value::RawValue;
+use tokio::sync::mpsc;
+use tracing::{trace, warn};
+
+use eventify_configs::configs::{ConnectionConfig, TraceApi};
+
+/// Maximum amount of blocks delivered by a single poll, so that catching up after the node was
+/// unreachable for a while doesn't result in huge `eth_getLogs` responses.
+const MAX_POLL_RANGE: u64 = 100;
+
+/// A stream of `newHeads`/`logs` notifications, either pushed by the node or polled for.
+pub type Subscription = Pin<Box<dyn Stream<Item = SubscriptionResult> + Send>>;
 
 #[derive(Clone)]
 pub struct NetworkClient {
-    inner: Arc<Client>,
+    transport: Transport,
     pub host: String,
 }
 
+#[derive(Clone)]
+enum Transport {
+    Ws(Arc<Client>),
+    /// Nodes without WebSocket support are polled on an interval instead of subscribed to
+    Http {
+        client: Arc<HttpClient>,
+        poll_interval: Duration,
+        backoff: Backoff,
+    },
+}
+
+/// Exponential backoff between reconnection attempts, doubling from the initial delay up to the
+/// configured cap, optionally jittered and limited to `max_retries` attempts.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    remaining: Option<usize>,
+}
+
+impl Backoff {
+    pub fn new(config: &ConnectionConfig) -> Self {
+        Self {
+            delay: config.retry_initial(),
+            max_delay: config.retry_max_delay(),
+            jitter: config.retry_jitter,
+            remaining: config.max_retries,
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(remaining) = self.remaining.as_mut() {
+            if *remaining == 0 {
+                return None;
+            }
+            *remaining -= 1;
+        }
+
+        let delay = self.delay.min(self.max_delay);
+        self.delay = self.delay.saturating_mul(2);
+
+        if self.jitter {
+            Some(delay / 2 + reconnecting_jsonrpsee_ws_client::jitter(delay / 2))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
 impl NetworkClient {
-    pub async fn new(host: String) -> Result<NetworkClient, RpcError> {
-        Ok(Self {
-            inner: std::sync::Arc::new(
+    pub async fn new(host: String, config: &ConnectionConfig) -> Result<NetworkClient, RpcError> {
+        let transport = if host.starts_with("http://") || host.starts_with("https://") {
+            Transport::Http {
+                client: Arc::new(
+                    HttpClientBuilder::default()
+                        .request_timeout(config.request_timeout())
+                        .build(host.clone())?,
+                ),
+                poll_interval: config.poll_interval(),
+                backoff: Backoff::new(config),
+            }
+        } else {
+            Transport::Ws(Arc::new(
                 reconnecting_jsonrpsee_ws_client::Client::builder()
-                    .retry_policy(reconnecting_jsonrpsee_ws_client::FixedInterval::from_millis(500))
+                    .retry_policy(Backoff::new(config))
                     .enable_ws_ping(
                         reconnecting_jsonrpsee_ws_client::PingConfig::new()
-                            .ping_interval(std::time::Duration::from_secs(6))
-                            .inactive_limit(std::time::Duration::from_secs(30)),
+                            .ping_interval(config.ping_interval())
+                            .inactive_limit(config.inactive_limit()),
                     )
+                    .request_timeout(config.request_timeout())
+                    .connection_timeout(config.connection_timeout())
                     .build(host.clone())
                     .await?,
-            ),
-            host,
-        })
+            ))
+        };
+
+        Ok(Self { transport, host })
     }
-}
 
-impl Deref for NetworkClient {
-    type Target = Client;
+    pub async fn request_raw<P>(&self, method: &str, params: P) -> Result<Box<RawValue>, RpcError>
+    where
+        P: ToRpcParams + Send,
+    {
+        match &self.transport {
+            Transport::Ws(client) => client.request(method.to_string(), params).await,
+            Transport::Http { client, .. } => client.request(method, params).await,
+        }
+    }
+
+    pub async fn request<R, P>(&self, method: &str, params: P) -> Result<R, RpcError>
+    where
+        R: serde::de::DeserializeOwned,
+        P: ToRpcParams + Send,
+    {
+        let raw = self.request_raw(method, params).await?;
+        serde_json::from_str(raw.get()).map_err(RpcError::ParseError)
+    }
+
+    /// Streams the new block headers, through `eth_subscribe` or by polling `eth_blockNumber`.
+    pub async fn sub_blocks(&self) -> Result<Subscription, RpcError> {
+        match &self.transport {
+            Transport::Ws(client) => Ok(Box::pin(
+                client
+                    .subscribe(
+                        "eth_subscribe".to_string(),
+                        rpc_params!["newHeads"],
+                        "eth_unsubscribe".to_string(),
+                    )
+                    .await?,
+            )),
+            Transport::Http {
+                poll_interval,
+                backoff,
+                ..
+            } => {
+                let head = self.block_number().await?;
+                Ok(self.poll(Poll::Blocks, head, *poll_interval, backoff.clone()))
+            }
+        }
+    }
+
+    /// Streams every new log, through `eth_subscribe` or by polling `eth_getLogs`.
+    pub async fn sub_logs(&self) -> Result<Subscription, RpcError> {
+        match &self.transport {
+            Transport::Ws(client) => Ok(Box::pin(
+                client
+                    .subscribe(
+                        "eth_subscribe".to_string(),
+                        rpc_params!["logs", {}],
+                        "eth_unsubscribe".to_string(),
+                    )
+                    .await?,
+            )),
+            Transport::Http {
+                poll_interval,
+                backoff,
+                ..
+            } => {
+                let head = self.block_number().await?;
+                Ok(self.poll(Poll::Logs, head, *poll_interval, backoff.clone()))
+            }
+        }
+    }
+
+    /// The block `number` with the hashes of its transactions.
+    pub async fn block(&self, number: u64) -> Result<Box<RawValue>, RpcError> {
+        self.request_raw(
+            "eth_getBlockByNumber",
+            rpc_params![U64::from(number), false],
+        )
+        .await
+    }
+
+    /// The full transactions included in the block `hash`.
+    pub async fn transactions(&self, hash: B256) -> Result<Vec<Box<RawValue>>, RpcError> {
+        #[derive(serde::Deserialize)]
+        struct FullBlock {
+            transactions: Vec<Box<RawValue>>,
+        }
+
+        self.request::<Option<FullBlock>, _>("eth_getBlockByHash", rpc_params![hash, true])
+            .await
+            .map(|block| block.map(|b| b.transactions).unwrap_or_default())
+    }
+
+    /// The hashes of the transactions included in the block `number`.
+    pub async fn transaction_hashes(&self, number: u64) -> Result<Vec<B256>, RpcError> {
+        #[derive(serde::Deserialize)]
+        struct Block {
+            transactions: Vec<B256>,
+        }
+
+        self.request::<Option<Block>, _>(
+            "eth_getBlockByNumber",
+            rpc_params![U64::from(number), false],
+        )
+        .await
+        .map(|block| block.map(|b| b.transactions).unwrap_or_default())
+    }
+
+    /// Every receipt of the block `number`, in a single `eth_getBlockReceipts` call.
+    pub async fn block_receipts(&self, number: u64) -> Result<Vec<Box<RawValue>>, RpcError> {
+        self.request::<Option<Vec<Box<RawValue>>>, _>(
+            "eth_getBlockReceipts",
+            rpc_params![U64::from(number)],
+        )
+        .await
+        .map(Option::unwrap_or_default)
+    }
+
+    /// The receipt of the transaction `hash`, `None` while it's still pending.
+    pub async fn receipt(&self, hash: B256) -> Result<Option<Box<RawValue>>, RpcError> {
+        self.request("eth_getTransactionReceipt", rpc_params![hash])
+            .await
+    }
+
+    /// The call traces of every transaction in the block `number`, in the shape `api` returns
+    /// them: one call tree per transaction for `debug_traceBlockByNumber`, a flat list of calls
+    /// for `trace_block`.
+    pub async fn traces(&self, number: u64, api: TraceApi) -> Result<Vec<Box<RawValue>>, RpcError> {
+        match api {
+            TraceApi::Debug => {
+                self.request(
+                    "debug_traceBlockByNumber",
+                    rpc_params![
+                        U64::from(number),
+                        serde_json::json!({ "tracer": "callTracer" })
+                    ],
+                )
+                .await
+            }
+            TraceApi::Parity => self
+                .request::<Option<Vec<Box<RawValue>>>, _>(
+                    "trace_block",
+                    rpc_params![U64::from(number)],
+                )
+                .await
+                .map(Option::unwrap_or_default),
+        }
+    }
+
+    /// Every log emitted in the blocks `from..=to`.
+    pub async fn logs(&self, from: u64, to: u64) -> Result<Vec<Box<RawValue>>, RpcError> {
+        self.request(
+            "eth_getLogs",
+            rpc_params![serde_json::json!({
+                "fromBlock": U64::from(from),
+                "toBlock": U64::from(to),
+            })],
+        )
+        .await
+    }
+
+    /// The output of calling `to` with `data` at the latest block, through `eth_call`.
+    pub async fn call(&self, to: Address, data: Bytes) -> Result<Bytes, RpcError> {
+        self.request(
+            "eth_call",
+            rpc_params![serde_json::json!({ "to": to, "data": data }), "latest"],
+        )
+        .await
+    }
+
+    pub async fn block_number(&self) -> Result<u64, RpcError> {
+        self.request::<U64, _>("eth_blockNumber", rpc_params![])
+            .await
+            .map(|n| n.to::<u64>())
+    }
+
+    /// Polls the node every `interval`, delivering whatever was produced after block `from`.
+    /// Blocks the node doesn't know about yet are waited for rather than skipped.
+    ///
+    /// Failed requests are reported the same way a dropped subscription is, and the blocks they
+    /// were meant to cover are retried after the `backoff` delay. Once the backoff runs out of
+    /// retries the stream ends.
+    fn poll(&self, kind: Poll, from: u64, interval: Duration, backoff: Backoff) -> Subscription {
+        let (tx, mut rx) = mpsc::channel::<SubscriptionResult>(1024);
+        let client = self.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut retries = backoff.clone();
+            let mut next = from + 1;
+
+            loop {
+                ticker.tick().await;
+                if tx.is_closed() {
+                    break;
+                }
+
+                let items = match client.poll_once(kind, next).await {
+                    Ok((items, polled_to)) => {
+                        next = polled_to + 1;
+                        retries = backoff.clone();
+                        items.into_iter().map(Ok).collect()
+                    }
+                    Err(err) => {
+                        warn!(kind = "poll_error", host = client.host, err = ?err);
+                        let Some(delay) = retries.next() else {
+                            warn!(
+                                kind = "poll_error",
+                                host = client.host,
+                                "giving up after too many failed polls"
+                            );
+                            let _ = tx.send(Err(DisconnectWillReconnect(err))).await;
+                            break;
+                        };
+                        tokio::time::sleep(delay).await;
+                        vec![Err(DisconnectWillReconnect(err))]
+                    }
+                };
+
+                for item in items {
+                    if tx.send(item).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+        Box::pin(futures::stream::poll_fn(move |cx| rx.poll_recv(cx)))
     }
+
+    /// Fetches the blocks/logs from `from` towards the current head, returning them along with the
+    /// last block number they cover.
+    async fn poll_once(
+        &self,
+        kind: Poll,
+        from: u64,
+    ) -> Result<(Vec<Box<RawValue>>, u64), RpcError> {
+        let head = self.block_number().await?;
+        if head < from {
+            return Ok((Vec::new(), from - 1));
+        }
+        let to = head.min(from + MAX_POLL_RANGE - 1);
+        trace!(kind = ?kind, from, to, head);
+
+        match kind {
+            Poll::Blocks => {
+                let mut blocks = Vec::new();
+                for number in from..=to {
+                    let block = self.block(number).await?;
+                    // nodes behind a load balancer may not serve the head another one reported
+                    // yet, the block is polled for again on the next tick
+                    if block.get() == "null" {
+                        return Ok((blocks, number - 1));
+                    }
+                    blocks.push(block);
+                }
+
+                Ok((blocks, to))
+            }
+            Poll::Logs => Ok((self.logs(from, to).await?, to)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Poll {
+    Blocks,
+    Logs,
 }
 
 impl std::fmt::Display for NetworkClient {
@@ -56,3 +398,104 @@ impl std::fmt::Debug for NetworkClient {
         write!(f, "{}", stringify!(NetworkClient))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::{Matcher, Mock, ServerGuard};
+
+    use super::*;
+
+    /// Answers the JSON-RPC calls to `method` with `result`, echoing their id.
+    async fn rpc(server: &mut ServerGuard, method: &str, result: serde_json::Value) -> Mock {
+        server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(
+                serde_json::json!({ "method": method }),
+            ))
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |request| {
+                let request: serde_json::Value =
+                    serde_json::from_slice(request.body().unwrap()).unwrap();
+                serde_json::json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+                    .to_string()
+                    .into_bytes()
+            })
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn polling_waits_for_null_blocks() {
+        let mut server = mockito::Server::new_async().await;
+        let config = ConnectionConfig {
+            poll_interval_ms: 10,
+            ..Default::default()
+        };
+
+        let head = rpc(&mut server, "eth_blockNumber", "0x1".into()).await;
+        let client = NetworkClient::new(server.url(), &config).await.unwrap();
+        let mut blocks = client.sub_blocks().await.unwrap();
+        head.remove_async().await;
+
+        let _head = rpc(&mut server, "eth_blockNumber", "0x2".into()).await;
+        let missing = rpc(&mut server, "eth_getBlockByNumber", serde_json::Value::Null).await;
+        let polled = tokio::time::timeout(Duration::from_millis(200), blocks.next()).await;
+        assert!(polled.is_err(), "{polled:?}");
+        missing.remove_async().await;
+
+        let _block = rpc(
+            &mut server,
+            "eth_getBlockByNumber",
+            serde_json::json!({ "number": "0x2" }),
+        )
+        .await;
+        let block = tokio::time::timeout(Duration::from_secs(5), blocks.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(block.get(), r#"{"number":"0x2"}"#);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let config = ConnectionConfig {
+            retry_initial_ms: 100,
+            retry_max_delay_ms: 500,
+            retry_jitter: false,
+            ..Default::default()
+        };
+
+        let delays: Vec<u64> = Backoff::new(&config)
+            .take(5)
+            .map(|d| d.as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+    }
+
+    #[test]
+    fn backoff_stops_after_max_retries() {
+        let config = ConnectionConfig {
+            max_retries: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(Backoff::new(&config).count(), 3);
+    }
+
+    #[test]
+    fn backoff_jitter_stays_within_bounds() {
+        let config = ConnectionConfig {
+            retry_initial_ms: 1000,
+            retry_max_delay_ms: 1000,
+            retry_jitter: true,
+            ..Default::default()
+        };
+
+        for delay in Backoff::new(&config).take(100) {
+            assert!(delay >= Duration::from_millis(500));
+            assert!(delay <= Duration::from_millis(1000));
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::arbitrum::{Block, Log};
+use eventify_primitives::arbitrum::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Arbitrum {
@@ -9,6 +9,8 @@ pub struct Arbitrum {
 impl Network for Arbitrum {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Arbitrum {
         Arbitrum { client }
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::avalanche::{Block, Log};
+use eventify_primitives::avalanche::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Avalanche {
@@ -9,6 +9,8 @@ pub struct Avalanche {
 impl Network for Avalanche {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Avalanche {
         Avalanche { client }
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::base::{Block, Log};
+use eventify_primitives::base::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Base {
@@ -9,6 +9,8 @@ pub struct Base {
 impl Network for Base {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Base {
         Base { client }
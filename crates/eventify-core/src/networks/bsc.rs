@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::bsc::{Block, Log};
+use eventify_primitives::bsc::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Bsc {
@@ -9,6 +9,8 @@ pub struct Bsc {
 impl Network for Bsc {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Bsc {
         Bsc { client }
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::ethereum::{Block, Log};
+use eventify_primitives::ethereum::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Eth {
@@ -9,6 +9,8 @@ pub struct Eth {
 impl Network for Eth {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Eth {
         Eth { client }
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::linea::{Block, Log};
+use eventify_primitives::linea::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Linea {
@@ -9,6 +9,8 @@ pub struct Linea {
 impl Network for Linea {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Linea {
         Linea { client }
@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::optimism::{Block, Log};
+use eventify_primitives::optimism::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Optimism {
@@ -9,6 +9,8 @@ pub struct Optimism {
 impl Network for Optimism {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Optimism {
         Optimism { client }
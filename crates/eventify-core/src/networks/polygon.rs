@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::polygon::{Block, Log};
+use eventify_primitives::polygon::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Polygon {
@@ -9,6 +9,8 @@ pub struct Polygon {
 impl Network for Polygon {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Polygon {
         Polygon { client }
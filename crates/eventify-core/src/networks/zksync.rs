@@ -1,5 +1,5 @@
 use crate::{networks::NetworkClient, traits::Network};
-use eventify_primitives::zksync::{Block, Log};
+use eventify_primitives::zksync::{Block, Log, Receipt, Transaction};
 
 #[derive(Clone, Debug)]
 pub struct Zksync {
@@ -9,6 +9,8 @@ pub struct Zksync {
 impl Network for Zksync {
     type Block = Block;
     type Log = Log;
+    type Transaction = Transaction;
+    type Receipt = Receipt;
 
     fn new(client: NetworkClient) -> Zksync {
         Zksync { client }
@@ -0,0 +1,581 @@
+//! Fetching of the JSON metadata ERC721 and ERC1155 tokens point to, kept in the `nft_metadata`
+//! table.
+//!
+//! Every token moved by a transfer is queued once as the transfer is stored, its URI being asked
+//! of its contract (`tokenURI`/`uri`) unless an ERC1155 `URI` event set it already. The document
+//! the URI points to is then read from HTTP(S), through the configured IPFS gateways or from the
+//! URI itself for `data:` URIs, failed attempts being retried with an exponential backoff until
+//! given up on.
+//!
+//! The URIs being up to whoever deployed the contract, they're kept from reaching loopback,
+//! link-local and private addresses unless told otherwise.
+
+use std::{
+    collections::HashSet,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use alloy_primitives::{hex, Address, Bytes, U256};
+use alloy_sol_types::{sol, SolCall};
+use base64::Engine as _;
+use futures::{stream, StreamExt};
+use hyper::client::connect::dns::Name;
+use percent_encoding::percent_decode_str;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    redirect,
+};
+use sqlx::PgPool;
+use tokio::sync::watch::Receiver;
+use tracing::{debug, info, warn};
+use url::{Host, Url};
+
+use crate::{metrics, networks::NetworkClient};
+use eventify_configs::configs::NftMetadataConfig;
+use eventify_primitives::networks::NetworkKind;
+
+sol! {
+    interface INft {
+        function tokenURI(uint256 tokenId) external view returns (string);
+        function uri(uint256 id) external view returns (string);
+    }
+}
+
+/// A queued token whose metadata is due to be fetched.
+#[derive(Debug, sqlx::FromRow)]
+struct Pending {
+    contract: Vec<u8>,
+    token_id: String,
+    standard: String,
+    uri: Option<String>,
+    attempts: i32,
+}
+
+/// How fetching the metadata of a token went.
+#[derive(Debug)]
+enum Outcome {
+    Fetched(serde_json::Value),
+    /// Failed, to be retried unless out of attempts
+    Retry(String),
+    /// Failed for good, e.g. the URI's scheme isn't supported
+    Failed(String),
+}
+
+/// Where the metadata of a token is read from.
+#[derive(Debug, PartialEq, Eq)]
+enum Source {
+    /// Tried in order until one serves a JSON document
+    Urls(Vec<String>),
+    /// Embedded in a `data:` URI
+    Inline(Vec<u8>),
+}
+
+/// Number of redirects followed per URI, as many as reqwest follows by default.
+const MAX_REDIRECTS: usize = 10;
+
+/// Keeps the requests for the URIs tokens point to to public addresses, checking the hosts
+/// after they're resolved and on every redirect. The configured gateways aren't checked.
+#[derive(Debug, Clone)]
+struct Guard {
+    /// the hosts and ports of the gateways
+    gateways: Arc<HashSet<(String, Option<u16>)>>,
+}
+
+impl Guard {
+    fn new(gateways: &[String]) -> Self {
+        Self {
+            gateways: Arc::new(
+                gateways
+                    .iter()
+                    .filter_map(|gateway| authority(&Url::parse(gateway).ok()?))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Whether `url` may be requested, as far as can be told before resolving its host.
+    fn check(&self, url: &Url) -> Result<(), String> {
+        let authority = authority(url).ok_or_else(|| "no host".to_string())?;
+        if self.gateways.contains(&authority) {
+            return Ok(());
+        }
+
+        let ip = match url.host() {
+            Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+            Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+            _ => return Ok(()),
+        };
+        if is_public(ip) {
+            Ok(())
+        } else {
+            Err(format!("{ip} isn't a public address"))
+        }
+    }
+}
+
+impl Resolve for Guard {
+    fn resolve(&self, name: Name) -> Resolving {
+        let gateway = self.gateways.iter().any(|(host, _)| host == name.as_str());
+        let host = name.as_str().to_string();
+
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| gateway || is_public(addr.ip()))
+                .collect::<Vec<SocketAddr>>();
+            if addrs.is_empty() {
+                return Err(format!("{host} doesn't resolve to a public address").into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// The host and port `url` is served from.
+fn authority(url: &Url) -> Option<(String, Option<u16>)> {
+    Some((url.host_str()?.to_string(), url.port_or_known_default()))
+}
+
+/// Whether `ip` is reachable from the internet, i.e. isn't a loopback, link-local, private,
+/// shared, documentation, multicast or unspecified address.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // "this network" and the carrier-grade NAT range
+                || a == 0
+                || (a == 100 && (b & 0xc0) == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(IpAddr::V4(ip)),
+            None => {
+                let segment = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // unique local and link-local
+                    || (segment & 0xfe00) == 0xfc00
+                    || (segment & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+/// Fetches the metadata of the tokens of a network.
+#[derive(Debug, Clone)]
+pub struct MetadataFetcher {
+    network: NetworkKind,
+    client: NetworkClient,
+    pool: PgPool,
+    http: reqwest::Client,
+    /// unset if the URIs may point anywhere
+    guard: Option<Guard>,
+    config: NftMetadataConfig,
+}
+
+impl MetadataFetcher {
+    pub fn new(
+        network: NetworkKind,
+        client: NetworkClient,
+        pool: PgPool,
+        config: NftMetadataConfig,
+    ) -> crate::Result<Self> {
+        let guard = (!config.allow_private_targets).then(|| Guard::new(&config.gateways));
+        let mut http = reqwest::Client::builder()
+            .timeout(config.timeout())
+            .user_agent(concat!("eventify/", env!("CARGO_PKG_VERSION")));
+        if let Some(guard) = guard.clone() {
+            let redirects = guard.clone();
+            http = http
+                .dns_resolver(Arc::new(guard))
+                .redirect(redirect::Policy::custom(move |attempt| {
+                    if attempt.previous().len() >= MAX_REDIRECTS {
+                        attempt.error("too many redirects")
+                    } else if let Err(err) = redirects.check(attempt.url()) {
+                        attempt.error(err)
+                    } else {
+                        attempt.follow()
+                    }
+                }));
+        }
+
+        Ok(Self {
+            network,
+            client,
+            pool,
+            http: http.build()?,
+            guard,
+            config,
+        })
+    }
+
+    /// Fetches the metadata of the tokens as they're queued, until told to stop.
+    pub async fn run(&self, mut stop_signal: Receiver<bool>) -> crate::Result<()> {
+        info!(network = ?self.network, gateways = ?self.config.gateways, "Fetching NFT metadata");
+
+        while !*stop_signal.borrow() {
+            let busy = match self.round().await {
+                Ok(busy) => busy,
+                Err(err) => {
+                    warn!(kind = "nft_metadata_error", network = ?self.network, err = %err);
+                    false
+                }
+            };
+
+            if !busy {
+                tokio::select! {
+                    _ = tokio::time::sleep(self.config.poll_interval()) => {}
+                    changed = stop_signal.changed() => changed?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetches a batch of due tokens, returning whether there was any.
+    async fn round(&self) -> crate::Result<bool> {
+        let due = self.due().await?;
+        let processed = due.len();
+
+        stream::iter(due)
+            .for_each_concurrent(self.config.concurrency.max(1), |token| async move {
+                self.process(token).await
+            })
+            .await;
+
+        Ok(processed > 0)
+    }
+
+    /// The queued tokens due for an attempt, those waiting the longest first.
+    async fn due(&self) -> crate::Result<Vec<Pending>> {
+        let sql = r#"SELECT contract, token_id::text AS token_id, standard, uri, attempts
+            FROM nft_metadata
+            WHERE network = $1 AND status = 'pending' AND next_attempt_at <= NOW()
+            ORDER BY next_attempt_at
+            LIMIT $2"#;
+
+        Ok(sqlx::query_as(sql)
+            .bind(self.network)
+            .bind(self.config.batch_size)
+            .fetch_all(&self.pool)
+            .await?)
+    }
+
+    /// Fetches the metadata of `token`, resolving its URI first if unknown, and records how it
+    /// went.
+    async fn process(&self, token: Pending) {
+        let (Ok(contract), Ok(id)) = (
+            Address::try_from(token.contract.as_slice()),
+            token.token_id.parse::<U256>(),
+        ) else {
+            return;
+        };
+        let erc1155 = token.standard == "erc1155";
+
+        let (uri, outcome) = match token.uri.clone() {
+            Some(uri) => {
+                let outcome = self.fetch(&uri, id, erc1155).await;
+                (Some(uri), outcome)
+            }
+            None => match self.token_uri(contract, id, erc1155).await {
+                Ok(uri) => {
+                    let outcome = self.fetch(&uri, id, erc1155).await;
+                    (Some(uri), outcome)
+                }
+                Err(err) => (None, Outcome::Retry(err)),
+            },
+        };
+
+        let attempts = token.attempts.max(0) as u32 + 1;
+        let (status, metadata, error) = match outcome {
+            Outcome::Fetched(metadata) => ("fetched", Some(metadata.to_string()), None),
+            Outcome::Retry(err) if attempts < self.config.max_attempts => {
+                ("pending", None, Some(err))
+            }
+            Outcome::Retry(err) | Outcome::Failed(err) => ("failed", None, Some(err)),
+        };
+        metrics::NFT_METADATA_FETCHES
+            .with_label_values(&[&self.network.to_string(), status])
+            .inc();
+        if let Some(err) = &error {
+            debug!(kind = "nft_metadata_fetch", %contract, %id, attempts, status, err);
+        }
+
+        // left alone if a `URI` event changed the URI in the meantime
+        let sql = r#"UPDATE nft_metadata SET
+                uri = $4,
+                status = $5,
+                metadata = COALESCE($6::jsonb, metadata),
+                error = $7,
+                attempts = $8,
+                next_attempt_at = NOW() + make_interval(secs => $9),
+                fetched_at = CASE WHEN $5 = 'fetched' THEN NOW() ELSE fetched_at END,
+                updated_at = NOW()
+            WHERE network = $1
+                AND contract = $2
+                AND token_id = $3::numeric
+                AND uri IS NOT DISTINCT FROM $10"#;
+
+        if let Err(err) = sqlx::query(sql)
+            .bind(self.network)
+            .bind(contract.as_slice())
+            .bind(&token.token_id)
+            .bind(uri.as_deref())
+            .bind(status)
+            .bind(metadata)
+            .bind(error)
+            .bind(attempts as i32)
+            .bind(self.config.retry_delay(attempts).as_secs_f64())
+            .bind(token.uri.as_deref())
+            .execute(&self.pool)
+            .await
+        {
+            warn!(kind = "nft_metadata_update_error", %contract, %id, err = %err);
+        }
+    }
+
+    /// The URI the contract returns for the token `id`.
+    async fn token_uri(
+        &self,
+        contract: Address,
+        id: U256,
+        erc1155: bool,
+    ) -> Result<String, String> {
+        let data = if erc1155 {
+            INft::uriCall { id }.abi_encode()
+        } else {
+            INft::tokenURICall { tokenId: id }.abi_encode()
+        };
+        let output = self
+            .client
+            .call(contract, Bytes::from(data))
+            .await
+            .map_err(|err| format!("eth_call: {err}"))?;
+
+        // both return a single string
+        INft::tokenURICall::abi_decode_returns(&output, true)
+            .map(|r| r._0)
+            .map_err(|err| format!("invalid uri: {err}"))
+    }
+
+    /// Reads the JSON document `uri` points to for the token `id`.
+    async fn fetch(&self, uri: &str, id: U256, erc1155: bool) -> Outcome {
+        let uri = if erc1155 {
+            substitute(uri, id)
+        } else {
+            uri.to_string()
+        };
+
+        let urls = match source(&uri, &self.config.gateways) {
+            Ok(Source::Inline(body)) if body.len() > self.config.max_size_bytes => {
+                return Outcome::Failed("metadata too large".to_string())
+            }
+            Ok(Source::Inline(body)) => {
+                return match serde_json::from_slice(&body) {
+                    Ok(metadata) => Outcome::Fetched(metadata),
+                    Err(err) => Outcome::Failed(format!("invalid json: {err}")),
+                }
+            }
+            Ok(Source::Urls(urls)) => urls,
+            Err(err) => return Outcome::Failed(err),
+        };
+
+        let mut errors = Vec::new();
+        for url in urls {
+            match self.get(&url).await.and_then(|body| {
+                serde_json::from_slice(&body).map_err(|err| format!("invalid json: {err}"))
+            }) {
+                Ok(metadata) => return Outcome::Fetched(metadata),
+                Err(err) => errors.push(format!("{url}: {err}")),
+            }
+        }
+
+        Outcome::Retry(errors.join("; "))
+    }
+
+    /// The body served at `url`, as long as it's not larger than allowed.
+    async fn get(&self, url: &str) -> Result<Vec<u8>, String> {
+        let url = Url::parse(url).map_err(|err| err.to_string())?;
+        if let Some(guard) = &self.guard {
+            guard.check(&url)?;
+        }
+
+        let mut response = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| err.to_string())?;
+        let too_large = |len: usize| len > self.config.max_size_bytes;
+
+        if response
+            .content_length()
+            .is_some_and(|len| too_large(len as usize))
+        {
+            return Err("metadata too large".to_string());
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|err| err.to_string())? {
+            body.extend_from_slice(&chunk);
+            if too_large(body.len()) {
+                return Err("metadata too large".to_string());
+            }
+        }
+
+        Ok(body)
+    }
+}
+
+/// `uri` with `{id}` replaced by the token id as 64 lowercase hex digits, as ERC1155 clients do.
+fn substitute(uri: &str, id: U256) -> String {
+    uri.replace("{id}", &hex::encode(id.to_be_bytes::<32>()))
+}
+
+/// Where the document `uri` points to is read from, `ipfs://` URIs going through `gateways`.
+fn source(uri: &str, gateways: &[String]) -> Result<Source, String> {
+    let uri = uri.trim();
+
+    if let Some(data) = uri.strip_prefix("data:") {
+        let (params, payload) = data
+            .split_once(',')
+            .ok_or_else(|| "invalid data uri".to_string())?;
+        return if params
+            .split(';')
+            .any(|param| param.eq_ignore_ascii_case("base64"))
+        {
+            base64::engine::general_purpose::STANDARD
+                .decode(payload.trim())
+                .map(Source::Inline)
+                .map_err(|err| format!("invalid data uri: {err}"))
+        } else {
+            Ok(Source::Inline(percent_decode_str(payload).collect()))
+        };
+    }
+
+    if let Some(path) = uri.strip_prefix("ipfs://") {
+        if gateways.is_empty() {
+            return Err("no ipfs gateway configured".to_string());
+        }
+        let path = path.strip_prefix("ipfs/").unwrap_or(path);
+        return Ok(Source::Urls(
+            gateways
+                .iter()
+                .map(|gateway| format!("{}/{}", gateway.trim_end_matches('/'), path))
+                .collect(),
+        ));
+    }
+
+    if uri.starts_with("https://") || uri.starts_with("http://") {
+        return Ok(Source::Urls(vec![uri.to_string()]));
+    }
+
+    Err(format!(
+        "unsupported uri: {}",
+        uri.chars().take(64).collect::<String>()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_erc1155_ids() {
+        assert_eq!(
+            substitute("https://token-cdn-domain/{id}.json", U256::from(314592)),
+            "https://token-cdn-domain/000000000000000000000000000000000000000000000000000000000004cce0.json"
+        );
+        assert_eq!(
+            substitute("https://example.com/1.json", U256::from(2)),
+            "https://example.com/1.json"
+        );
+    }
+
+    #[test]
+    fn guards_private_targets() {
+        let guard = Guard::new(&["http://127.0.0.1:8080/ipfs/".to_string()]);
+        let check = |url: &str| guard.check(&Url::parse(url).unwrap());
+
+        assert!(check("https://example.com/1.json").is_ok());
+        assert!(check("http://1.1.1.1/1.json").is_ok());
+        assert!(check("http://127.0.0.1:8080/ipfs/QmXyz").is_ok());
+
+        assert!(check("http://127.0.0.1/1.json").is_err());
+        assert!(check("http://169.254.169.254/latest/meta-data").is_err());
+        assert!(check("http://10.0.0.1/1.json").is_err());
+        assert!(check("http://192.168.1.1/1.json").is_err());
+        assert!(check("http://100.64.0.1/1.json").is_err());
+        assert!(check("http://[::1]/1.json").is_err());
+        assert!(check("http://[fe80::1]/1.json").is_err());
+        assert!(check("http://[fd00::1]/1.json").is_err());
+        assert!(check("http://[::ffff:127.0.0.1]/1.json").is_err());
+    }
+
+    #[tokio::test]
+    async fn resolves_public_addresses_only() {
+        let guard = Guard::new(&[]);
+        let resolve = |host: &str| guard.resolve(host.parse::<Name>().unwrap());
+
+        assert!(resolve("localhost").await.is_err());
+        assert!(Guard::new(&["http://localhost:8080".to_string()])
+            .resolve("localhost".parse::<Name>().unwrap())
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn resolves_sources() {
+        let gateways = vec![
+            "https://ipfs.io/ipfs/".to_string(),
+            "https://dweb.link/ipfs".to_string(),
+        ];
+
+        assert_eq!(
+            source("ipfs://QmXyz/1.json", &gateways),
+            Ok(Source::Urls(vec![
+                "https://ipfs.io/ipfs/QmXyz/1.json".to_string(),
+                "https://dweb.link/ipfs/QmXyz/1.json".to_string(),
+            ]))
+        );
+        assert_eq!(
+            source("ipfs://ipfs/QmXyz", &gateways[..1]),
+            Ok(Source::Urls(vec!["https://ipfs.io/ipfs/QmXyz".to_string()]))
+        );
+        assert!(source("ipfs://QmXyz", &[]).is_err());
+        assert_eq!(
+            source(" https://example.com/1 ", &gateways),
+            Ok(Source::Urls(vec!["https://example.com/1".to_string()]))
+        );
+
+        assert_eq!(
+            source("data:application/json;base64,eyJuYW1lIjoiQSJ9", &gateways),
+            Ok(Source::Inline(br#"{"name":"A"}"#.to_vec()))
+        );
+        assert_eq!(
+            source(
+                "data:application/json,%7B%22name%22%3A%22A%22%7D",
+                &gateways
+            ),
+            Ok(Source::Inline(br#"{"name":"A"}"#.to_vec()))
+        );
+        assert_eq!(
+            source(r#"data:application/json;utf8,{"name":"A"}"#, &gateways),
+            Ok(Source::Inline(br#"{"name":"A"}"#.to_vec()))
+        );
+        assert!(source("data:application/json;base64", &gateways).is_err());
+
+        assert!(source("ar://abc", &gateways).is_err());
+        assert!(source("", &gateways).is_err());
+    }
+}
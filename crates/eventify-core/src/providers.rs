@@ -0,0 +1,471 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use eventify_configs::configs::ConnectionConfig;
+use futures::{Stream, StreamExt};
+use reconnecting_jsonrpsee_ws_client::{RpcError, SubscriptionResult};
+use tracing::warn;
+
+use crate::{
+    metrics,
+    networks::{NetworkClient, Subscription},
+    NetworkT,
+};
+
+/// How long a single provider is waited on while the collector is being set up.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+/// How long a provider's errors count against it; a provider that recovered is trusted again
+/// once the window it failed in has passed.
+const ERROR_WINDOW: Duration = Duration::from_secs(600);
+
+/// The set of nodes a collector streams from in parallel.
+///
+/// Every provider is subscribed to at the same time, so losing one of them doesn't interrupt the
+/// stream - whatever it would've delivered arrives through the others. The items are tagged with
+/// the index of the provider they came from, which is what the health bookkeeping is keyed by.
+#[derive(Clone, Debug)]
+pub struct Providers<N>
+where
+    N: NetworkT,
+{
+    network: String,
+    nodes: Vec<N>,
+    health: Arc<Mutex<Vec<Health>>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Health {
+    /// Label the provider is reported under; the host of its URL
+    pub name: String,
+    /// Latest block number the provider delivered
+    pub head: u64,
+    /// Number of disconnects/failed subscriptions within the current error window
+    pub errors: u64,
+    /// When the current error window started, i.e. the first error after the previous one expired
+    pub errors_since: Option<Instant>,
+    /// Number of blocks/logs the provider delivered before any other provider
+    pub first: u64,
+}
+
+impl Health {
+    /// Lower is better - the amount of blocks the provider is behind the best head, penalized
+    /// by its recent errors and rewarded by how often it's the first one to deliver.
+    fn score(&self, best_head: u64, now: Instant) -> i64 {
+        let lag = best_head.saturating_sub(self.head) as i64;
+        lag * 10 + self.recent_errors(now) as i64 * 5 - (self.first as i64).min(100)
+    }
+
+    /// The errors of the window that's still open at `now`.
+    fn recent_errors(&self, now: Instant) -> u64 {
+        match self.errors_since {
+            Some(since) if now.duration_since(since) < ERROR_WINDOW => self.errors,
+            _ => 0,
+        }
+    }
+
+    fn record_error(&mut self, now: Instant) {
+        if self.recent_errors(now) == 0 {
+            self.errors = 0;
+            self.errors_since = Some(now);
+        }
+        self.errors += 1;
+    }
+}
+
+impl<N> Providers<N>
+where
+    N: NetworkT,
+{
+    /// Connects to every url concurrently, keeping the ones that could be reached, as long as
+    /// there are enough of them to reach `quorum`.
+    pub async fn connect(
+        network: String,
+        urls: &[String],
+        config: &ConnectionConfig,
+        quorum: usize,
+    ) -> eyre::Result<Self> {
+        let connections = futures::future::join_all(urls.iter().map(|url| async move {
+            (
+                url,
+                tokio::time::timeout(CONNECT_TIMEOUT, NetworkClient::new(url.clone(), config))
+                    .await,
+            )
+        }))
+        .await;
+
+        let mut nodes = Vec::new();
+        let mut health = Vec::new();
+        for (url, connection) in connections {
+            match connection {
+                Ok(Ok(client)) => {
+                    nodes.push(N::new(client));
+                    health.push(Health {
+                        name: provider_name(url, health.len()),
+                        ..Default::default()
+                    });
+                }
+                Ok(Err(err)) => warn!(kind = "provider_error", network, err = ?err),
+                Err(_) => warn!(
+                    kind = "provider_error",
+                    network,
+                    err = "connection timed out"
+                ),
+            }
+        }
+
+        if nodes.is_empty() {
+            eyre::bail!(
+                "none of the {} providers for {} are reachable",
+                urls.len(),
+                network
+            );
+        }
+        check_quorum(&network, quorum, nodes.len(), urls.len())?;
+
+        Ok(Self {
+            network,
+            nodes,
+            health: Arc::new(Mutex::new(health)),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The provider that's currently the most up to date, used for one-off requests.
+    pub fn healthiest(&self) -> &N {
+        let health = self.health.lock().expect("provider health lock poisoned");
+        let best_head = health.iter().map(|h| h.head).max().unwrap_or_default();
+        let now = Instant::now();
+        let idx = health
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, h)| h.score(best_head, now))
+            .map(|(idx, _)| idx)
+            .unwrap_or_default();
+
+        &self.nodes[idx]
+    }
+
+    pub fn health(&self) -> Vec<Health> {
+        self.health
+            .lock()
+            .expect("provider health lock poisoned")
+            .clone()
+    }
+
+    pub async fn sub_blocks(
+        &self,
+    ) -> Result<impl Stream<Item = (usize, SubscriptionResult)> + Unpin, RpcError> {
+        let mut subs = Vec::new();
+        for node in self.nodes.iter() {
+            subs.push(node.sub_blocks().await);
+        }
+
+        self.merge(subs, "blocks")
+    }
+
+    pub async fn sub_logs(
+        &self,
+    ) -> Result<impl Stream<Item = (usize, SubscriptionResult)> + Unpin, RpcError> {
+        let mut subs = Vec::new();
+        for node in self.nodes.iter() {
+            subs.push(node.sub_logs().await);
+        }
+
+        self.merge(subs, "logs")
+    }
+
+    fn merge(
+        &self,
+        subs: Vec<Result<Subscription, RpcError>>,
+        stream: &str,
+    ) -> Result<impl Stream<Item = (usize, SubscriptionResult)> + Unpin, RpcError> {
+        let mut merged = Vec::new();
+        let mut last_err = None;
+
+        for (idx, sub) in subs.into_iter().enumerate() {
+            match sub {
+                Ok(sub) => merged.push(sub.map(move |item| (idx, item))),
+                Err(err) => {
+                    self.record_error(idx, stream, &err);
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        match (merged.is_empty(), last_err) {
+            (true, Some(err)) => Err(err),
+            _ => Ok(futures::stream::select_all(merged)),
+        }
+    }
+
+    /// Records a disconnect of the provider's `stream` subscription.
+    pub fn record_error(&self, idx: usize, stream: &str, err: &RpcError) {
+        let reason = disconnect_reason(err);
+        let mut health = self.health.lock().expect("provider health lock poisoned");
+        if let Some(h) = health.get_mut(idx) {
+            warn!(kind = "disconnect", network = self.network, stream, provider = h.name, reason, err = %err);
+            h.record_error(Instant::now());
+            metrics::NODE_RECONNECTS
+                .with_label_values(&[&self.network, stream, &h.name, reason])
+                .inc();
+        }
+    }
+
+    /// Records that the provider delivered an item from block `number`; `first` tells whether no
+    /// other provider delivered it before.
+    pub fn record_delivery(&self, idx: usize, number: Option<u64>, first: bool) {
+        let mut health = self.health.lock().expect("provider health lock poisoned");
+        if let Some(h) = health.get_mut(idx) {
+            if let Some(number) = number {
+                h.head = h.head.max(number);
+                metrics::PROVIDER_HEAD
+                    .with_label_values(&[&self.network, &h.name])
+                    .set(h.head as i64);
+            }
+            if first {
+                h.first += 1;
+            }
+        }
+    }
+
+    pub fn name(&self, idx: usize) -> String {
+        self.health
+            .lock()
+            .expect("provider health lock poisoned")
+            .get(idx)
+            .map(|h| h.name.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn provider_name(url: &str, idx: usize) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_string()))
+        .unwrap_or_else(|| idx.to_string())
+}
+
+/// Coarse classification of why a provider disconnected, used as a metric label.
+fn disconnect_reason(err: &RpcError) -> &'static str {
+    match err {
+        RpcError::Transport(_) => "transport",
+        RpcError::RestartNeeded(_) => "restart_needed",
+        RpcError::RequestTimeout => "timeout",
+        RpcError::ParseError(_) => "parse",
+        RpcError::Call(_) => "call",
+        _ => "other",
+    }
+}
+
+/// What happened to an item after it was reported by a provider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seen {
+    /// Not enough providers have reported it yet
+    Pending,
+    /// This report made it reach the quorum, the item should be processed
+    Accepted,
+    /// The item was already accepted, or this provider had already reported it
+    Duplicate,
+}
+
+/// Deduplicates the items coming from several providers, accepting each of them once it has been
+/// reported by `quorum` distinct providers.
+///
+/// Only the last `capacity` keys are remembered, which is plenty given that the providers are
+/// expected to deliver the same items within moments of each other.
+#[derive(Debug)]
+pub struct Quorum<K> {
+    quorum: usize,
+    capacity: usize,
+    seen: HashMap<K, (HashSet<usize>, bool)>,
+    order: VecDeque<K>,
+}
+
+impl<K> Quorum<K>
+where
+    K: Clone + Eq + Hash,
+{
+    pub fn new(quorum: usize, capacity: usize) -> Self {
+        Self {
+            quorum: quorum.max(1),
+            capacity: capacity.max(1),
+            seen: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn observe(&mut self, key: K, provider: usize) -> Seen {
+        if !self.seen.contains_key(&key) {
+            if self.order.len() == self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.seen.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+
+        let (providers, accepted) = self.seen.entry(key).or_default();
+        if !providers.insert(provider) || *accepted {
+            return Seen::Duplicate;
+        }
+
+        if providers.len() >= self.quorum {
+            *accepted = true;
+            Seen::Accepted
+        } else {
+            Seen::Pending
+        }
+    }
+
+    /// Accepts an item that didn't come through the subscriptions (e.g. a backfilled one), returning
+    /// whether it wasn't accepted before.
+    pub fn accept(&mut self, key: K) -> bool {
+        if !self.seen.contains_key(&key) {
+            if self.order.len() == self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.seen.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+
+        let (_, accepted) = self.seen.entry(key).or_default();
+        !std::mem::replace(accepted, true)
+    }
+
+    /// Whether nothing was reported for the key before.
+    pub fn is_new(&self, key: &K) -> bool {
+        !self.seen.contains_key(key)
+    }
+}
+
+/// Nothing would ever be accepted with fewer than `quorum` connected providers.
+fn check_quorum(
+    network: &str,
+    quorum: usize,
+    connected: usize,
+    configured: usize,
+) -> eyre::Result<()> {
+    if quorum > connected {
+        eyre::bail!(
+            "a quorum of {} can't be reached for {}: only {} of the {} providers are reachable",
+            quorum,
+            network,
+            connected,
+            configured
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quorum_of_one_accepts_once() {
+        let mut quorum = Quorum::new(1, 16);
+
+        assert_eq!(quorum.observe(1, 0), Seen::Accepted);
+        assert_eq!(quorum.observe(1, 1), Seen::Duplicate);
+        assert_eq!(quorum.observe(1, 0), Seen::Duplicate);
+        assert_eq!(quorum.observe(2, 1), Seen::Accepted);
+    }
+
+    #[test]
+    fn quorum_requires_distinct_providers() {
+        let mut quorum = Quorum::new(2, 16);
+
+        assert_eq!(quorum.observe("a", 0), Seen::Pending);
+        assert_eq!(quorum.observe("a", 0), Seen::Duplicate);
+        assert_eq!(quorum.observe("a", 1), Seen::Accepted);
+        assert_eq!(quorum.observe("a", 2), Seen::Duplicate);
+    }
+
+    #[test]
+    fn quorum_forgets_oldest_keys() {
+        let mut quorum = Quorum::new(1, 2);
+
+        assert_eq!(quorum.observe(1, 0), Seen::Accepted);
+        assert_eq!(quorum.observe(2, 0), Seen::Accepted);
+        assert_eq!(quorum.observe(3, 0), Seen::Accepted);
+        assert!(quorum.is_new(&1));
+        assert_eq!(quorum.observe(1, 1), Seen::Accepted);
+    }
+
+    #[test]
+    fn accepted_items_are_duplicates_for_providers() {
+        let mut quorum = Quorum::new(2, 16);
+
+        assert_eq!(quorum.observe(1, 0), Seen::Pending);
+        assert!(quorum.accept(1));
+        assert!(!quorum.accept(1));
+        assert_eq!(quorum.observe(1, 1), Seen::Duplicate);
+
+        assert!(quorum.accept(2));
+        assert_eq!(quorum.observe(2, 0), Seen::Duplicate);
+    }
+
+    #[test]
+    fn quorum_needs_enough_connected_providers() {
+        assert!(check_quorum("ethereum", 1, 1, 1).is_ok());
+        assert!(check_quorum("ethereum", 2, 2, 3).is_ok());
+        assert!(check_quorum("ethereum", 2, 1, 2).is_err());
+        assert!(check_quorum("ethereum", 3, 2, 2).is_err());
+    }
+
+    #[test]
+    fn health_prefers_up_to_date_providers() {
+        let behind = Health {
+            head: 90,
+            ..Default::default()
+        };
+        let mut flaky = Health {
+            head: 100,
+            ..Default::default()
+        };
+        let fine = Health {
+            head: 100,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        flaky.record_error(now);
+
+        assert!(fine.score(100, now) < flaky.score(100, now));
+        assert!(flaky.score(100, now) < behind.score(100, now));
+    }
+
+    #[test]
+    fn health_forgets_old_errors() {
+        let mut recovered = Health {
+            head: 100,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        recovered.record_error(now);
+        recovered.record_error(now);
+        assert_eq!(recovered.recent_errors(now), 2);
+
+        let later = now + ERROR_WINDOW;
+        assert_eq!(recovered.recent_errors(later), 0);
+        assert_eq!(
+            recovered.score(100, later),
+            Health::default().score(0, later)
+        );
+
+        recovered.record_error(later);
+        assert_eq!(recovered.recent_errors(later), 1);
+    }
+}
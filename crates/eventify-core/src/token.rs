@@ -0,0 +1,290 @@
+//! Resolution of the metadata of the token contracts emitting the known events, through
+//! `eth_call`s to the contracts themselves, kept in the `token` table when indexing.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::{Address, Bytes, FixedBytes};
+use alloy_sol_types::{sol, SolCall};
+use reconnecting_jsonrpsee_ws_client::RpcError;
+#[cfg(feature = "index")]
+use sqlx::PgPool;
+use tracing::{debug, warn};
+
+use crate::{metrics, networks::NetworkClient};
+use eventify_primitives::{
+    networks::NetworkKind,
+    token::{Standard, Token},
+};
+
+/// Number of contracts whose metadata is kept in memory.
+const CACHE_CAPACITY: usize = 65_536;
+
+/// Upper bound of each `eth_call` asking a contract for its metadata, the logs of the contract
+/// waiting on them.
+const CALL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How long the contracts whose metadata couldn't be fetched are left alone before asking again.
+const RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The ERC165 interface id of ERC721.
+const ERC721_INTERFACE: FixedBytes<4> = FixedBytes([0x80, 0xac, 0x58, 0xcd]);
+/// The ERC165 interface id of ERC1155.
+const ERC1155_INTERFACE: FixedBytes<4> = FixedBytes([0xd9, 0xb6, 0x7a, 0x26]);
+
+sol! {
+    interface IToken {
+        function name() external view returns (string);
+        function symbol() external view returns (string);
+        function decimals() external view returns (uint8);
+        function totalSupply() external view returns (uint256);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
+
+/// Resolves token contracts to their metadata, once per contract.
+#[derive(Debug, Clone)]
+pub struct Tokens {
+    network: NetworkKind,
+    cache: Arc<Mutex<HashMap<Address, Token>>>,
+    failures: Arc<Mutex<Failures>>,
+    #[cfg(feature = "index")]
+    pool: PgPool,
+}
+
+impl Tokens {
+    pub fn new(network: NetworkKind, #[cfg(feature = "index")] pool: PgPool) -> Self {
+        Self {
+            network,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            failures: Arc::new(Mutex::new(Failures::default())),
+            #[cfg(feature = "index")]
+            pool,
+        }
+    }
+
+    /// The metadata of the token at `address`, known to emit events of the `hint` standard,
+    /// asked of the contract through `client` the first time it's looked up.
+    ///
+    /// Functions the contract doesn't implement (or that revert) leave their field unset, they
+    /// aren't retried. If the node can't be reached (or takes longer than [`CALL_TIMEOUT`]) the
+    /// contract is asked again once [`RETRY_AFTER`] has passed, an empty token being returned
+    /// meanwhile.
+    pub async fn resolve(&self, client: &NetworkClient, address: Address, hint: Standard) -> Token {
+        let network = self.network.to_string();
+        if let Some(token) = self.cached(&address) {
+            return token;
+        }
+
+        #[cfg(feature = "index")]
+        match Token::get(&self.pool, self.network, address).await {
+            Ok(Some(token)) => {
+                metrics::TOKEN_RESOLUTIONS
+                    .with_label_values(&[&network, "db"])
+                    .inc();
+                self.cache(address, token.clone());
+                return token;
+            }
+            Ok(None) => {}
+            Err(err) => warn!(kind = "token_lookup_error", %address, err = %err),
+        }
+
+        if self.failures().is_recent(&address, Instant::now()) {
+            return Token::default();
+        }
+        let token = match fetch(client, address, hint).await {
+            Ok(token) => token,
+            Err(err) => {
+                metrics::TOKEN_RESOLUTIONS
+                    .with_label_values(&[&network, "unreachable"])
+                    .inc();
+                warn!(kind = "token_call_error", %address, err = %err);
+                self.failures().record(address, Instant::now());
+                return Token::default();
+            }
+        };
+        metrics::TOKEN_RESOLUTIONS
+            .with_label_values(&[&network, "node"])
+            .inc();
+
+        #[cfg(feature = "index")]
+        if let Err(err) = token.upsert(&self.pool, self.network, address).await {
+            warn!(kind = "token_insert_error", %address, err = %err);
+        }
+        self.cache(address, token.clone());
+
+        token
+    }
+
+    fn failures(&self) -> std::sync::MutexGuard<'_, Failures> {
+        self.failures.lock().expect("token failures poisoned")
+    }
+
+    fn cached(&self, address: &Address) -> Option<Token> {
+        self.cache
+            .lock()
+            .expect("token cache poisoned")
+            .get(address)
+            .cloned()
+    }
+
+    fn cache(&self, address: Address, token: Token) {
+        let mut cache = self.cache.lock().expect("token cache poisoned");
+        if cache.len() == CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(address, token);
+    }
+}
+
+/// When fetching the metadata of contracts last failed.
+#[derive(Debug, Default)]
+struct Failures(HashMap<Address, Instant>);
+
+impl Failures {
+    /// Whether fetching the metadata of `address` failed less than [`RETRY_AFTER`] before `now`.
+    fn is_recent(&mut self, address: &Address, now: Instant) -> bool {
+        match self.0.get(address) {
+            Some(at) if now.duration_since(*at) < RETRY_AFTER => true,
+            Some(_) => {
+                self.0.remove(address);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn record(&mut self, address: Address, now: Instant) {
+        if self.0.len() == CACHE_CAPACITY {
+            self.0.retain(|_, at| now.duration_since(*at) < RETRY_AFTER);
+            if self.0.len() == CACHE_CAPACITY {
+                self.0.clear();
+            }
+        }
+        self.0.insert(address, now);
+    }
+}
+
+/// Asks the contract at `address` for its metadata, all at once.
+///
+/// Failing only if the node couldn't be reached, a call the contract reverts leaving its field
+/// unset.
+async fn fetch(
+    client: &NetworkClient,
+    address: Address,
+    hint: Standard,
+) -> Result<Token, RpcError> {
+    let call = |data: Vec<u8>| async move {
+        let output = tokio::time::timeout(CALL_TIMEOUT, client.call(address, Bytes::from(data)));
+        match output.await.unwrap_or(Err(RpcError::RequestTimeout)) {
+            Ok(output) => Ok(Some(output)),
+            // the contract reverted the call, asking again won't change that
+            Err(RpcError::Call(err)) if err.code() == 3 || err.message().contains("revert") => {
+                debug!(kind = "token_call_reverted", %address, err = %err);
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    };
+    let supports = |interface: FixedBytes<4>| {
+        call(
+            IToken::supportsInterfaceCall {
+                interfaceId: interface,
+            }
+            .abi_encode(),
+        )
+    };
+
+    let (name, symbol, decimals, total_supply, erc721, erc1155) = futures::join!(
+        call(IToken::nameCall {}.abi_encode()),
+        call(IToken::symbolCall {}.abi_encode()),
+        call(IToken::decimalsCall {}.abi_encode()),
+        call(IToken::totalSupplyCall {}.abi_encode()),
+        supports(ERC721_INTERFACE),
+        supports(ERC1155_INTERFACE),
+    );
+    let name = name?.and_then(|output| text(&output));
+    let symbol = symbol?.and_then(|output| text(&output));
+    let decimals = decimals?
+        .and_then(|output| IToken::decimalsCall::abi_decode_returns(&output, true).ok())
+        .map(|r| r._0);
+    let total_supply = total_supply?
+        .and_then(|output| IToken::totalSupplyCall::abi_decode_returns(&output, true).ok())
+        .map(|r| r._0);
+    let supported = |output: Option<Bytes>| {
+        output
+            .and_then(|output| {
+                IToken::supportsInterfaceCall::abi_decode_returns(&output, true).ok()
+            })
+            .is_some_and(|r| r._0)
+    };
+
+    let standard = if supported(erc721?) {
+        Standard::Erc721
+    } else if supported(erc1155?) {
+        Standard::Erc1155
+    } else if decimals.is_some() {
+        Standard::Erc20
+    } else {
+        hint
+    };
+
+    Ok(Token {
+        standard: Some(standard),
+        name,
+        symbol,
+        // NFTs have no decimals, whatever the contract says
+        decimals: decimals.filter(|_| standard == Standard::Erc20),
+        total_supply,
+    })
+}
+
+/// A `string` return value, or a `bytes32` one as returned by early tokens (e.g. MKR).
+fn text(output: &[u8]) -> Option<String> {
+    let text = match IToken::nameCall::abi_decode_returns(output, true) {
+        Ok(r) => r._0,
+        Err(_) if output.len() == 32 => String::from_utf8(output.to_vec()).ok()?,
+        Err(_) => return None,
+    };
+    let text = text.trim_end_matches('\0').trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::SolValue;
+
+    #[test]
+    fn decodes_string_and_bytes32_text() {
+        assert_eq!(
+            text(&("Wrapped Ether".to_string(),).abi_encode_params()),
+            Some("Wrapped Ether".to_string())
+        );
+
+        let mut mkr = [0u8; 32];
+        mkr[..5].copy_from_slice(b"Maker");
+        assert_eq!(text(&mkr), Some("Maker".to_string()));
+
+        assert_eq!(text(&[0u8; 32]), None);
+        assert_eq!(text(&[]), None);
+    }
+
+    #[test]
+    fn leaves_failed_contracts_alone_for_a_while() {
+        let mut failures = Failures::default();
+        let token = Address::repeat_byte(0x11);
+        let now = Instant::now();
+
+        assert!(!failures.is_recent(&token, now));
+        failures.record(token, now);
+        assert!(failures.is_recent(&token, now + RETRY_AFTER / 2));
+        assert!(!failures.is_recent(&Address::repeat_byte(0x22), now));
+        assert!(!failures.is_recent(&token, now + RETRY_AFTER));
+        assert!(!failures.is_recent(&token, now));
+    }
+}
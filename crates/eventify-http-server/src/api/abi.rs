@@ -0,0 +1,157 @@
+use actix_web::{get, post, web, HttpResponse, Responder};
+use alloy_primitives::Address;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::types::{AbiEventResponse, AbiRequest, AbiResponse, ErrorResponse};
+use eventify_primitives::{abi::Abi, networks::NetworkKind};
+
+/// Register an ABI
+///
+/// This endpoint registers a JSON ABI whose events the collectors decode into the
+/// `decoded_event` table. Bound to an `address`, only that contract's logs are decoded against
+/// it; without one, any log whose topic0 matches one of its events is. The collectors pick up
+/// new ABIs within 30 seconds.
+///
+/// # Responses
+///
+/// * `201 Created`: The ABI was registered. The response body lists the events it'll decode.
+/// * `400 Bad Request`: The ABI doesn't parse, has no events, or the address is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "address": "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640",
+///   "abi": [{"type": "event", "name": "Swap", "inputs": [...]}]
+/// }
+/// ```
+#[utoipa::path(
+    post,
+    path = "/api/v1/abis",
+    request_body = AbiRequest,
+    responses(
+        (status = 201, description = "Successfully registered the ABI", body = AbiResponse),
+        (status = 400, description = "Invalid ABI or address"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[post("")]
+pub(crate) async fn register_abi(
+    conn: web::Data<PgPool>,
+    request: web::Json<AbiRequest>,
+) -> impl Responder {
+    let address = match request.address.as_deref().map(str::parse::<Address>) {
+        Some(Ok(address)) => Some(address),
+        Some(Err(_)) => {
+            return HttpResponse::BadRequest().json(ErrorResponse {
+                error: format!(
+                    "invalid address: {}",
+                    request.address.as_deref().unwrap_or("")
+                ),
+            })
+        }
+        None => None,
+    };
+    let abi = match Abi::from_value(&request.abi) {
+        Ok(abi) if abi.events.iter().any(|e| !e.anonymous) => abi,
+        Ok(_) => {
+            return HttpResponse::BadRequest().json(ErrorResponse {
+                error: "the ABI has no non-anonymous events".to_string(),
+            })
+        }
+        Err(err) => {
+            return HttpResponse::BadRequest().json(ErrorResponse {
+                error: err.to_string(),
+            })
+        }
+    };
+
+    let sql = "INSERT INTO abi (network, address, abi) VALUES ($1, $2, $3::jsonb) RETURNING id";
+    match sqlx::query_scalar::<_, i32>(sql)
+        .bind(request.network)
+        .bind(address.as_ref().map(|a| a.as_slice()))
+        .bind(request.abi.to_string())
+        .fetch_one(conn.as_ref())
+        .await
+    {
+        Ok(id) => HttpResponse::Created().json(response(id, request.network, address, &abi)),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// List the registered ABIs
+///
+/// This endpoint returns every ABI registered through the API, along with the events decoded
+/// against each.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the ABIs. The response body will be a JSON array of `{ "id": Number, "network": String|null, "address": String|null, "events": [{ "name": String, "signature": String, "topic0": String }] }`.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+#[utoipa::path(
+    get,
+    path = "/api/v1/abis",
+    responses(
+        (status = 200, description = "Successfully retrieved the ABIs", body = [AbiResponse]),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("")]
+pub(crate) async fn get_abis(conn: web::Data<PgPool>) -> impl Responder {
+    let sql = "SELECT id, network, address, abi::text FROM abi ORDER BY id";
+    match sqlx::query_as::<_, (i32, Option<NetworkKind>, Option<Vec<u8>>, String)>(sql)
+        .fetch_all(conn.as_ref())
+        .await
+    {
+        Ok(rows) => {
+            let abis = rows
+                .into_iter()
+                .filter_map(|(id, network, address, json)| {
+                    let abi = Abi::from_json(&json).ok()?;
+                    let address = address
+                        .filter(|a| a.len() == 20)
+                        .map(|a| Address::from_slice(&a));
+                    Some(response(id, network, address, &abi))
+                })
+                .collect::<Vec<_>>();
+            HttpResponse::Ok().json(abis)
+        }
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn response(
+    id: i32,
+    network: Option<NetworkKind>,
+    address: Option<Address>,
+    abi: &Abi,
+) -> AbiResponse {
+    AbiResponse {
+        id,
+        network,
+        address: address.map(|a| a.to_checksum(None)),
+        events: abi
+            .events
+            .iter()
+            .filter(|e| !e.anonymous)
+            .map(|e| AbiEventResponse {
+                name: e.name.clone(),
+                signature: e.signature(),
+                topic0: e.selector().to_string(),
+            })
+            .collect(),
+    }
+}
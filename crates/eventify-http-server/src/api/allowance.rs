@@ -0,0 +1,163 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::hex;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::{
+        events::{address, page, parse_address, uint},
+        token::formatted,
+    },
+    types::{AllowanceResponse, AllowancesQuery, ErrorResponse},
+};
+
+const SOURCES: [&str; 2] = ["erc20", "permit2"];
+
+/// Get Token Allowances
+///
+/// This endpoint returns the latest allowances given through ERC20 `Approval` events and Uniswap
+/// Permit2, most recently changed first, optionally only those of an owner, spender or token.
+/// A Permit2 allowance can be spent only as long as its owner's ERC20 allowance to Permit2
+/// itself, listed alongside, covers it.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the allowances. The response body will be a JSON array of allowances, amounts being decimal strings.
+/// * `400 Bad Request`: An address, the source, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "token": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+///   "owner": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "spender": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "source": "permit2",
+///   "amount": "1461501637330902918203684832716283019655932542975",
+///   "symbol": "USDC",
+///   "decimals": 6,
+///   "formattedAmount": "1461501637330902918203684832716283019655932.542975",
+///   "expiration": 1713000000,
+///   "nonce": 3,
+///   "blockNumber": 19000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 42
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/allowances",
+    params(AllowancesQuery),
+    responses(
+        (status = 200, description = "Successfully retrieved the allowances", body = [AllowanceResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("")]
+pub(crate) async fn get_allowances(
+    conn: web::Data<PgPool>,
+    query: web::Query<AllowancesQuery>,
+) -> impl Responder {
+    let (owner, spender, token) = match (
+        parse_address(query.owner.as_deref()),
+        parse_address(query.spender.as_deref()),
+        parse_address(query.token.as_deref()),
+    ) {
+        (Ok(owner), Ok(spender), Ok(token)) => (owner, spender, token),
+        (Err(error), _, _) | (_, Err(error), _) | (_, _, Err(error)) => {
+            return HttpResponse::BadRequest().json(error)
+        }
+    };
+    if let Some(source) = query.source.as_deref().filter(|s| !SOURCES.contains(s)) {
+        return HttpResponse::BadRequest().json(ErrorResponse {
+            error: format!(
+                "invalid source: {}, expected one of {}",
+                source,
+                SOURCES.join(", ")
+            ),
+        });
+    }
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT
+            a.network,
+            a.token,
+            a."owner",
+            a.spender,
+            a.source,
+            a.amount,
+            a.expiration,
+            a.nonce,
+            a.block_number,
+            a.tx_hash,
+            a.log_index,
+            t.symbol,
+            t.decimals
+        FROM allowance a
+        LEFT JOIN token t ON t.network = a.network AND t.address = a.token
+        WHERE ($1::network_type IS NULL OR a.network = $1)
+            AND ($2::bytea IS NULL OR a."owner" = $2)
+            AND ($3::bytea IS NULL OR a.spender = $3)
+            AND ($4::bytea IS NULL OR a.token = $4)
+            AND ($5::text IS NULL OR a.source = $5)
+        ORDER BY a.block_number DESC NULLS LAST, a.log_index DESC NULLS LAST
+        LIMIT $6 OFFSET $7"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(owner.as_ref().map(|a| a.as_slice()))
+        .bind(spender.as_ref().map(|a| a.as_slice()))
+        .bind(token.as_ref().map(|a| a.as_slice()))
+        .bind(query.source.as_deref())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(response).collect::<Result<Vec<_>, _>>())
+    {
+        Ok(allowances) => HttpResponse::Ok().json(allowances),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn response(row: &PgRow) -> Result<AllowanceResponse, sqlx::Error> {
+    let address_at = |i: usize| -> Result<String, sqlx::Error> {
+        Ok(address(&row.try_get::<Vec<u8>, _>(i)?).unwrap_or_default())
+    };
+
+    let amount = row
+        .try_get::<Option<Vec<u8>>, _>(5)?
+        .and_then(|v| uint(&v))
+        .map(|v| v.to_string());
+    let decimals = row.try_get(12)?;
+
+    Ok(AllowanceResponse {
+        network: row.try_get(0)?,
+        token: address_at(1)?,
+        owner: address_at(2)?,
+        spender: address_at(3)?,
+        source: row.try_get(4)?,
+        formatted_amount: formatted(amount.as_deref(), decimals),
+        amount,
+        symbol: row.try_get(11)?,
+        decimals,
+        expiration: row.try_get(6)?,
+        nonce: row.try_get(7)?,
+        block_number: row.try_get(8)?,
+        tx_hash: row
+            .try_get::<Option<Vec<u8>>, _>(9)?
+            .map(hex::encode_prefixed),
+        log_index: row.try_get(10)?,
+    })
+}
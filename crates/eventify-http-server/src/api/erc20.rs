@@ -0,0 +1,288 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::Address;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::{
+        events::{address, page, parse_address},
+        token::formatted,
+    },
+    types::{BalanceResponse, BalancesQuery, ErrorResponse, SupplyQuery, SupplyResponse},
+};
+
+/// The latest balances, `$1` being the network and `$2` the block (unused), to be grouped by
+/// holder like the historical ones.
+const LATEST: &str = r#"SELECT network, token, holder, SUM(balance)::text AS balance
+    FROM erc20_balance
+    WHERE ($1::network_type IS NULL OR network = $1) AND $2::bigint IS NULL"#;
+
+/// The zero address, holding the opposite of the supply.
+const ZERO: &str = r"'\x0000000000000000000000000000000000000000'";
+
+/// The balances as of the end of block `$2` on network `$1`.
+const HISTORICAL: &str = r#"SELECT network, token, holder, SUM(delta)::text AS balance
+    FROM erc20_balance_change
+    WHERE network = $1 AND block_number <= $2"#;
+
+/// Get the ERC20 Balances of an Account
+///
+/// This endpoint returns the non-zero balances of an account over every token it has been
+/// transferred, as of a block or the latest ones, folded from the tokens' `Transfer` events.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the balances. The response body will be a JSON array of balances, ordered by token.
+/// * `400 Bad Request`: The address, the limit or the offset is invalid, or a block was given without a network.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "token": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+///   "holder": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "balance": "1500000000",
+///   "symbol": "USDC",
+///   "decimals": 6,
+///   "formattedBalance": "1500"
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/erc20/balances/{holder}",
+    params(
+        ("holder" = String, Path, description = "The account"),
+        BalancesQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the balances", body = [BalanceResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/balances/{holder}")]
+pub(crate) async fn get_balances(
+    conn: web::Data<PgPool>,
+    holder: web::Path<String>,
+    query: web::Query<BalancesQuery>,
+) -> impl Responder {
+    let holder = match parse_address(Some(holder.as_str())) {
+        Ok(holder) => holder.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (ledger, limit, offset) = match ledger(&query) {
+        Ok(ledger) => ledger,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = format!(
+        r#"SELECT b.*, t.symbol, t.decimals
+        FROM ({ledger} AND holder = $3 GROUP BY network, token, holder) b
+        LEFT JOIN token t ON t.network = b.network AND t.address = b.token
+        WHERE b.balance::numeric <> 0
+        ORDER BY b.network, b.token
+        LIMIT $4 OFFSET $5"#
+    );
+    balances(&conn, &sql, &query, holder, limit, offset).await
+}
+
+/// Get the Holders of an ERC20 Token
+///
+/// This endpoint returns the accounts holding a token, the largest holders first, as of a block
+/// or the latest ones, folded from the token's `Transfer` events.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the holders, see `GET /api/v1/erc20/balances/{holder}`.
+/// * `400 Bad Request`: The address, the limit or the offset is invalid, or a block was given without a network.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+#[utoipa::path(
+    get,
+    path = "/api/v1/erc20/holders/{token}",
+    params(
+        ("token" = String, Path, description = "The token"),
+        BalancesQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the holders", body = [BalanceResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/holders/{token}")]
+pub(crate) async fn get_holders(
+    conn: web::Data<PgPool>,
+    token: web::Path<String>,
+    query: web::Query<BalancesQuery>,
+) -> impl Responder {
+    let token = match parse_address(Some(token.as_str())) {
+        Ok(token) => token.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (ledger, limit, offset) = match ledger(&query) {
+        Ok(ledger) => ledger,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = format!(
+        r#"SELECT b.*, t.symbol, t.decimals
+        FROM ({ledger} AND token = $3 AND holder <> {ZERO}
+            GROUP BY network, token, holder) b
+        LEFT JOIN token t ON t.network = b.network AND t.address = b.token
+        WHERE b.balance::numeric > 0
+        ORDER BY b.balance::numeric DESC, b.holder
+        LIMIT $4 OFFSET $5"#
+    );
+    balances(&conn, &sql, &query, token, limit, offset).await
+}
+
+/// Get the Supply of an ERC20 Token
+///
+/// This endpoint returns the amount of a token minted less the amount burned, as of a block or
+/// the latest one, i.e. its total supply unless it's minted or burned without `Transfer` events.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the supply.
+/// * `400 Bad Request`: The address is invalid.
+/// * `404 Not Found`: None of the token has been minted up to the block.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "token": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+///   "supply": "25000000000000000",
+///   "symbol": "USDC",
+///   "decimals": 6,
+///   "formattedSupply": "25000000000"
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/erc20/supply/{token}",
+    params(
+        ("token" = String, Path, description = "The token"),
+        SupplyQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the supply", body = SupplyResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "No supply"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/supply/{token}")]
+pub(crate) async fn get_supply(
+    conn: web::Data<PgPool>,
+    token: web::Path<String>,
+    query: web::Query<SupplyQuery>,
+) -> impl Responder {
+    let token = match parse_address(Some(token.as_str())) {
+        Ok(token) => token.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let ledger = if query.block.is_some() {
+        HISTORICAL
+    } else {
+        LATEST
+    };
+    let sql = format!(
+        r#"SELECT b.network, b.token, (-b.balance::numeric)::text AS supply, t.symbol, t.decimals
+        FROM ({ledger} AND token = $3 AND holder = {ZERO}
+            GROUP BY network, token, holder) b
+        LEFT JOIN token t ON t.network = b.network AND t.address = b.token"#
+    );
+
+    match sqlx::query(&sql)
+        .bind(query.network)
+        .bind(query.block)
+        .bind(token.as_slice())
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| {
+            row.map(|row| {
+                let supply: String = row.try_get(2)?;
+                let decimals = row.try_get(4)?;
+                Ok(SupplyResponse {
+                    network: row.try_get(0)?,
+                    token: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+                    formatted_supply: formatted(Some(&supply), decimals),
+                    supply,
+                    symbol: row.try_get(3)?,
+                    decimals,
+                })
+            })
+            .transpose()
+        }) {
+        Ok(Some(supply)) => HttpResponse::Ok().json(supply),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("no supply of {} on {}", token, query.network),
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// The ledger to read the balances of `query` from, and its page.
+fn ledger(query: &BalancesQuery) -> Result<(&'static str, i64, i64), ErrorResponse> {
+    let (limit, offset) = page(query.limit, query.offset)?;
+    match (query.network, query.block) {
+        (None, Some(_)) => Err(ErrorResponse {
+            error: "a block requires a network".to_string(),
+        }),
+        (_, Some(_)) => Ok((HISTORICAL, limit, offset)),
+        (_, None) => Ok((LATEST, limit, offset)),
+    }
+}
+
+async fn balances(
+    conn: &PgPool,
+    sql: &str,
+    query: &BalancesQuery,
+    address: Address,
+    limit: i64,
+    offset: i64,
+) -> HttpResponse {
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(query.block)
+        .bind(address.as_slice())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn)
+        .await
+        .and_then(|rows| rows.iter().map(response).collect::<Result<Vec<_>, _>>())
+    {
+        Ok(balances) => HttpResponse::Ok().json(balances),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn response(row: &PgRow) -> Result<BalanceResponse, sqlx::Error> {
+    let balance: String = row.try_get(3)?;
+    let decimals = row.try_get(5)?;
+    Ok(BalanceResponse {
+        network: row.try_get(0)?,
+        token: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+        holder: address(&row.try_get::<Vec<u8>, _>(2)?).unwrap_or_default(),
+        formatted_balance: formatted(Some(&balance), decimals),
+        balance,
+        symbol: row.try_get(4)?,
+        decimals,
+    })
+}
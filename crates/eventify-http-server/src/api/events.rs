@@ -0,0 +1,178 @@
+//! Listing of the events stored in the per-event tables keyed by the log they were decoded from.
+
+use actix_web::{web, HttpResponse};
+use alloy_primitives::{Address, B256, I256, U256};
+use serde_json::{Map, Value};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::types::{ErrorResponse, EventResponse, EventsQuery};
+use eventify_primitives::networks::NetworkKind;
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+/// How a column is stored.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Column {
+    Address,
+    /// a little endian unsigned integer
+    Uint,
+    /// a little endian two's complement signed integer
+    Int,
+    /// an `INTEGER`
+    Integer,
+}
+
+/// A table of events, the columns besides the log's given as `(column, parameter, kind)`.
+#[derive(Debug)]
+pub(crate) struct EventTable {
+    pub(crate) table: &'static str,
+    pub(crate) event: &'static str,
+    pub(crate) columns: &'static [(&'static str, &'static str, Column)],
+}
+
+/// The events of `table` matching the query, latest first.
+pub(crate) async fn list(conn: &PgPool, table: &EventTable, query: &EventsQuery) -> HttpResponse {
+    let address = match parse_address(query.address.as_deref()) {
+        Ok(address) => address,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let columns = table
+        .columns
+        .iter()
+        .map(|(column, _, _)| format!(", \"{}\"", column))
+        .collect::<String>();
+    let sql = format!(
+        r#"SELECT network, address, block_number, tx_hash, log_index{}
+        FROM {}
+        WHERE ($1::network_type IS NULL OR network = $1) AND ($2::bytea IS NULL OR address = $2)
+        ORDER BY block_number DESC NULLS LAST, log_index DESC
+        LIMIT $3 OFFSET $4"#,
+        columns, table.table
+    );
+
+    match sqlx::query(&sql)
+        .bind(query.network)
+        .bind(address.as_ref().map(|a| a.as_slice()))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn)
+        .await
+        .and_then(|rows| {
+            rows.iter()
+                .map(|row| response(table, row))
+                .collect::<Result<Vec<_>, _>>()
+        }) {
+        Ok(events) => HttpResponse::Ok().json(events),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// Looks `event` up among `tables` and lists its events, `404 Not Found` if there's no such
+/// table.
+pub(crate) async fn list_by_name(
+    conn: web::Data<PgPool>,
+    tables: &[EventTable],
+    event: &str,
+    query: &EventsQuery,
+) -> HttpResponse {
+    match tables.iter().find(|t| t.event.eq_ignore_ascii_case(event)) {
+        Some(table) => list(conn.as_ref(), table, query).await,
+        None => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!(
+                "unknown event {}, expected one of {}",
+                event,
+                tables
+                    .iter()
+                    .map(|t| t.event.to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }),
+    }
+}
+
+fn response(table: &EventTable, row: &PgRow) -> Result<EventResponse, sqlx::Error> {
+    let mut params = Map::new();
+    for (i, (_, param, kind)) in table.columns.iter().enumerate() {
+        let i = i + 5;
+        let value = match kind {
+            Column::Address => row
+                .try_get::<Option<Vec<u8>>, _>(i)?
+                .and_then(|a| address(&a))
+                .map_or(Value::Null, Value::String),
+            Column::Uint => row
+                .try_get::<Option<Vec<u8>>, _>(i)?
+                .and_then(|v| uint(&v))
+                .map_or(Value::Null, |v| Value::String(v.to_string())),
+            Column::Int => row
+                .try_get::<Option<Vec<u8>>, _>(i)?
+                .and_then(|v| uint(&v))
+                .map_or(Value::Null, |v| {
+                    Value::String(I256::from_raw(v).to_string())
+                }),
+            Column::Integer => row
+                .try_get::<Option<i32>, _>(i)?
+                .map_or(Value::Null, Value::from),
+        };
+        params.insert(param.to_string(), value);
+    }
+
+    Ok(EventResponse {
+        network: row.try_get::<NetworkKind, _>(0)?,
+        address: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+        block_number: row.try_get(2)?,
+        tx_hash: row
+            .try_get::<Option<Vec<u8>>, _>(3)?
+            .filter(|h| h.len() == 32)
+            .map(|h| B256::from_slice(&h).to_string()),
+        log_index: row.try_get(4)?,
+        event: table.event.to_string(),
+        params: Value::Object(params),
+    })
+}
+
+/// Parses an optional address query parameter.
+pub(crate) fn parse_address(address: Option<&str>) -> Result<Option<Address>, ErrorResponse> {
+    match address.map(str::parse::<Address>) {
+        Some(Ok(address)) => Ok(Some(address)),
+        Some(Err(_)) => Err(ErrorResponse {
+            error: format!("invalid address: {}", address.unwrap_or_default()),
+        }),
+        None => Ok(None),
+    }
+}
+
+/// The limit and offset of a listing.
+pub(crate) fn page(limit: Option<i64>, offset: Option<i64>) -> Result<(i64, i64), ErrorResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let offset = offset.unwrap_or(0);
+    if !(1..=MAX_LIMIT).contains(&limit) || offset < 0 {
+        return Err(ErrorResponse {
+            error: format!("limit must be within 1..={} and offset positive", MAX_LIMIT),
+        });
+    }
+
+    Ok((limit, offset))
+}
+
+/// A stored address, checksummed.
+pub(crate) fn address(bytes: &[u8]) -> Option<String> {
+    (bytes.len() == 20).then(|| Address::from_slice(bytes).to_checksum(None))
+}
+
+/// A stored little endian integer.
+pub(crate) fn uint(bytes: &[u8]) -> Option<U256> {
+    (bytes.len() <= 32).then(|| U256::from_le_slice(bytes))
+}
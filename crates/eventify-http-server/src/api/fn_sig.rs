@@ -0,0 +1,84 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::types::{ErrorResponse, FnSigResponse};
+
+/// Get the Function Signatures of a Selector
+///
+/// This endpoint returns every known function signature whose 4-byte selector matches the given
+/// one, shortest first. More than one signature means the selector collides.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the signatures. The response body will be a JSON object with the structure `{ "selector": String, "signatures": [String] }`; `signatures` is empty for unknown selectors.
+/// * `400 Bad Request`: The selector isn't 4 hex encoded bytes.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "selector": "0xa9059cbb",
+///   "signatures": ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/fn-sigs/{selector}",
+    params(
+        ("selector" = String, Path, description = "4-byte function selector, e.g. 0xa9059cbb")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the function signatures", body = FnSigResponse),
+        (status = 400, description = "Invalid selector"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{selector}")]
+pub(crate) async fn get_fn_sigs(
+    conn: web::Data<PgPool>,
+    selector: web::Path<String>,
+) -> impl Responder {
+    let Some(bytes) = parse_selector(&selector) else {
+        return HttpResponse::BadRequest().json(ErrorResponse {
+            error: format!("invalid selector: {}", selector),
+        });
+    };
+
+    let sql = "SELECT text_sig FROM fn_sig WHERE hex_sig = $1 ORDER BY length(text_sig), text_sig";
+    match sqlx::query_scalar::<_, String>(sql)
+        .bind(bytes.as_slice())
+        .fetch_all(conn.as_ref())
+        .await
+    {
+        Ok(signatures) => HttpResponse::Ok().json(FnSigResponse {
+            selector: format!("0x{}", hex(&bytes)),
+            signatures,
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn parse_selector(selector: &str) -> Option<[u8; 4]> {
+    let selector = selector.strip_prefix("0x").unwrap_or(selector);
+    if selector.len() != 8 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 4];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(selector.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,19 @@
+use actix_web::{HttpResponse, Responder};
+use prometheus::{Encoder, TextEncoder};
+use tracing::error;
+
+/// Exposes every metric registered in the process in the prometheus text format
+pub async fn metrics() -> impl Responder {
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+
+    match encoder.encode(&prometheus::gather(), &mut buffer) {
+        Ok(()) => HttpResponse::Ok()
+            .content_type(encoder.format_type())
+            .body(buffer),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -1,8 +1,21 @@
+pub mod abi;
+pub mod allowance;
 pub mod block;
+pub mod erc20;
+pub mod events;
+pub mod fn_sig;
 pub mod health;
 pub mod log;
+pub mod metrics;
+pub mod nft;
+pub mod price;
+pub mod token;
+pub mod uniswap;
+pub mod user_operation;
+pub mod vault;
 
 pub use health::health;
+pub use metrics::metrics;
 
 use actix_web::{web, HttpResponse};
 use sqlx::{PgPool, Row};
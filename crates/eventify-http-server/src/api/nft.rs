@@ -0,0 +1,451 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::{hex, Address, U256};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::events::{address, page, parse_address},
+    types::{
+        CollectionQuery, ErrorResponse, HolderCountResponse, NftMetadataResponse, NftResponse,
+        NftTransferResponse, OwnedNftsQuery, TokenQuery,
+    },
+};
+
+/// Get the NFTs of an Account
+///
+/// This endpoint returns the ERC721 and ERC1155 tokens an account currently owns, folded from
+/// the contracts' transfer events.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the tokens. The response body will be a JSON array of tokens, ordered by contract and token id.
+/// * `400 Bad Request`: An address, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "contract": "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D",
+///   "tokenId": "1234",
+///   "owner": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "quantity": "1"
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/nfts/owned/{owner}",
+    params(
+        ("owner" = String, Path, description = "The account"),
+        OwnedNftsQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the tokens", body = [NftResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/owned/{owner}")]
+pub(crate) async fn get_owned(
+    conn: web::Data<PgPool>,
+    owner: web::Path<String>,
+    query: web::Query<OwnedNftsQuery>,
+) -> impl Responder {
+    let (owner, contract) = match (
+        parse_address(Some(owner.as_str())),
+        parse_address(query.contract.as_deref()),
+    ) {
+        (Ok(owner), Ok(contract)) => (owner.unwrap_or_default(), contract),
+        (Err(error), _) | (_, Err(error)) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT network, contract, token_id::text, "owner", quantity::text
+        FROM nft_owner
+        WHERE "owner" = $1
+            AND quantity > 0
+            AND ($2::network_type IS NULL OR network = $2)
+            AND ($3::bytea IS NULL OR contract = $3)
+        ORDER BY network, contract, token_id
+        LIMIT $4 OFFSET $5"#;
+
+    let result = sqlx::query(sql)
+        .bind(owner.as_slice())
+        .bind(query.network)
+        .bind(contract.as_ref().map(|a| a.as_slice()))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(nft).collect::<Result<Vec<_>, _>>());
+    respond(result)
+}
+
+/// Get the Holder Count of a Collection
+///
+/// This endpoint returns how many accounts currently own at least one token of an ERC721 or
+/// ERC1155 contract.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the holder count.
+/// * `400 Bad Request`: The address is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "contract": "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D",
+///   "holders": 5532
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/nfts/{contract}/holders",
+    params(
+        ("contract" = String, Path, description = "The ERC721 or ERC1155 contract"),
+        CollectionQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the holder count", body = HolderCountResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{contract}/holders")]
+pub(crate) async fn get_holder_count(
+    conn: web::Data<PgPool>,
+    contract: web::Path<String>,
+    query: web::Query<CollectionQuery>,
+) -> impl Responder {
+    let contract = match parse_address(Some(contract.as_str())) {
+        Ok(contract) => contract.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT COUNT(DISTINCT "owner")
+        FROM nft_owner
+        WHERE network = $1 AND contract = $2 AND quantity > 0"#;
+
+    match sqlx::query_scalar::<_, i64>(sql)
+        .bind(query.network)
+        .bind(contract.as_slice())
+        .fetch_one(conn.as_ref())
+        .await
+    {
+        Ok(holders) => HttpResponse::Ok().json(HolderCountResponse {
+            network: query.network,
+            contract: contract.to_checksum(None),
+            holders,
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// Get the Owners of an NFT
+///
+/// This endpoint returns the accounts currently owning a token, a single one for ERC721 tokens,
+/// the largest holders first.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the owners, see `GET /api/v1/nfts/owned/{owner}`.
+/// * `400 Bad Request`: The address, the token id, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+#[utoipa::path(
+    get,
+    path = "/api/v1/nfts/{contract}/{token_id}/owners",
+    params(
+        ("contract" = String, Path, description = "The ERC721 or ERC1155 contract"),
+        ("token_id" = String, Path, description = "The token id, decimal or hex encoded"),
+        CollectionQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the owners", body = [NftResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{contract}/{token_id}/owners")]
+pub(crate) async fn get_owners(
+    conn: web::Data<PgPool>,
+    path: web::Path<(String, String)>,
+    query: web::Query<CollectionQuery>,
+) -> impl Responder {
+    let (contract, token_id) = match token(&path) {
+        Ok(token) => token,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT network, contract, token_id::text, "owner", quantity::text
+        FROM nft_owner
+        WHERE network = $1 AND contract = $2 AND token_id = $3::numeric AND quantity > 0
+        ORDER BY quantity DESC, "owner"
+        LIMIT $4 OFFSET $5"#;
+
+    let result = sqlx::query(sql)
+        .bind(query.network)
+        .bind(contract.as_slice())
+        .bind(token_id.to_string())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(nft).collect::<Result<Vec<_>, _>>());
+    respond(result)
+}
+
+/// Get the History of an NFT
+///
+/// This endpoint returns the transfers of a token, latest first.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the transfers. The response body will be a JSON array of transfers, quantities being decimal strings.
+/// * `400 Bad Request`: The address, the token id, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "contract": "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D",
+///   "tokenId": "1234",
+///   "standard": "erc721",
+///   "operator": null,
+///   "from": "0x0000000000000000000000000000000000000000",
+///   "to": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "quantity": "1",
+///   "blockNumber": 12000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 42
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/nfts/{contract}/{token_id}/transfers",
+    params(
+        ("contract" = String, Path, description = "The ERC721 or ERC1155 contract"),
+        ("token_id" = String, Path, description = "The token id, decimal or hex encoded"),
+        CollectionQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the transfers", body = [NftTransferResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{contract}/{token_id}/transfers")]
+pub(crate) async fn get_transfers(
+    conn: web::Data<PgPool>,
+    path: web::Path<(String, String)>,
+    query: web::Query<CollectionQuery>,
+) -> impl Responder {
+    let (contract, token_id) = match token(&path) {
+        Ok(token) => token,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT
+            network,
+            contract,
+            token_id::text,
+            standard,
+            operator,
+            "from",
+            "to",
+            quantity::text,
+            block_number,
+            tx_hash,
+            log_index
+        FROM nft_transfer
+        WHERE network = $1 AND contract = $2 AND token_id = $3::numeric
+        ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST, batch_index DESC
+        LIMIT $4 OFFSET $5"#;
+
+    let result = sqlx::query(sql)
+        .bind(query.network)
+        .bind(contract.as_slice())
+        .bind(token_id.to_string())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(transfer).collect::<Result<Vec<_>, _>>());
+    respond(result)
+}
+
+/// Get the Metadata of an NFT
+///
+/// This endpoint returns the JSON metadata the URI of a token points to, as fetched by the
+/// metadata worker, along with where fetching it stands.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the metadata.
+/// * `400 Bad Request`: The address or the token id is invalid.
+/// * `404 Not Found`: The token hasn't been queued for fetching.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "contract": "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D",
+///   "tokenId": "1234",
+///   "standard": "erc721",
+///   "uri": "ipfs://QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/1234",
+///   "status": "fetched",
+///   "metadata": {"image": "ipfs://QmVvsdpLCSNbgrvJ1NrHsN2yVKj6ko5v7HALFpyNgEVspM", "attributes": []},
+///   "error": null,
+///   "attempts": 1
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/nfts/{contract}/{token_id}/metadata",
+    params(
+        ("contract" = String, Path, description = "The ERC721 or ERC1155 contract"),
+        ("token_id" = String, Path, description = "The token id, decimal or hex encoded"),
+        TokenQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the metadata", body = NftMetadataResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "Unknown token"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{contract}/{token_id}/metadata")]
+pub(crate) async fn get_metadata(
+    conn: web::Data<PgPool>,
+    path: web::Path<(String, String)>,
+    query: web::Query<TokenQuery>,
+) -> impl Responder {
+    let (contract, token_id) = match token(&path) {
+        Ok(token) => token,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT
+            network,
+            contract,
+            token_id::text,
+            standard,
+            uri,
+            status,
+            metadata::text,
+            error,
+            attempts
+        FROM nft_metadata
+        WHERE network = $1 AND contract = $2 AND token_id = $3::numeric"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(contract.as_slice())
+        .bind(token_id.to_string())
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| {
+            row.map(|row| {
+                Ok(NftMetadataResponse {
+                    network: row.try_get(0)?,
+                    contract: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+                    token_id: row.try_get(2)?,
+                    standard: row.try_get(3)?,
+                    uri: row.try_get(4)?,
+                    status: row.try_get(5)?,
+                    metadata: row
+                        .try_get::<Option<String>, _>(6)?
+                        .and_then(|v| serde_json::from_str(&v).ok()),
+                    error: row.try_get(7)?,
+                    attempts: row.try_get(8)?,
+                })
+            })
+            .transpose()
+        }) {
+        Ok(Some(metadata)) => HttpResponse::Ok().json(metadata),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("unknown token: {} {}", contract, token_id),
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// The contract and token id of a path.
+fn token(path: &(String, String)) -> Result<(Address, U256), ErrorResponse> {
+    let contract = parse_address(Some(path.0.as_str()))?.unwrap_or_default();
+    let token_id = path.1.parse::<U256>().map_err(|_| ErrorResponse {
+        error: format!("invalid token id: {}", path.1),
+    })?;
+
+    Ok((contract, token_id))
+}
+
+fn respond<T: serde::Serialize>(result: Result<Vec<T>, sqlx::Error>) -> HttpResponse {
+    match result {
+        Ok(rows) => HttpResponse::Ok().json(rows),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn nft(row: &PgRow) -> Result<NftResponse, sqlx::Error> {
+    Ok(NftResponse {
+        network: row.try_get(0)?,
+        contract: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+        token_id: row.try_get(2)?,
+        owner: address(&row.try_get::<Vec<u8>, _>(3)?).unwrap_or_default(),
+        quantity: row.try_get(4)?,
+    })
+}
+
+fn transfer(row: &PgRow) -> Result<NftTransferResponse, sqlx::Error> {
+    Ok(NftTransferResponse {
+        network: row.try_get(0)?,
+        contract: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+        token_id: row.try_get(2)?,
+        standard: row.try_get(3)?,
+        operator: row
+            .try_get::<Option<Vec<u8>>, _>(4)?
+            .and_then(|a| address(&a)),
+        from: address(&row.try_get::<Vec<u8>, _>(5)?).unwrap_or_default(),
+        to: address(&row.try_get::<Vec<u8>, _>(6)?).unwrap_or_default(),
+        quantity: row.try_get(7)?,
+        block_number: row.try_get(8)?,
+        tx_hash: row
+            .try_get::<Option<Vec<u8>>, _>(9)?
+            .map(hex::encode_prefixed),
+        log_index: row.try_get(10)?,
+    })
+}
@@ -0,0 +1,131 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::{hex, I256};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::events::{address, uint},
+    types::{ErrorResponse, PriceQuery, PriceResponse},
+};
+use eventify_primitives::token::format_signed_units;
+
+/// Get a Chainlink Price
+///
+/// This endpoint returns the latest answer of a configured Chainlink feed as of a block or a
+/// timestamp, the latest one collected if neither is given.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the price.
+/// * `400 Bad Request`: Both a block and a timestamp were given.
+/// * `404 Not Found`: No answer of the feed has been collected up to the block or timestamp.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "feed": "ETH/USD",
+///   "aggregator": "0xE62B71cf983019BFf55bC83B48601ce8419650CC",
+///   "roundId": "20043",
+///   "answer": "352112345678",
+///   "decimals": 8,
+///   "price": "3521.12345678",
+///   "answeredAt": 1705000000,
+///   "blockNumber": 19000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 42
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/prices",
+    params(PriceQuery),
+    responses(
+        (status = 200, description = "Successfully retrieved the price", body = PriceResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "No price"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("")]
+pub(crate) async fn get_price(
+    conn: web::Data<PgPool>,
+    query: web::Query<PriceQuery>,
+) -> impl Responder {
+    if query.block.is_some() && query.timestamp.is_some() {
+        return HttpResponse::BadRequest().json(ErrorResponse {
+            error: "expected either a block or a timestamp".to_string(),
+        });
+    }
+
+    let sql = r#"SELECT
+            network,
+            feed,
+            address,
+            round_id,
+            answer,
+            decimals,
+            answered_at,
+            block_number,
+            tx_hash,
+            log_index
+        FROM chainlink_price
+        WHERE network = $1
+            AND feed = $2
+            AND ($3::bigint IS NULL OR block_number <= $3)
+            AND ($4::bigint IS NULL OR answered_at <= $4)
+        ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST
+        LIMIT 1"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(&query.feed)
+        .bind(query.block)
+        .bind(query.timestamp)
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| row.as_ref().map(response).transpose())
+    {
+        Ok(Some(price)) => HttpResponse::Ok().json(price),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("no price of {} on {}", query.feed, query.network),
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn response(row: &PgRow) -> Result<PriceResponse, sqlx::Error> {
+    let decimals: i16 = row.try_get(5)?;
+    let answer = row
+        .try_get::<Option<Vec<u8>>, _>(4)?
+        .and_then(|v| uint(&v))
+        .map(I256::from_raw);
+
+    Ok(PriceResponse {
+        network: row.try_get(0)?,
+        feed: row.try_get(1)?,
+        aggregator: address(&row.try_get::<Vec<u8>, _>(2)?).unwrap_or_default(),
+        round_id: row
+            .try_get::<Option<Vec<u8>>, _>(3)?
+            .and_then(|v| uint(&v))
+            .map(|v| v.to_string()),
+        answer: answer.map(|v| v.to_string()),
+        decimals,
+        price: answer
+            .zip(u8::try_from(decimals).ok())
+            .map(|(v, decimals)| format_signed_units(v, decimals)),
+        answered_at: row.try_get(6)?,
+        block_number: row.try_get(7)?,
+        tx_hash: row
+            .try_get::<Option<Vec<u8>>, _>(8)?
+            .map(hex::encode_prefixed),
+        log_index: row.try_get(9)?,
+    })
+}
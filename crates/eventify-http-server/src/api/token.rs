@@ -0,0 +1,117 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::U256;
+use sqlx::{PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::events::{address, parse_address},
+    types::{ErrorResponse, TokenQuery, TokenResponse},
+};
+use eventify_primitives::token::format_units;
+
+/// Get the Metadata of a Token
+///
+/// This endpoint returns what a token contract told about itself the first time it emitted a
+/// known event, each field being null if the contract doesn't implement the function it comes
+/// from.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the token.
+/// * `400 Bad Request`: The address is invalid.
+/// * `404 Not Found`: The token hasn't been resolved.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+///   "standard": "erc20",
+///   "name": "USD Coin",
+///   "symbol": "USDC",
+///   "decimals": 6,
+///   "totalSupply": "25000000000000000",
+///   "formattedTotalSupply": "25000000000"
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/tokens/{address}",
+    params(
+        ("address" = String, Path, description = "The token contract"),
+        TokenQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the token", body = TokenResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "Unknown token"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{address}")]
+pub(crate) async fn get_token(
+    conn: web::Data<PgPool>,
+    token: web::Path<String>,
+    query: web::Query<TokenQuery>,
+) -> impl Responder {
+    let token = match parse_address(Some(token.as_str())) {
+        Ok(token) => token.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT network, address, standard, name, symbol, decimals, total_supply::text
+        FROM token
+        WHERE network = $1 AND address = $2"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(token.as_slice())
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| {
+            row.map(|row| {
+                let decimals = row.try_get(5)?;
+                let total_supply: Option<String> = row.try_get(6)?;
+                Ok(TokenResponse {
+                    network: row.try_get(0)?,
+                    address: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+                    standard: row.try_get(2)?,
+                    name: row.try_get(3)?,
+                    symbol: row.try_get(4)?,
+                    decimals,
+                    formatted_total_supply: formatted(total_supply.as_deref(), decimals),
+                    total_supply,
+                })
+            })
+            .transpose()
+        }) {
+        Ok(Some(token)) => HttpResponse::Ok().json(token),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("unknown token: {}", token),
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// A decimal `amount` in the token's base unit, in its display unit given the token's
+/// `decimals`, if known.
+pub(crate) fn formatted(amount: Option<&str>, decimals: Option<i16>) -> Option<String> {
+    let decimals = u8::try_from(decimals?).ok()?;
+    let amount = amount?;
+    let (sign, digits) = match amount.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", amount),
+    };
+
+    Some(format!(
+        "{sign}{}",
+        format_units(digits.parse::<U256>().ok()?, decimals)
+    ))
+}
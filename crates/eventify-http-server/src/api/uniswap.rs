@@ -0,0 +1,216 @@
+use actix_web::{get, web, Responder};
+use sqlx::PgPool;
+
+use crate::{
+    api::events::{list_by_name, Column, EventTable},
+    types::EventsQuery,
+};
+
+const V2: &[EventTable] = &[
+    EventTable {
+        table: "uniswap_v2_swap",
+        event: "Swap",
+        columns: &[
+            ("sender", "sender", Column::Address),
+            ("amount0_in", "amount0In", Column::Uint),
+            ("amount1_in", "amount1In", Column::Uint),
+            ("amount0_out", "amount0Out", Column::Uint),
+            ("amount1_out", "amount1Out", Column::Uint),
+            ("to", "to", Column::Address),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v2_mint",
+        event: "Mint",
+        columns: &[
+            ("sender", "sender", Column::Address),
+            ("amount0", "amount0", Column::Uint),
+            ("amount1", "amount1", Column::Uint),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v2_burn",
+        event: "Burn",
+        columns: &[
+            ("sender", "sender", Column::Address),
+            ("amount0", "amount0", Column::Uint),
+            ("amount1", "amount1", Column::Uint),
+            ("to", "to", Column::Address),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v2_sync",
+        event: "Sync",
+        columns: &[
+            ("reserve0", "reserve0", Column::Uint),
+            ("reserve1", "reserve1", Column::Uint),
+        ],
+    },
+];
+
+const V3: &[EventTable] = &[
+    EventTable {
+        table: "uniswap_v3_swap",
+        event: "Swap",
+        columns: &[
+            ("sender", "sender", Column::Address),
+            ("recipient", "recipient", Column::Address),
+            ("amount0", "amount0", Column::Int),
+            ("amount1", "amount1", Column::Int),
+            ("sqrt_price_x96", "sqrtPriceX96", Column::Uint),
+            ("liquidity", "liquidity", Column::Uint),
+            ("tick", "tick", Column::Integer),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v3_mint",
+        event: "Mint",
+        columns: &[
+            ("sender", "sender", Column::Address),
+            ("owner", "owner", Column::Address),
+            ("tick_lower", "tickLower", Column::Integer),
+            ("tick_upper", "tickUpper", Column::Integer),
+            ("amount", "amount", Column::Uint),
+            ("amount0", "amount0", Column::Uint),
+            ("amount1", "amount1", Column::Uint),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v3_burn",
+        event: "Burn",
+        columns: &[
+            ("owner", "owner", Column::Address),
+            ("tick_lower", "tickLower", Column::Integer),
+            ("tick_upper", "tickUpper", Column::Integer),
+            ("amount", "amount", Column::Uint),
+            ("amount0", "amount0", Column::Uint),
+            ("amount1", "amount1", Column::Uint),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v3_collect",
+        event: "Collect",
+        columns: &[
+            ("owner", "owner", Column::Address),
+            ("recipient", "recipient", Column::Address),
+            ("tick_lower", "tickLower", Column::Integer),
+            ("tick_upper", "tickUpper", Column::Integer),
+            ("amount0", "amount0", Column::Uint),
+            ("amount1", "amount1", Column::Uint),
+        ],
+    },
+    EventTable {
+        table: "uniswap_v3_initialize",
+        event: "Initialize",
+        columns: &[
+            ("sqrt_price_x96", "sqrtPriceX96", Column::Uint),
+            ("tick", "tick", Column::Integer),
+        ],
+    },
+];
+
+/// Get Uniswap V2 Events
+///
+/// This endpoint returns the `Swap`, `Mint`, `Burn` or `Sync` events of Uniswap V2 pairs (and
+/// of the forks sharing their events), latest first, optionally only those of a single pair
+/// and network.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the events. The response body will be a JSON array of `{ "network": String, "address": String, "blockNumber": Number, "transactionHash": String, "logIndex": Number, "event": String, "params": Object }`, amounts being decimal strings.
+/// * `400 Bad Request`: The pair address, limit or offset is invalid.
+/// * `404 Not Found`: The event isn't one of `swap`, `mint`, `burn` or `sync`.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "address": "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc",
+///   "blockNumber": 19000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 12,
+///   "event": "Sync",
+///   "params": { "reserve0": "40321945512671", "reserve1": "17005381716154712451083" }
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/uniswap/v2/{event}",
+    params(
+        ("event" = String, Path, description = "One of swap, mint, burn or sync"),
+        EventsQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the events", body = [EventResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "Unknown event"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/v2/{event}")]
+pub(crate) async fn get_uniswap_v2_events(
+    conn: web::Data<PgPool>,
+    event: web::Path<String>,
+    query: web::Query<EventsQuery>,
+) -> impl Responder {
+    list_by_name(conn, V2, &event, &query).await
+}
+
+/// Get Uniswap V3 Events
+///
+/// This endpoint returns the `Swap`, `Mint`, `Burn`, `Collect` or `Initialize` events of
+/// Uniswap V3 pools (and of the forks sharing their events), latest first, optionally only
+/// those of a single pool and network.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the events. The response body will be a JSON array of `{ "network": String, "address": String, "blockNumber": Number, "transactionHash": String, "logIndex": Number, "event": String, "params": Object }`, amounts being (possibly negative) decimal strings and ticks numbers.
+/// * `400 Bad Request`: The pool address, limit or offset is invalid.
+/// * `404 Not Found`: The event isn't one of `swap`, `mint`, `burn`, `collect` or `initialize`.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "address": "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640",
+///   "blockNumber": 19000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 7,
+///   "event": "Swap",
+///   "params": {
+///     "sender": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///     "recipient": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///     "amount0": "-2500000000",
+///     "amount1": "1000000000000000000",
+///     "sqrtPriceX96": "1987177417612616932498627581470000",
+///     "liquidity": "24019611537802218540",
+///     "tick": 200637
+///   }
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/uniswap/v3/{event}",
+    params(
+        ("event" = String, Path, description = "One of swap, mint, burn, collect or initialize"),
+        EventsQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the events", body = [EventResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "Unknown event"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/v3/{event}")]
+pub(crate) async fn get_uniswap_v3_events(
+    conn: web::Data<PgPool>,
+    event: web::Path<String>,
+    query: web::Query<EventsQuery>,
+) -> impl Responder {
+    list_by_name(conn, V3, &event, &query).await
+}
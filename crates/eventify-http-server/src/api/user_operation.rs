@@ -0,0 +1,216 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use alloy_primitives::{hex, B256};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::events::{address, page, parse_address, uint},
+    types::{ErrorResponse, UserOperationResponse, UserOperationsQuery},
+};
+
+const SELECT: &str = r#"SELECT
+        uo.network,
+        uo.entry_point,
+        uo.user_op_hash,
+        uo.sender,
+        uo.paymaster,
+        COALESCE(t."from", r."from") AS bundler,
+        uo.nonce,
+        uo.success,
+        uo.actual_gas_cost,
+        uo.actual_gas_used,
+        uo.factory,
+        uo.revert_reason,
+        uo.post_op_revert_reason,
+        uo.block_number,
+        uo.tx_hash,
+        uo.log_index
+    FROM user_operation uo
+    LEFT JOIN transaction t ON t.hash = uo.tx_hash
+    LEFT JOIN receipt r ON r.tx_hash = uo.tx_hash"#;
+
+/// Get ERC-4337 User Operations
+///
+/// This endpoint returns the user operations executed by the canonical v0.6 and v0.7
+/// EntryPoints, latest first, optionally only those of a sender, paymaster or bundler.
+/// Bundlers are known only once the transaction or receipt of the bundle has been collected.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the user operations. The response body will be a JSON array of user operations, amounts being decimal strings and revert reasons hex encoded.
+/// * `400 Bad Request`: An address, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "base",
+///   "entryPoint": "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789",
+///   "userOpHash": "0x7c0b3f0e8d6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0d9c8b7a6f5e4d3c2b1a09",
+///   "sender": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "paymaster": "0x0000000000000000000000000000000000000000",
+///   "bundler": "0x4337001Fff419768e088Ce247456c1B892888084",
+///   "nonce": "12",
+///   "success": true,
+///   "actualGasCost": "31254000000000",
+///   "actualGasUsed": "156270",
+///   "factory": null,
+///   "revertReason": null,
+///   "postOpRevertReason": null,
+///   "blockNumber": 12000000,
+///   "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+///   "logIndex": 42
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/user-operations",
+    params(UserOperationsQuery),
+    responses(
+        (status = 200, description = "Successfully retrieved the user operations", body = [UserOperationResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("")]
+pub(crate) async fn get_user_operations(
+    conn: web::Data<PgPool>,
+    query: web::Query<UserOperationsQuery>,
+) -> impl Responder {
+    let (sender, paymaster, bundler) = match (
+        parse_address(query.sender.as_deref()),
+        parse_address(query.paymaster.as_deref()),
+        parse_address(query.bundler.as_deref()),
+    ) {
+        (Ok(sender), Ok(paymaster), Ok(bundler)) => (sender, paymaster, bundler),
+        (Err(error), _, _) | (_, Err(error), _) | (_, _, Err(error)) => {
+            return HttpResponse::BadRequest().json(error)
+        }
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = format!(
+        r#"{SELECT}
+        WHERE ($1::network_type IS NULL OR uo.network = $1)
+            AND ($2::bytea IS NULL OR uo.sender = $2)
+            AND ($3::bytea IS NULL OR uo.paymaster = $3)
+            AND ($4::bytea IS NULL OR COALESCE(t."from", r."from") = $4)
+        ORDER BY uo.block_number DESC NULLS LAST, uo.log_index DESC NULLS LAST
+        LIMIT $5 OFFSET $6"#
+    );
+    match sqlx::query(&sql)
+        .bind(query.network)
+        .bind(sender.as_ref().map(|a| a.as_slice()))
+        .bind(paymaster.as_ref().map(|a| a.as_slice()))
+        .bind(bundler.as_ref().map(|a| a.as_slice()))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(response).collect::<Result<Vec<_>, _>>())
+    {
+        Ok(operations) => HttpResponse::Ok().json(operations),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+/// Get an ERC-4337 User Operation
+///
+/// This endpoint returns the user operation with the given hash.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the user operation, see `GET /api/v1/user-operations`.
+/// * `400 Bad Request`: The hash isn't 32 hex encoded bytes.
+/// * `404 Not Found`: No such user operation has been collected.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+#[utoipa::path(
+    get,
+    path = "/api/v1/user-operations/{hash}",
+    params(
+        ("hash" = String, Path, description = "The userOpHash")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the user operation", body = UserOperationResponse),
+        (status = 400, description = "Invalid hash"),
+        (status = 404, description = "Unknown user operation"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{hash}")]
+pub(crate) async fn get_user_operation(
+    conn: web::Data<PgPool>,
+    hash: web::Path<String>,
+) -> impl Responder {
+    let Ok(user_op_hash) = hash.parse::<B256>() else {
+        return HttpResponse::BadRequest().json(ErrorResponse {
+            error: format!("invalid hash: {}", hash),
+        });
+    };
+
+    let sql = format!("{SELECT} WHERE uo.user_op_hash = $1 LIMIT 1");
+    match sqlx::query(&sql)
+        .bind(user_op_hash.as_slice())
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| row.as_ref().map(response).transpose())
+    {
+        Ok(Some(operation)) => HttpResponse::Ok().json(operation),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("unknown user operation: {}", user_op_hash),
+        }),
+        Err(err) => {
+            error!("Error: {}", err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Internal server error".to_string(),
+            })
+        }
+    }
+}
+
+fn response(row: &PgRow) -> Result<UserOperationResponse, sqlx::Error> {
+    let address_at = |i: usize| -> Result<Option<String>, sqlx::Error> {
+        Ok(row
+            .try_get::<Option<Vec<u8>>, _>(i)?
+            .and_then(|a| address(&a)))
+    };
+    let uint_at = |i: usize| -> Result<Option<String>, sqlx::Error> {
+        Ok(row
+            .try_get::<Option<Vec<u8>>, _>(i)?
+            .and_then(|v| uint(&v))
+            .map(|v| v.to_string()))
+    };
+    let hex_at = |i: usize| -> Result<Option<String>, sqlx::Error> {
+        Ok(row
+            .try_get::<Option<Vec<u8>>, _>(i)?
+            .map(hex::encode_prefixed))
+    };
+
+    Ok(UserOperationResponse {
+        network: row.try_get(0)?,
+        entry_point: address_at(1)?.unwrap_or_default(),
+        user_op_hash: hex_at(2)?.unwrap_or_default(),
+        sender: address_at(3)?.unwrap_or_default(),
+        paymaster: address_at(4)?,
+        bundler: address_at(5)?,
+        nonce: uint_at(6)?,
+        success: row.try_get(7)?,
+        actual_gas_cost: uint_at(8)?,
+        actual_gas_used: uint_at(9)?,
+        factory: address_at(10)?,
+        revert_reason: hex_at(11)?,
+        post_op_revert_reason: hex_at(12)?,
+        block_number: row.try_get(13)?,
+        tx_hash: hex_at(14)?,
+        log_index: row.try_get(15)?,
+    })
+}
@@ -0,0 +1,319 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use tracing::error;
+
+use crate::{
+    api::events::{address, page, parse_address},
+    types::{
+        ErrorResponse, PositionResponse, PositionsQuery, SharePriceQuery, SharePriceResponse,
+        VaultQuery, VaultResponse,
+    },
+};
+
+/// Get an ERC4626 Vault
+///
+/// This endpoint returns the assets and shares an ERC4626 vault has taken in through deposits
+/// and paid out through withdrawals, and the latest price of its shares.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the vault.
+/// * `400 Bad Request`: The address is invalid.
+/// * `404 Not Found`: No deposit into or withdrawal from the vault has been collected.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "network": "ethereum",
+///   "vault": "0x83F20F44975D03b1b09e64809B757c47f942BEeA",
+///   "assetsDeposited": "5000000000000000000000",
+///   "assetsWithdrawn": "1000000000000000000000",
+///   "netAssets": "4000000000000000000000",
+///   "sharesMinted": "4800000000000000000000",
+///   "sharesBurned": "950000000000000000000",
+///   "netShares": "3850000000000000000000",
+///   "deposits": 12,
+///   "withdrawals": 3,
+///   "sharePrice": "1.052301",
+///   "blockNumber": 19000000
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/vaults/{vault}",
+    params(
+        ("vault" = String, Path, description = "The ERC4626 vault"),
+        VaultQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the vault", body = VaultResponse),
+        (status = 400, description = "Invalid query"),
+        (status = 404, description = "Unknown vault"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{vault}")]
+pub(crate) async fn get_vault(
+    conn: web::Data<PgPool>,
+    vault: web::Path<String>,
+    query: web::Query<VaultQuery>,
+) -> impl Responder {
+    let vault = match parse_address(Some(vault.as_str())) {
+        Ok(vault) => vault.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT
+            t.network,
+            t.vault,
+            t.assets_deposited::text,
+            t.assets_withdrawn::text,
+            (t.assets_deposited - t.assets_withdrawn)::text,
+            t.shares_minted::text,
+            t.shares_burned::text,
+            (t.shares_minted - t.shares_burned)::text,
+            t.deposits,
+            t.withdrawals,
+            p.share_price::text,
+            t.block_number
+        FROM vault_total t
+        LEFT JOIN vault_share_price p
+            ON p.network = t.network AND p.vault = t.vault AND p.block_number = t.block_number
+        WHERE t.network = $1 AND t.vault = $2"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(vault.as_slice())
+        .fetch_optional(conn.as_ref())
+        .await
+        .and_then(|row| {
+            row.map(|row| {
+                Ok(VaultResponse {
+                    network: row.try_get(0)?,
+                    vault: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+                    assets_deposited: row.try_get(2)?,
+                    assets_withdrawn: row.try_get(3)?,
+                    net_assets: row.try_get(4)?,
+                    shares_minted: row.try_get(5)?,
+                    shares_burned: row.try_get(6)?,
+                    net_shares: row.try_get(7)?,
+                    deposits: row.try_get(8)?,
+                    withdrawals: row.try_get(9)?,
+                    share_price: row.try_get(10)?,
+                    block_number: row.try_get(11)?,
+                })
+            })
+            .transpose()
+        }) {
+        Ok(Some(vault)) => HttpResponse::Ok().json(vault),
+        Ok(None) => HttpResponse::NotFound().json(ErrorResponse {
+            error: format!("unknown vault: {}", vault),
+        }),
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Get the Share Price Series of an ERC4626 Vault
+///
+/// This endpoint returns, for each block the vault was deposited into or withdrawn from, the
+/// assets paid per share, in the assets' and shares' base units, oldest first.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the series.
+/// * `400 Bad Request`: The address, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "blockNumber": 19000000,
+///   "assets": "1052301000000000000000",
+///   "shares": "1000000000000000000000",
+///   "sharePrice": "1.052301"
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/vaults/{vault}/share-price",
+    params(
+        ("vault" = String, Path, description = "The ERC4626 vault"),
+        SharePriceQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the series", body = [SharePriceResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{vault}/share-price")]
+pub(crate) async fn get_share_price(
+    conn: web::Data<PgPool>,
+    vault: web::Path<String>,
+    query: web::Query<SharePriceQuery>,
+) -> impl Responder {
+    let vault = match parse_address(Some(vault.as_str())) {
+        Ok(vault) => vault.unwrap_or_default(),
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    let sql = r#"SELECT block_number, "assets"::text, shares::text, share_price::text
+        FROM vault_share_price
+        WHERE network = $1
+            AND vault = $2
+            AND ($3::bigint IS NULL OR block_number >= $3)
+            AND ($4::bigint IS NULL OR block_number <= $4)
+        ORDER BY block_number
+        LIMIT $5 OFFSET $6"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(vault.as_slice())
+        .bind(query.from_block)
+        .bind(query.to_block)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| {
+            rows.iter()
+                .map(|row| {
+                    Ok(SharePriceResponse {
+                        block_number: row.try_get(0)?,
+                        assets: row.try_get(1)?,
+                        shares: row.try_get(2)?,
+                        share_price: row.try_get(3)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, sqlx::Error>>()
+        }) {
+        Ok(series) => HttpResponse::Ok().json(series),
+        Err(err) => internal_error(err),
+    }
+}
+
+/// Get the Positions in an ERC4626 Vault
+///
+/// This endpoint returns, per depositor, the assets deposited and withdrawn, the shares minted
+/// and burned and the shares currently held, the largest depositors first.
+///
+/// # Responses
+///
+/// * `200 OK`: Successfully retrieved the positions.
+/// * `400 Bad Request`: An address, the limit or the offset is invalid.
+/// * `500 Internal Server Error`: Indicates that an error occurred on the server while processing the request. The response body will contain a JSON object with an error message.
+///
+/// # Example
+///
+/// ```json
+/// [{
+///   "network": "ethereum",
+///   "vault": "0x83F20F44975D03b1b09e64809B757c47f942BEeA",
+///   "owner": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+///   "assetsDeposited": "1000000000000000000000",
+///   "assetsWithdrawn": "0",
+///   "sharesMinted": "980000000000000000000",
+///   "sharesBurned": "0",
+///   "shares": "980000000000000000000"
+/// }]
+/// ```
+#[utoipa::path(
+    get,
+    path = "/api/v1/vaults/{vault}/positions",
+    params(
+        ("vault" = String, Path, description = "The ERC4626 vault"),
+        PositionsQuery
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the positions", body = [PositionResponse]),
+        (status = 400, description = "Invalid query"),
+        (status = 500, description = "Internal Server Error")
+    )
+)]
+#[get("/{vault}/positions")]
+pub(crate) async fn get_positions(
+    conn: web::Data<PgPool>,
+    vault: web::Path<String>,
+    query: web::Query<PositionsQuery>,
+) -> impl Responder {
+    let (vault, owner) = match (
+        parse_address(Some(vault.as_str())),
+        parse_address(query.owner.as_deref()),
+    ) {
+        (Ok(vault), Ok(owner)) => (vault.unwrap_or_default(), owner),
+        (Err(error), _) | (_, Err(error)) => return HttpResponse::BadRequest().json(error),
+    };
+    let (limit, offset) = match page(query.limit, query.offset) {
+        Ok(page) => page,
+        Err(error) => return HttpResponse::BadRequest().json(error),
+    };
+
+    // the shares are the vault's own ERC20 token
+    let sql = r#"SELECT
+            f.network,
+            f.vault,
+            f."owner",
+            f.assets_deposited::text,
+            f.assets_withdrawn::text,
+            f.shares_minted::text,
+            f.shares_burned::text,
+            b.balance::text
+        FROM (
+            SELECT
+                network,
+                vault,
+                "owner",
+                COALESCE(SUM("assets") FILTER (WHERE direction = 'deposit'), 0) AS assets_deposited,
+                COALESCE(SUM("assets") FILTER (WHERE direction = 'withdraw'), 0) AS assets_withdrawn,
+                COALESCE(SUM(shares) FILTER (WHERE direction = 'deposit'), 0) AS shares_minted,
+                COALESCE(SUM(shares) FILTER (WHERE direction = 'withdraw'), 0) AS shares_burned
+            FROM vault_flow
+            WHERE network = $1 AND vault = $2 AND ($3::bytea IS NULL OR "owner" = $3)
+            GROUP BY network, vault, "owner"
+        ) f
+        LEFT JOIN erc20_balance b
+            ON b.network = f.network AND b.token = f.vault AND b.holder = f."owner"
+        ORDER BY f.assets_deposited DESC, f."owner"
+        LIMIT $4 OFFSET $5"#;
+
+    match sqlx::query(sql)
+        .bind(query.network)
+        .bind(vault.as_slice())
+        .bind(owner.as_ref().map(|a| a.as_slice()))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(conn.as_ref())
+        .await
+        .and_then(|rows| rows.iter().map(position).collect::<Result<Vec<_>, _>>())
+    {
+        Ok(positions) => HttpResponse::Ok().json(positions),
+        Err(err) => internal_error(err),
+    }
+}
+
+fn position(row: &PgRow) -> Result<PositionResponse, sqlx::Error> {
+    Ok(PositionResponse {
+        network: row.try_get(0)?,
+        vault: address(&row.try_get::<Vec<u8>, _>(1)?).unwrap_or_default(),
+        owner: address(&row.try_get::<Vec<u8>, _>(2)?).unwrap_or_default(),
+        assets_deposited: row.try_get(3)?,
+        assets_withdrawn: row.try_get(4)?,
+        shares_minted: row.try_get(5)?,
+        shares_burned: row.try_get(6)?,
+        shares: row.try_get(7)?,
+    })
+}
+
+fn internal_error(err: sqlx::Error) -> HttpResponse {
+    error!("Error: {}", err);
+    HttpResponse::InternalServerError().json(ErrorResponse {
+        error: "Internal server error".to_string(),
+    })
+}
@@ -4,6 +4,7 @@
 
 pub mod api;
 pub mod error;
+pub mod metrics;
 pub mod startup;
 pub mod types;
 
@@ -0,0 +1,11 @@
+use once_cell::sync::Lazy;
+use prometheus::{register_histogram_vec, HistogramVec};
+
+pub static HTTP_REQUEST_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "eventify_http_request_duration_seconds",
+        "Time spent serving HTTP requests, by route",
+        &["method", "route", "status"]
+    )
+    .expect("failed to register eventify_http_request_duration_seconds")
+});
@@ -1,6 +1,9 @@
 use std::net::TcpListener;
 
-use actix_web::{dev::Server, web, App, HttpServer};
+use actix_web::{
+    dev::{Server, Service},
+    web, App, HttpServer,
+};
 use sqlx::{PgPool, Pool, Postgres};
 use utoipa::OpenApi;
 use utoipa_rapidoc::RapiDoc;
@@ -8,8 +11,11 @@ use utoipa_redoc::{Redoc, Servable};
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::{
-    api::{self, block, log},
-    Result,
+    api::{
+        self, abi, allowance, block, erc20, fn_sig, log, nft, price, token, uniswap,
+        user_operation, vault,
+    },
+    metrics, Result,
 };
 use eventify_configs::configs::ApplicationConfig;
 
@@ -38,7 +44,53 @@ impl Application {
 }
 
 #[derive(OpenApi)]
-#[openapi(paths(block::get_blocks_count, log::get_logs_count))]
+#[openapi(
+    paths(
+        block::get_blocks_count,
+        log::get_logs_count,
+        fn_sig::get_fn_sigs,
+        abi::register_abi,
+        abi::get_abis,
+        uniswap::get_uniswap_v2_events,
+        uniswap::get_uniswap_v3_events,
+        user_operation::get_user_operations,
+        user_operation::get_user_operation,
+        allowance::get_allowances,
+        price::get_price,
+        erc20::get_balances,
+        erc20::get_holders,
+        erc20::get_supply,
+        nft::get_owned,
+        nft::get_holder_count,
+        nft::get_owners,
+        nft::get_transfers,
+        nft::get_metadata,
+        vault::get_vault,
+        vault::get_share_price,
+        vault::get_positions,
+        token::get_token
+    ),
+    components(schemas(
+        crate::types::FnSigResponse,
+        crate::types::AbiRequest,
+        crate::types::AbiResponse,
+        crate::types::AbiEventResponse,
+        crate::types::EventResponse,
+        crate::types::UserOperationResponse,
+        crate::types::AllowanceResponse,
+        crate::types::PriceResponse,
+        crate::types::BalanceResponse,
+        crate::types::SupplyResponse,
+        crate::types::NftResponse,
+        crate::types::NftTransferResponse,
+        crate::types::HolderCountResponse,
+        crate::types::NftMetadataResponse,
+        crate::types::VaultResponse,
+        crate::types::SharePriceResponse,
+        crate::types::PositionResponse,
+        crate::types::TokenResponse
+    ))
+)]
 struct ApiDoc;
 
 pub fn start(
@@ -51,6 +103,27 @@ pub fn start(
 
     let server = HttpServer::new(move || {
         App::new()
+            .wrap_fn(|req, srv| {
+                let method = req.method().to_string();
+                let route = req
+                    .match_pattern()
+                    .unwrap_or_else(|| "unmatched".to_string());
+                let timer = std::time::Instant::now();
+                let fut = srv.call(req);
+
+                async move {
+                    let res = fut.await;
+                    let status = match &res {
+                        Ok(res) => res.status(),
+                        Err(err) => err.as_response_error().status_code(),
+                    };
+                    metrics::HTTP_REQUEST_DURATION
+                        .with_label_values(&[&method, &route, status.as_str()])
+                        .observe(timer.elapsed().as_secs_f64());
+
+                    res
+                }
+            })
             // swagger-related
             .service(Redoc::with_url("/redoc", openapi.clone()))
             .service(
@@ -59,11 +132,51 @@ pub fn start(
             .service(RapiDoc::new("/api-docs/openapi.json").path("/rapidoc"))
             // api
             .route("/health", web::get().to(api::health))
+            .route("/metrics", web::get().to(api::metrics))
             .service(
                 web::scope("/api").service(
                     web::scope("/v1")
                         .service(web::scope("/blocks").service(block::get_blocks_count))
-                        .service(web::scope("/logs").service(api::log::get_logs_count)),
+                        .service(web::scope("/logs").service(api::log::get_logs_count))
+                        .service(web::scope("/fn-sigs").service(fn_sig::get_fn_sigs))
+                        .service(
+                            web::scope("/abis")
+                                .service(abi::register_abi)
+                                .service(abi::get_abis),
+                        )
+                        .service(
+                            web::scope("/uniswap")
+                                .service(uniswap::get_uniswap_v2_events)
+                                .service(uniswap::get_uniswap_v3_events),
+                        )
+                        .service(
+                            web::scope("/user-operations")
+                                .service(user_operation::get_user_operations)
+                                .service(user_operation::get_user_operation),
+                        )
+                        .service(web::scope("/allowances").service(allowance::get_allowances))
+                        .service(web::scope("/prices").service(price::get_price))
+                        .service(
+                            web::scope("/erc20")
+                                .service(erc20::get_balances)
+                                .service(erc20::get_holders)
+                                .service(erc20::get_supply),
+                        )
+                        .service(
+                            web::scope("/nfts")
+                                .service(nft::get_owned)
+                                .service(nft::get_holder_count)
+                                .service(nft::get_owners)
+                                .service(nft::get_transfers)
+                                .service(nft::get_metadata),
+                        )
+                        .service(
+                            web::scope("/vaults")
+                                .service(vault::get_vault)
+                                .service(vault::get_share_price)
+                                .service(vault::get_positions),
+                        )
+                        .service(web::scope("/tokens").service(token::get_token)),
                 ),
             )
             .app_data(db_pool.clone())
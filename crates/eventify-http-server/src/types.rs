@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub(crate) struct CountResponse {
@@ -13,3 +13,456 @@ pub(crate) struct CountResponse {
 pub(crate) struct ErrorResponse {
     pub(crate) error: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub(crate) struct FnSigResponse {
+    /// The 4-byte function selector
+    #[schema(example = "0xa9059cbb")]
+    pub(crate) selector: String,
+    /// Every known signature hashing to the selector, shortest first
+    pub(crate) signatures: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub(crate) struct AbiRequest {
+    /// The network the ABI applies to, all of them if omitted
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// The contract the ABI is bound to; if omitted, logs are matched by topic0 alone
+    #[schema(example = "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640")]
+    pub(crate) address: Option<String>,
+    /// Either the bare JSON ABI or a build artifact holding it under `abi`
+    #[schema(value_type = Object)]
+    pub(crate) abi: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub(crate) struct AbiResponse {
+    pub(crate) id: i32,
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    pub(crate) address: Option<String>,
+    /// The events logs are decoded against
+    pub(crate) events: Vec<AbiEventResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub(crate) struct AbiEventResponse {
+    #[schema(example = "Swap")]
+    pub(crate) name: String,
+    #[schema(example = "Swap(address,address,int256,int256,uint160,uint128,int24)")]
+    pub(crate) signature: String,
+    #[schema(example = "0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67")]
+    pub(crate) topic0: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct EventsQuery {
+    /// Only the events of the given network
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// Only the events emitted by the given contract
+    #[param(example = "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc")]
+    pub(crate) address: Option<String>,
+    /// At most this many events, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many events
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub(crate) struct EventResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// The emitting contract
+    #[schema(example = "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc")]
+    pub(crate) address: String,
+    #[serde(rename = "blockNumber")]
+    pub(crate) block_number: Option<i64>,
+    #[serde(rename = "transactionHash")]
+    pub(crate) tx_hash: Option<String>,
+    #[serde(rename = "logIndex")]
+    pub(crate) log_index: Option<i64>,
+    #[schema(example = "Swap")]
+    pub(crate) event: String,
+    /// The event's parameters keyed by their name, integers as decimal strings
+    #[schema(value_type = Object)]
+    pub(crate) params: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct UserOperationsQuery {
+    /// Only the user operations of the given network
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// Only the user operations of the given smart account
+    pub(crate) sender: Option<String>,
+    /// Only the user operations sponsored by the given paymaster
+    pub(crate) paymaster: Option<String>,
+    /// Only the user operations bundled by the given EOA, known once the bundle's transaction or
+    /// receipt has been collected
+    pub(crate) bundler: Option<String>,
+    /// At most this many user operations, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many user operations
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UserOperationResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    #[schema(example = "0x0000000071727De22E5E9d8BAf0edAc6f37da032")]
+    pub(crate) entry_point: String,
+    pub(crate) user_op_hash: String,
+    pub(crate) sender: String,
+    /// The zero address for user operations paying for themselves
+    pub(crate) paymaster: Option<String>,
+    /// The sender of the bundle's transaction
+    pub(crate) bundler: Option<String>,
+    pub(crate) nonce: Option<String>,
+    pub(crate) success: Option<bool>,
+    /// In wei, as a decimal string
+    pub(crate) actual_gas_cost: Option<String>,
+    pub(crate) actual_gas_used: Option<String>,
+    /// The factory the account was deployed through, if deployed by the user operation
+    pub(crate) factory: Option<String>,
+    pub(crate) revert_reason: Option<String>,
+    pub(crate) post_op_revert_reason: Option<String>,
+    pub(crate) block_number: Option<i64>,
+    #[serde(rename = "transactionHash")]
+    pub(crate) tx_hash: Option<String>,
+    pub(crate) log_index: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct AllowancesQuery {
+    /// Only the allowances of the given network
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// Only the allowances given by the given account
+    pub(crate) owner: Option<String>,
+    /// Only the allowances given to the given spender
+    pub(crate) spender: Option<String>,
+    /// Only the allowances over the given token
+    pub(crate) token: Option<String>,
+    /// Only the allowances given through `erc20` (the token's `approve`) or `permit2`
+    pub(crate) source: Option<String>,
+    /// At most this many allowances, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many allowances
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AllowanceResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) token: String,
+    pub(crate) owner: String,
+    pub(crate) spender: String,
+    #[schema(example = "permit2")]
+    pub(crate) source: String,
+    /// As a decimal string, in the token's base unit
+    pub(crate) amount: Option<String>,
+    /// The token's symbol, if resolved
+    pub(crate) symbol: Option<String>,
+    /// The token's decimals, if resolved
+    pub(crate) decimals: Option<i16>,
+    /// The amount in the token's display unit, if its decimals are known
+    pub(crate) formatted_amount: Option<String>,
+    /// The unix timestamp a Permit2 allowance expires at
+    pub(crate) expiration: Option<i64>,
+    /// The next Permit2 nonce of the owner, token and spender
+    pub(crate) nonce: Option<i64>,
+    /// The block of the latest change
+    pub(crate) block_number: Option<i64>,
+    #[serde(rename = "transactionHash")]
+    pub(crate) tx_hash: Option<String>,
+    pub(crate) log_index: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct PriceQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// The name of the feed as configured, e.g. `ETH/USD`
+    pub(crate) feed: String,
+    /// The price as of the end of the given block
+    pub(crate) block: Option<i64>,
+    /// The price as of the given unix timestamp, by the time the aggregator answered at
+    pub(crate) timestamp: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PriceResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    #[schema(example = "ETH/USD")]
+    pub(crate) feed: String,
+    pub(crate) aggregator: String,
+    pub(crate) round_id: Option<String>,
+    /// The answer as reported, a decimal string
+    pub(crate) answer: Option<String>,
+    pub(crate) decimals: i16,
+    /// The answer scaled by the feed's decimals, a decimal string
+    #[schema(example = "3521.12345678")]
+    pub(crate) price: Option<String>,
+    /// The unix timestamp the aggregator answered at
+    pub(crate) answered_at: Option<i64>,
+    pub(crate) block_number: Option<i64>,
+    #[serde(rename = "transactionHash")]
+    pub(crate) tx_hash: Option<String>,
+    pub(crate) log_index: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct BalancesQuery {
+    /// Only the balances on the given network, required along with a block
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// The balances as of the end of the given block, the latest ones if not given
+    pub(crate) block: Option<i64>,
+    /// At most this many balances, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many balances
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BalanceResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) token: String,
+    pub(crate) holder: String,
+    /// As a decimal string, in the token's base unit
+    #[schema(example = "1500000000")]
+    pub(crate) balance: String,
+    /// The token's symbol, if resolved
+    #[schema(example = "USDC")]
+    pub(crate) symbol: Option<String>,
+    /// The token's decimals, if resolved
+    pub(crate) decimals: Option<i16>,
+    /// The balance in the token's display unit, if its decimals are known
+    #[schema(example = "1500")]
+    pub(crate) formatted_balance: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct SupplyQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// The supply as of the end of the given block, the latest one if not given
+    pub(crate) block: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SupplyResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) token: String,
+    /// Minted less burned, as a decimal string in the token's base unit
+    #[schema(example = "1000000000000000000000000")]
+    pub(crate) supply: String,
+    /// The token's symbol, if resolved
+    pub(crate) symbol: Option<String>,
+    /// The token's decimals, if resolved
+    pub(crate) decimals: Option<i16>,
+    /// The supply in the token's display unit, if its decimals are known
+    #[schema(example = "1000000")]
+    pub(crate) formatted_supply: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct OwnedNftsQuery {
+    /// Only the tokens on the given network
+    pub(crate) network: Option<eventify_primitives::networks::NetworkKind>,
+    /// Only the tokens of the given contract
+    pub(crate) contract: Option<String>,
+    /// At most this many tokens, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many tokens
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct CollectionQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// At most this many entries, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many entries
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NftResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) contract: String,
+    /// As a decimal string
+    #[schema(example = "1234")]
+    pub(crate) token_id: String,
+    pub(crate) owner: String,
+    /// As a decimal string, 1 for ERC721 tokens
+    #[schema(example = "1")]
+    pub(crate) quantity: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NftTransferResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) contract: String,
+    pub(crate) token_id: String,
+    #[schema(example = "erc1155")]
+    pub(crate) standard: String,
+    pub(crate) operator: Option<String>,
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) quantity: String,
+    pub(crate) block_number: Option<i64>,
+    #[serde(rename = "transactionHash")]
+    pub(crate) tx_hash: Option<String>,
+    pub(crate) log_index: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HolderCountResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) contract: String,
+    /// The accounts holding at least one token of the contract
+    pub(crate) holders: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NftMetadataResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) contract: String,
+    /// As a decimal string
+    #[schema(example = "1234")]
+    pub(crate) token_id: String,
+    #[schema(example = "erc721")]
+    pub(crate) standard: String,
+    /// The token's URI, before the `{id}` substitution, unset until resolved
+    pub(crate) uri: Option<String>,
+    /// `pending` until fetched, `failed` once given up on
+    #[schema(example = "fetched")]
+    pub(crate) status: String,
+    /// The JSON document the URI points to
+    #[schema(value_type = Option<Object>)]
+    pub(crate) metadata: Option<serde_json::Value>,
+    /// Why the latest attempt failed
+    pub(crate) error: Option<String>,
+    pub(crate) attempts: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct VaultQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VaultResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) vault: String,
+    /// As a decimal string, in the asset's base unit
+    pub(crate) assets_deposited: String,
+    pub(crate) assets_withdrawn: String,
+    /// Deposited less withdrawn, leaving the yield out
+    pub(crate) net_assets: String,
+    /// As a decimal string, in the share's base unit
+    pub(crate) shares_minted: String,
+    pub(crate) shares_burned: String,
+    pub(crate) net_shares: String,
+    pub(crate) deposits: i64,
+    pub(crate) withdrawals: i64,
+    /// The assets paid per share at the latest block with flows, a decimal string
+    #[schema(example = "1.052301")]
+    pub(crate) share_price: Option<String>,
+    pub(crate) block_number: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SharePriceQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// Only the blocks from the given one on
+    pub(crate) from_block: Option<i64>,
+    /// Only the blocks up to the given one
+    pub(crate) to_block: Option<i64>,
+    /// At most this many blocks, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many blocks
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SharePriceResponse {
+    pub(crate) block_number: Option<i64>,
+    /// The assets moved by the block's deposits and withdrawals, a decimal string
+    pub(crate) assets: String,
+    /// The shares moved by the block's deposits and withdrawals, a decimal string
+    pub(crate) shares: String,
+    /// The assets paid per share, a decimal string
+    #[schema(example = "1.052301")]
+    pub(crate) share_price: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct PositionsQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    /// Only the position of the given owner
+    pub(crate) owner: Option<String>,
+    /// At most this many positions, 100 by default and 1000 at most
+    pub(crate) limit: Option<i64>,
+    /// Skip this many positions
+    pub(crate) offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PositionResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) vault: String,
+    pub(crate) owner: String,
+    /// As decimal strings, in the asset's base unit
+    pub(crate) assets_deposited: String,
+    pub(crate) assets_withdrawn: String,
+    /// As decimal strings, in the share's base unit
+    pub(crate) shares_minted: String,
+    pub(crate) shares_burned: String,
+    /// The shares currently held, share transfers included, as folded from the vault's ERC20
+    /// transfers
+    pub(crate) shares: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(crate) struct TokenQuery {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TokenResponse {
+    pub(crate) network: eventify_primitives::networks::NetworkKind,
+    pub(crate) address: String,
+    /// `erc20`, `erc721` or `erc1155`
+    #[schema(example = "erc20")]
+    pub(crate) standard: Option<String>,
+    #[schema(example = "USD Coin")]
+    pub(crate) name: Option<String>,
+    #[schema(example = "USDC")]
+    pub(crate) symbol: Option<String>,
+    pub(crate) decimals: Option<i16>,
+    /// As of the resolution, as a decimal string in the token's base unit
+    pub(crate) total_supply: Option<String>,
+    /// The total supply in the token's display unit, if its decimals are known
+    pub(crate) formatted_total_supply: Option<String>,
+}
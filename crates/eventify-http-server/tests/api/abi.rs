@@ -0,0 +1,95 @@
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+
+const POOL: &str = "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640";
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn register_and_list_abis() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let url = format!("{}/api/v1/abis", app.address);
+    let register = |body: Value| {
+        let request = client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+        async move { request.send().await.expect("Failed to execute request.") }
+    };
+
+    let swap = json!({"type": "event", "name": "Swap", "anonymous": false, "inputs": [
+        {"name": "sender", "type": "address", "indexed": true},
+        {"name": "recipient", "type": "address", "indexed": true},
+        {"name": "amount0", "type": "int256", "indexed": false},
+        {"name": "amount1", "type": "int256", "indexed": false},
+        {"name": "sqrtPriceX96", "type": "uint160", "indexed": false},
+        {"name": "liquidity", "type": "uint128", "indexed": false},
+        {"name": "tick", "type": "int24", "indexed": false}
+    ]});
+    let response = register(json!({
+        "network": "ethereum",
+        "address": POOL,
+        "abi": {"abi": [{"type": "function", "name": "swap", "inputs": []}, swap]},
+    }))
+    .await;
+    assert_eq!(response.status().as_u16(), 201);
+    let registered = json(response).await;
+    assert_eq!(
+        registered["events"],
+        json!([{
+            "name": "Swap",
+            "signature": "Swap(address,address,int256,int256,uint160,uint128,int24)",
+            "topic0": "0xc42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67",
+        }])
+    );
+
+    let transfer = json!({"type": "event", "name": "Transfer", "inputs": [
+        {"name": "from", "type": "address", "indexed": true},
+        {"name": "to", "type": "address", "indexed": true},
+        {"name": "value", "type": "uint256", "indexed": false}
+    ]});
+    assert_eq!(
+        register(json!({"abi": [transfer]})).await.status().as_u16(),
+        201
+    );
+
+    let abis = json(client.get(&url).send().await.unwrap()).await;
+    assert_eq!(abis.as_array().unwrap().len(), 2);
+    assert_eq!(abis[0]["id"], registered["id"]);
+    assert_eq!(abis[0]["network"], "ethereum");
+    assert_eq!(abis[0]["address"], POOL);
+    assert_eq!(abis[1]["network"], Value::Null);
+    assert_eq!(abis[1]["address"], Value::Null);
+    assert_eq!(
+        abis[1]["events"][0]["signature"],
+        "Transfer(address,address,uint256)"
+    );
+
+    for invalid in [
+        json!({"address": "0x12", "abi": [transfer]}),
+        json!({"abi": {"bytecode": "0x"}}),
+        json!({"abi": [{"type": "function", "name": "swap", "inputs": []}]}),
+        json!({"abi": [{"type": "event", "name": "Set", "inputs": [
+            {"name": "value", "type": "uint7", "indexed": false}
+        ]}]}),
+    ] {
+        assert_eq!(
+            register(invalid.clone()).await.status().as_u16(),
+            400,
+            "{}",
+            invalid
+        );
+    }
+    assert_eq!(
+        json(client.get(&url).send().await.unwrap())
+            .await
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+}
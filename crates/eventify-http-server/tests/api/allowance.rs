@@ -0,0 +1,130 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, Permit2, ERC20},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const TOKEN: Address = Address::repeat_byte(0xaa);
+const OWNER: Address = Address::repeat_byte(0x11);
+const ROUTER: Address = Address::repeat_byte(0x22);
+const PERMIT2: Address = Address::repeat_byte(0x33);
+
+fn emitted<E>(address: Address, block_number: u64, event: E) -> Emitted<E> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address,
+        block_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event,
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn allowances_by_owner_and_source() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let approval = emitted(
+        TOKEN,
+        100,
+        ERC20::Approval {
+            owner: OWNER,
+            spender: PERMIT2,
+            value: U256::MAX,
+        },
+    );
+    approval
+        .insert(&app.db_pool, &approval.tx_hash)
+        .await
+        .unwrap();
+    let permit = emitted(
+        PERMIT2,
+        200,
+        Permit2::Permit {
+            owner: OWNER,
+            token: TOKEN,
+            spender: ROUTER,
+            amount: U256::from(1_000).to(),
+            expiration: 1_700_000_000,
+            nonce: 1,
+        },
+    );
+    permit.insert(&app.db_pool, &permit.tx_hash).await.unwrap();
+
+    let get = |query: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/allowances?{}", app.address, query);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let allowances = json(get(format!("network=ethereum&owner={OWNER}")).await).await;
+    assert_eq!(
+        allowances,
+        json!([
+            {
+                "network": "ethereum",
+                "token": TOKEN.to_checksum(None),
+                "owner": OWNER.to_checksum(None),
+                "spender": ROUTER.to_checksum(None),
+                "source": "permit2",
+                "amount": "1000",
+                "symbol": null,
+                "decimals": null,
+                "formattedAmount": null,
+                "expiration": 1_700_000_000,
+                "nonce": 1,
+                "blockNumber": 200,
+                "transactionHash": B256::left_padding_from(&200u64.to_be_bytes()).to_string(),
+                "logIndex": 0
+            },
+            {
+                "network": "ethereum",
+                "token": TOKEN.to_checksum(None),
+                "owner": OWNER.to_checksum(None),
+                "spender": PERMIT2.to_checksum(None),
+                "source": "erc20",
+                "amount": U256::MAX.to_string(),
+                "symbol": null,
+                "decimals": null,
+                "formattedAmount": null,
+                "expiration": null,
+                "nonce": null,
+                "blockNumber": 100,
+                "transactionHash": B256::left_padding_from(&100u64.to_be_bytes()).to_string(),
+                "logIndex": 0
+            },
+        ])
+    );
+
+    let erc20 = json(get(format!("owner={OWNER}&source=erc20")).await).await;
+    assert_eq!(erc20.as_array().unwrap().len(), 1);
+    assert_eq!(erc20[0]["spender"], PERMIT2.to_checksum(None));
+
+    let paged = json(get(format!("owner={OWNER}&limit=1&offset=1")).await).await;
+    assert_eq!(paged.as_array().unwrap().len(), 1);
+    assert_eq!(paged[0]["source"], "erc20");
+
+    let others = json(get(format!("owner={ROUTER}")).await).await;
+    assert_eq!(others, json!([]));
+
+    assert_eq!(get("source=foo".to_string()).await.status().as_u16(), 400);
+    assert_eq!(get("owner=0x12".to_string()).await.status().as_u16(), 400);
+}
@@ -0,0 +1,93 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, ERC20},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const TOKEN: Address = Address::repeat_byte(0xaa);
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+
+fn transfer(block_number: u64, from: Address, to: Address, value: u64) -> Emitted<ERC20::Transfer> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address: TOKEN,
+        block_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event: ERC20::Transfer {
+            from,
+            to,
+            value: U256::from(value),
+        },
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn balances_holders_and_supply() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for transfer in [
+        transfer(100, Address::ZERO, ALICE, 1_000),
+        transfer(200, ALICE, BOB, 600),
+        transfer(300, BOB, Address::ZERO, 100),
+    ] {
+        transfer
+            .insert(&app.db_pool, &transfer.tx_hash)
+            .await
+            .unwrap();
+    }
+
+    let get = |path: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/erc20/{}", app.address, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let holders = json(get(format!("holders/{TOKEN}")).await).await;
+    assert_eq!(
+        holders,
+        json!([
+            {"network": "ethereum", "token": TOKEN.to_checksum(None), "holder": BOB.to_checksum(None), "balance": "500", "symbol": null, "decimals": null, "formattedBalance": null},
+            {"network": "ethereum", "token": TOKEN.to_checksum(None), "holder": ALICE.to_checksum(None), "balance": "400", "symbol": null, "decimals": null, "formattedBalance": null},
+        ])
+    );
+
+    let at_block = json(get(format!("holders/{TOKEN}?network=ethereum&block=199")).await).await;
+    assert_eq!(at_block.as_array().unwrap().len(), 1);
+    assert_eq!(at_block[0]["balance"], "1000");
+
+    let balances = json(get(format!("balances/{BOB}?network=ethereum")).await).await;
+    assert_eq!(balances[0]["balance"], "500");
+
+    let supply = json(get(format!("supply/{TOKEN}?network=ethereum")).await).await;
+    assert_eq!(supply["supply"], "900");
+    let supply = json(get(format!("supply/{TOKEN}?network=ethereum&block=299")).await).await;
+    assert_eq!(supply["supply"], "1000");
+
+    let status = |response: reqwest::Response| response.status().as_u16();
+    assert_eq!(
+        status(get(format!("supply/{TOKEN}?network=ethereum&block=99")).await),
+        404
+    );
+    assert_eq!(status(get(format!("balances/{BOB}?block=1")).await), 400);
+    assert_eq!(status(get("balances/0x12".to_string()).await), 400);
+}
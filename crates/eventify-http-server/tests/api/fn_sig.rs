@@ -0,0 +1,50 @@
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn signatures_of_selector() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for signature in ["transfer(address,uint256)", "many_msg_babbage(bytes1)"] {
+        sqlx::query("INSERT INTO fn_sig (hex_sig, text_sig) VALUES ('\\xa9059cbb', $1)")
+            .bind(signature)
+            .execute(&app.db_pool)
+            .await
+            .unwrap();
+    }
+
+    let get = |selector: &'static str| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/fn-sigs/{}", app.address, selector);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    assert_eq!(
+        json(get("0xa9059cbb").await).await,
+        json!({
+            "selector": "0xa9059cbb",
+            "signatures": ["many_msg_babbage(bytes1)", "transfer(address,uint256)"],
+        })
+    );
+    // the prefix is optional and the case irrelevant
+    assert_eq!(json(get("A9059CBB").await).await["selector"], "0xa9059cbb");
+    assert_eq!(
+        json(get("0x095ea7b3").await).await,
+        json!({"selector": "0x095ea7b3", "signatures": []})
+    );
+
+    for selector in ["0xa9059c", "0xa9059cbbaa", "0xzz059cbb"] {
+        assert_eq!(get(selector).await.status().as_u16(), 400, "{}", selector);
+    }
+}
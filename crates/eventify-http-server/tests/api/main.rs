@@ -1,4 +1,15 @@
+mod abi;
+mod allowance;
 mod block;
+mod erc20;
+mod fn_sig;
 mod health;
 mod helpers;
 mod log;
+mod metrics;
+mod nft;
+mod price;
+mod token;
+mod uniswap;
+mod user_operation;
+mod vault;
@@ -0,0 +1,53 @@
+use crate::helpers::spawn_app;
+
+#[tokio::test]
+async fn metrics_endpoint_works() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    client
+        .get(format!("{}/health", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let response = client
+        .get(format!("{}/metrics", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert!(response.status().is_success());
+    let body = response.text().await.expect("Failed to read body.");
+    assert!(body.contains("eventify_http_request_duration_seconds"));
+}
+
+#[tokio::test]
+async fn metrics_include_rejected_requests() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/api/v1/abis", &app.address))
+        .header("Content-Type", "application/json")
+        .body("not json")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 400);
+
+    let body = client
+        .get(format!("{}/metrics", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .text()
+        .await
+        .expect("Failed to read body.");
+
+    assert!(body.lines().any(|line| line
+        .starts_with("eventify_http_request_duration_seconds_count")
+        && line.contains(r#"method="POST""#)
+        && line.contains(r#"route="/api/v1/abis""#)
+        && line.contains(r#"status="400""#)));
+}
@@ -0,0 +1,141 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::Value;
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, ERC721},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const COLLECTION: Address = Address::repeat_byte(0xaa);
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+
+fn transfer(
+    block_number: u64,
+    from: Address,
+    to: Address,
+    token_id: u64,
+) -> Emitted<ERC721::Transfer> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address: COLLECTION,
+        block_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event: ERC721::Transfer {
+            from,
+            to,
+            tokenId: U256::from(token_id),
+        },
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn owned_owners_holders_and_history() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for transfer in [
+        transfer(100, Address::ZERO, ALICE, 1),
+        transfer(101, Address::ZERO, ALICE, 2),
+        transfer(200, ALICE, BOB, 1),
+    ] {
+        transfer
+            .insert(&app.db_pool, &transfer.tx_hash)
+            .await
+            .unwrap();
+    }
+
+    let get = |path: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/nfts/{}", app.address, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let owned = json(get(format!("owned/{ALICE}?network=ethereum")).await).await;
+    assert_eq!(owned.as_array().unwrap().len(), 1);
+    assert_eq!(owned[0]["tokenId"], "2");
+    assert_eq!(owned[0]["quantity"], "1");
+
+    let owners = json(get(format!("{COLLECTION}/1/owners?network=ethereum")).await).await;
+    assert_eq!(owners[0]["owner"], BOB.to_checksum(None));
+
+    let holders = json(get(format!("{COLLECTION}/holders?network=ethereum")).await).await;
+    assert_eq!(holders["holders"], 2);
+
+    let transfers = json(get(format!("{COLLECTION}/0x01/transfers?network=ethereum")).await).await;
+    assert_eq!(transfers.as_array().unwrap().len(), 2);
+    assert_eq!(transfers[0]["to"], BOB.to_checksum(None));
+    assert_eq!(transfers[1]["from"], Address::ZERO.to_checksum(None));
+
+    let status = get(format!("{COLLECTION}/x/owners?network=ethereum"))
+        .await
+        .status();
+    assert_eq!(status.as_u16(), 400);
+}
+
+#[tokio::test]
+async fn metadata() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let get = |path: &str| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/nfts/{COLLECTION}/{path}", app.address);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    assert_eq!(
+        get("1/metadata?network=ethereum").await.status().as_u16(),
+        404
+    );
+
+    let mint = transfer(100, Address::ZERO, ALICE, 1);
+    mint.insert(&app.db_pool, &mint.tx_hash).await.unwrap();
+    let queued = json(get("1/metadata?network=ethereum").await).await;
+    assert_eq!(queued["status"], "pending");
+    assert_eq!(queued["uri"], Value::Null);
+
+    // as fetched by the worker
+    sqlx::query(
+        r##"UPDATE nft_metadata SET uri = 'ipfs://QmXyz/1', status = 'fetched', metadata = '{"name": "#1"}', attempts = 1
+        WHERE network = 'ethereum' AND contract = $1 AND token_id = 1"##,
+    )
+    .bind(COLLECTION.as_slice())
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+
+    let metadata = json(get("0x1/metadata?network=ethereum").await).await;
+    assert_eq!(metadata["tokenId"], "1");
+    assert_eq!(metadata["standard"], "erc721");
+    assert_eq!(metadata["uri"], "ipfs://QmXyz/1");
+    assert_eq!(metadata["status"], "fetched");
+    assert_eq!(metadata["metadata"]["name"], "#1");
+    assert_eq!(metadata["attempts"], 1);
+
+    assert_eq!(
+        get("x/metadata?network=ethereum").await.status().as_u16(),
+        400
+    );
+}
@@ -0,0 +1,78 @@
+use alloy_primitives::{Address, B256, I256, U256, U64};
+use serde_json::Value;
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{chainlink::PriceUpdate, Chainlink, Emitted},
+    networks::NetworkKind,
+    InsertT,
+};
+
+fn update(block_number: u64, answer: i64, answered_at: u64) -> PriceUpdate {
+    PriceUpdate {
+        feed: "ETH/USD".to_string(),
+        decimals: 8,
+        update: Emitted {
+            network: NetworkKind::Ethereum,
+            address: Address::repeat_byte(0xaa),
+            block_hash: None,
+            block_number: Some(U64::from(block_number)),
+            tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+            log_index: Some(U64::from(0)),
+            removed: false,
+            token: None,
+            formatted_value: None,
+            event: Chainlink::AnswerUpdated {
+                current: I256::try_from(answer).unwrap(),
+                roundId: U256::from(block_number),
+                updatedAt: U256::from(answered_at),
+            },
+        },
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn price_at_block_or_timestamp() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for update in [
+        update(100, 300_000_000_000, 1_000),
+        update(200, 310_050_000_000, 2_000),
+    ] {
+        update.insert(&app.db_pool, &None).await.unwrap();
+    }
+
+    let price = |query: &'static str| {
+        let client = client.clone();
+        let url = format!(
+            "{}/api/v1/prices?network=ethereum&feed=ETH/USD{}",
+            app.address, query
+        );
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let latest = json(price("").await).await;
+    assert_eq!(latest["price"], "3100.5");
+    assert_eq!(latest["answer"], "310050000000");
+    assert_eq!(latest["blockNumber"], 200);
+
+    let at_block = json(price("&block=199").await).await;
+    assert_eq!(at_block["price"], "3000");
+    assert_eq!(at_block["roundId"], "100");
+
+    let at_timestamp = json(price("&timestamp=2000").await).await;
+    assert_eq!(at_timestamp["price"], "3100.5");
+
+    assert_eq!(price("&block=99").await.status().as_u16(), 404);
+    assert_eq!(price("&block=1&timestamp=1").await.status().as_u16(), 400);
+}
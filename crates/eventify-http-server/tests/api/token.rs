@@ -0,0 +1,95 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, ERC20},
+    networks::NetworkKind,
+    token::{Standard, Token},
+    InsertT,
+};
+
+const TOKEN: Address = Address::repeat_byte(0xaa);
+const ALICE: Address = Address::repeat_byte(0x11);
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn token_metadata_and_formatted_amounts() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let get = |path: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/{}", app.address, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let response = get(format!("tokens/{TOKEN}?network=ethereum")).await;
+    assert_eq!(response.status().as_u16(), 404);
+
+    Token {
+        standard: Some(Standard::Erc20),
+        name: Some("USD Coin".to_string()),
+        symbol: Some("USDC".to_string()),
+        decimals: Some(6),
+        total_supply: Some(U256::from(2_500_000)),
+    }
+    .upsert(&app.db_pool, NetworkKind::Ethereum, TOKEN)
+    .await
+    .unwrap();
+    let mint = Emitted {
+        network: NetworkKind::Ethereum,
+        address: TOKEN,
+        block_hash: Some(B256::repeat_byte(0x01)),
+        block_number: Some(U64::from(100)),
+        tx_hash: Some(B256::repeat_byte(0x02)),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event: ERC20::Transfer {
+            from: Address::ZERO,
+            to: ALICE,
+            value: U256::from(1_500_000),
+        },
+    };
+    mint.insert(&app.db_pool, &mint.tx_hash).await.unwrap();
+
+    assert_eq!(
+        json(get(format!("tokens/{TOKEN}?network=ethereum")).await).await,
+        json!({
+            "network": "ethereum",
+            "address": TOKEN.to_checksum(None),
+            "standard": "erc20",
+            "name": "USD Coin",
+            "symbol": "USDC",
+            "decimals": 6,
+            "totalSupply": "2500000",
+            "formattedTotalSupply": "2.5"
+        })
+    );
+
+    let balances = json(get(format!("erc20/balances/{ALICE}?network=ethereum")).await).await;
+    assert_eq!(balances[0]["symbol"], "USDC");
+    assert_eq!(balances[0]["decimals"], 6);
+    assert_eq!(balances[0]["formattedBalance"], "1.5");
+
+    let supply = json(get(format!("erc20/supply/{TOKEN}?network=ethereum")).await).await;
+    assert_eq!(supply["formattedSupply"], "1.5");
+
+    assert_eq!(
+        get("tokens/0x12?network=ethereum".to_string())
+            .await
+            .status()
+            .as_u16(),
+        400
+    );
+}
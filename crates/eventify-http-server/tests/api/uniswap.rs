@@ -0,0 +1,155 @@
+use alloy_primitives::{Address, B256, I256, U256, U64};
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, UniswapV2, UniswapV3},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const V2_PAIR: Address = Address::repeat_byte(0xaa);
+const V3_POOL: Address = Address::repeat_byte(0xbb);
+const ROUTER: Address = Address::repeat_byte(0x11);
+const TRADER: Address = Address::repeat_byte(0x22);
+
+fn emitted<E>(address: Address, block_number: u64, event: E) -> Emitted<E> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address,
+        block_hash: None,
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event,
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn uniswap_events_by_version_and_name() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for block_number in [100, 200] {
+        let swap = emitted(
+            V2_PAIR,
+            block_number,
+            UniswapV2::Swap {
+                sender: ROUTER,
+                amount0In: U256::ZERO,
+                amount1In: U256::from(block_number),
+                amount0Out: U256::from(2_500_000_000u64),
+                amount1Out: U256::ZERO,
+                to: TRADER,
+            },
+        );
+        swap.insert(&app.db_pool, &swap.tx_hash).await.unwrap();
+    }
+    let sync = emitted(
+        V2_PAIR,
+        100,
+        UniswapV2::Sync {
+            reserve0: U256::from(40_321_945_512_671u64).to(),
+            reserve1: U256::from(17_005_381_716_154_712_451_083u128).to(),
+        },
+    );
+    sync.insert(&app.db_pool, &sync.tx_hash).await.unwrap();
+    let swap = emitted(
+        V3_POOL,
+        300,
+        UniswapV3::Swap {
+            sender: ROUTER,
+            recipient: TRADER,
+            amount0: I256::try_from(-2_500_000_000i64).unwrap(),
+            amount1: I256::try_from(1_000_000_000_000_000_000i64).unwrap(),
+            sqrtPriceX96: U256::from(1u128 << 96).to(),
+            liquidity: 24_019_611_537_802_218_540,
+            tick: -200_637,
+        },
+    );
+    swap.insert(&app.db_pool, &swap.tx_hash).await.unwrap();
+
+    let get = |path: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/uniswap/{}", app.address, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let swaps = json(get("v2/swap?network=ethereum".to_string()).await).await;
+    assert_eq!(swaps.as_array().unwrap().len(), 2);
+    assert_eq!(
+        swaps[0],
+        json!({
+            "network": "ethereum",
+            "address": V2_PAIR.to_checksum(None),
+            "blockNumber": 200,
+            "transactionHash": B256::left_padding_from(&200u64.to_be_bytes()).to_string(),
+            "logIndex": 0,
+            "event": "Swap",
+            "params": {
+                "sender": ROUTER.to_checksum(None),
+                "amount0In": "0",
+                "amount1In": "200",
+                "amount0Out": "2500000000",
+                "amount1Out": "0",
+                "to": TRADER.to_checksum(None),
+            },
+        })
+    );
+    let paged = json(get(format!("v2/swap?address={V2_PAIR}&limit=1&offset=1")).await).await;
+    assert_eq!(paged.as_array().unwrap().len(), 1);
+    assert_eq!(paged[0]["blockNumber"], 100);
+
+    let syncs = json(get("v2/Sync".to_string()).await).await;
+    assert_eq!(
+        syncs[0]["params"],
+        json!({"reserve0": "40321945512671", "reserve1": "17005381716154712451083"})
+    );
+
+    let swaps = json(get("v3/swap".to_string()).await).await;
+    assert_eq!(
+        swaps[0]["params"],
+        json!({
+            "sender": ROUTER.to_checksum(None),
+            "recipient": TRADER.to_checksum(None),
+            "amount0": "-2500000000",
+            "amount1": "1000000000000000000",
+            "sqrtPriceX96": "79228162514264337593543950336",
+            "liquidity": "24019611537802218540",
+            "tick": -200637,
+        })
+    );
+    assert_eq!(
+        json(get(format!("v3/swap?address={V2_PAIR}")).await).await,
+        json!([])
+    );
+
+    assert_eq!(get("v2/collect".to_string()).await.status().as_u16(), 404);
+    assert_eq!(get("v3/sync".to_string()).await.status().as_u16(), 404);
+    assert_eq!(
+        get("v3/swap?address=0x12".to_string())
+            .await
+            .status()
+            .as_u16(),
+        400
+    );
+    assert_eq!(
+        get("v2/swap?limit=1001".to_string())
+            .await
+            .status()
+            .as_u16(),
+        400
+    );
+}
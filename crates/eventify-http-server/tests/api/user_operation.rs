@@ -0,0 +1,162 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::{json, Value};
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{entry_point::ENTRY_POINT_V07, Emitted, EntryPoint},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+const PAYMASTER: Address = Address::repeat_byte(0x33);
+const BUNDLER: Address = Address::repeat_byte(0x44);
+const OTHER_BUNDLER: Address = Address::repeat_byte(0x55);
+
+fn tx_hash(block_number: u64) -> B256 {
+    B256::left_padding_from(&block_number.to_be_bytes())
+}
+
+fn user_operation(
+    block_number: u64,
+    sender: Address,
+    paymaster: Address,
+) -> Emitted<EntryPoint::UserOperationEvent> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address: ENTRY_POINT_V07,
+        block_hash: Some(tx_hash(block_number)),
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(tx_hash(block_number)),
+        log_index: Some(U64::from(0)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event: EntryPoint::UserOperationEvent {
+            userOpHash: B256::repeat_byte(block_number as u8),
+            sender,
+            paymaster,
+            nonce: U256::from(1),
+            success: true,
+            actualGasCost: U256::from(21_000_000),
+            actualGasUsed: U256::from(100_000),
+        },
+    }
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn user_operations_by_sender_paymaster_and_bundler() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for operation in [
+        user_operation(100, ALICE, PAYMASTER),
+        user_operation(200, BOB, Address::ZERO),
+    ] {
+        operation
+            .insert(&app.db_pool, &operation.tx_hash)
+            .await
+            .unwrap();
+    }
+    // the first bundle's transaction is collected, only the second one's receipt is
+    sqlx::query(r#"INSERT INTO transaction (network, hash, "from") VALUES ('ethereum', $1, $2)"#)
+        .bind(tx_hash(100).as_slice())
+        .bind(BUNDLER.as_slice())
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    sqlx::query(r#"INSERT INTO receipt (network, tx_hash, "from") VALUES ('ethereum', $1, $2)"#)
+        .bind(tx_hash(200).as_slice())
+        .bind(OTHER_BUNDLER.as_slice())
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let get = |path: String| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/user-operations{}", app.address, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let operations = json(get("?network=ethereum".to_string()).await).await;
+    assert_eq!(
+        operations
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|op| (op["sender"].clone(), op["bundler"].clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                json!(BOB.to_checksum(None)),
+                json!(OTHER_BUNDLER.to_checksum(None))
+            ),
+            (
+                json!(ALICE.to_checksum(None)),
+                json!(BUNDLER.to_checksum(None))
+            ),
+        ]
+    );
+
+    let by_paymaster = json(get(format!("?paymaster={PAYMASTER}")).await).await;
+    assert_eq!(
+        by_paymaster,
+        json!([{
+            "network": "ethereum",
+            "entryPoint": ENTRY_POINT_V07.to_checksum(None),
+            "userOpHash": B256::repeat_byte(100).to_string(),
+            "sender": ALICE.to_checksum(None),
+            "paymaster": PAYMASTER.to_checksum(None),
+            "bundler": BUNDLER.to_checksum(None),
+            "nonce": "1",
+            "success": true,
+            "actualGasCost": "21000000",
+            "actualGasUsed": "100000",
+            "factory": null,
+            "revertReason": null,
+            "postOpRevertReason": null,
+            "blockNumber": 100,
+            "transactionHash": tx_hash(100).to_string(),
+            "logIndex": 0
+        }])
+    );
+
+    let by_bundler = json(get(format!("?bundler={OTHER_BUNDLER}")).await).await;
+    assert_eq!(by_bundler.as_array().unwrap().len(), 1);
+    assert_eq!(by_bundler[0]["sender"], BOB.to_checksum(None));
+
+    let by_sender = json(get(format!("?sender={ALICE}&network=ethereum")).await).await;
+    assert_eq!(
+        by_sender[0]["userOpHash"],
+        B256::repeat_byte(100).to_string()
+    );
+
+    let paged = json(get("?limit=1&offset=1".to_string()).await).await;
+    assert_eq!(paged.as_array().unwrap().len(), 1);
+    assert_eq!(paged[0]["sender"], ALICE.to_checksum(None));
+
+    let operation = json(get(format!("/{}", B256::repeat_byte(200))).await).await;
+    assert_eq!(operation["sender"], BOB.to_checksum(None));
+    assert_eq!(operation["bundler"], OTHER_BUNDLER.to_checksum(None));
+
+    assert_eq!(
+        get(format!("/{}", B256::repeat_byte(1)))
+            .await
+            .status()
+            .as_u16(),
+        404
+    );
+    assert_eq!(get("/0x12".to_string()).await.status().as_u16(), 400);
+    assert_eq!(get("?sender=0x12".to_string()).await.status().as_u16(), 400);
+    assert_eq!(get("?limit=-1".to_string()).await.status().as_u16(), 400);
+}
@@ -0,0 +1,129 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use serde_json::Value;
+
+use crate::helpers::spawn_app;
+use eventify_primitives::{
+    events::{Emitted, ERC20, ERC4626},
+    networks::NetworkKind,
+    InsertT,
+};
+
+const VAULT: Address = Address::repeat_byte(0xaa);
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+
+fn at<E>(block_number: u64, log_index: u64, event: E) -> Emitted<E> {
+    Emitted {
+        network: NetworkKind::Ethereum,
+        address: VAULT,
+        block_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        block_number: Some(U64::from(block_number)),
+        tx_hash: Some(B256::left_padding_from(&block_number.to_be_bytes())),
+        log_index: Some(U64::from(log_index)),
+        removed: false,
+        token: None,
+        formatted_value: None,
+        event,
+    }
+}
+
+fn deposit(
+    block_number: u64,
+    owner: Address,
+    assets: u64,
+    shares: u64,
+) -> Emitted<ERC4626::Deposit> {
+    at(
+        block_number,
+        1,
+        ERC4626::Deposit {
+            sender: owner,
+            owner,
+            assets: U256::from(assets),
+            shares: U256::from(shares),
+        },
+    )
+}
+
+async fn json(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn vault_share_price_and_positions() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    for deposit in [
+        deposit(100, ALICE, 1_000, 1_000),
+        deposit(200, BOB, 1_200, 1_000),
+    ] {
+        deposit
+            .insert(&app.db_pool, &deposit.tx_hash)
+            .await
+            .unwrap();
+    }
+    // the shares minted to alice, half of which she then sends to bob
+    for transfer in [
+        at(
+            100,
+            0,
+            ERC20::Transfer {
+                from: Address::ZERO,
+                to: ALICE,
+                value: U256::from(1_000),
+            },
+        ),
+        at(
+            300,
+            0,
+            ERC20::Transfer {
+                from: ALICE,
+                to: BOB,
+                value: U256::from(500),
+            },
+        ),
+    ] {
+        transfer
+            .insert(&app.db_pool, &transfer.tx_hash)
+            .await
+            .unwrap();
+    }
+
+    let get = |path: &'static str| {
+        let client = client.clone();
+        let url = format!("{}/api/v1/vaults/{}{}", app.address, VAULT, path);
+        async move {
+            client
+                .get(url)
+                .send()
+                .await
+                .expect("Failed to execute request.")
+        }
+    };
+
+    let vault = json(get("?network=ethereum").await).await;
+    assert_eq!(vault["netAssets"], "2200");
+    assert_eq!(vault["netShares"], "2000");
+    assert_eq!(vault["deposits"], 2);
+    assert_eq!(vault["sharePrice"], "1.2");
+    assert_eq!(vault["blockNumber"], 200);
+
+    let series = json(get("/share-price?network=ethereum&fromBlock=100").await).await;
+    assert_eq!(series[0]["sharePrice"], "1");
+    assert_eq!(series[1]["sharePrice"], "1.2");
+
+    let positions = json(get("/positions?network=ethereum").await).await;
+    assert_eq!(positions[0]["owner"], BOB.to_checksum(None));
+    assert_eq!(positions[0]["assetsDeposited"], "1200");
+    assert_eq!(positions[0]["shares"], "500");
+    assert_eq!(positions[1]["sharesMinted"], "1000");
+    assert_eq!(positions[1]["shares"], "500");
+
+    assert_eq!(
+        get("/positions?network=ethereum&owner=0x12")
+            .await
+            .status()
+            .as_u16(),
+        400
+    );
+}
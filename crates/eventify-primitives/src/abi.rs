@@ -0,0 +1,467 @@
+//! Decoding of the events of user supplied JSON ABIs into JSON, on top of [`alloy_json_abi`] and
+//! [`alloy_dyn_abi`].
+
+use alloy_dyn_abi::{DecodedEvent, DynSolEvent, DynSolType, DynSolValue, EventExt, ResolveSolType};
+use alloy_json_abi::JsonAbi;
+pub use alloy_json_abi::{Event, Param};
+use alloy_primitives::{hex, B256, U256};
+use serde_json::{Map, Value};
+
+/// Events with more inputs aren't guessed at, see [`infer`].
+const MAX_INFERRED_INPUTS: usize = 32;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum AbiError {
+    #[error("invalid ABI: {0}")]
+    Json(String),
+    #[error(transparent)]
+    Decode(#[from] alloy_dyn_abi::Error),
+    #[error("log isn't a canonical encoding of {0}")]
+    NonCanonical(String),
+}
+
+/// The events of a JSON ABI, the rest of its entries are ignored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Abi {
+    pub events: Vec<Event>,
+}
+
+impl Abi {
+    /// Parses either the bare array emitted by solc or a build artifact holding it under `abi`
+    /// (hardhat, foundry).
+    pub fn from_json(json: &str) -> Result<Self, AbiError> {
+        let value: Value = serde_json::from_str(json).map_err(|e| AbiError::Json(e.to_string()))?;
+        Self::from_value(&value)
+    }
+
+    /// Like [`Abi::from_json`], every event having to be made of valid solidity types.
+    pub fn from_value(value: &Value) -> Result<Self, AbiError> {
+        let entries = match value {
+            Value::Object(artifact) => artifact.get("abi").and_then(Value::as_array),
+            entries => entries.as_array(),
+        }
+        .ok_or_else(|| AbiError::Json("expected an array of ABI entries".to_string()))?;
+
+        // only the events are read, defaulting `anonymous` which hand written ABIs often leave
+        // out unlike solc
+        let events = entries
+            .iter()
+            .filter(|entry| entry.get("type").and_then(Value::as_str) == Some("event"))
+            .map(|entry| {
+                let mut event = entry.clone();
+                if let Value::Object(event) = &mut event {
+                    event.entry("anonymous").or_insert(Value::Bool(false));
+                }
+                event
+            })
+            .collect::<Vec<_>>();
+
+        // the ABI's deserializer borrows its strings, it can only read them from the text
+        let abi: JsonAbi = serde_json::from_str(&Value::Array(events).to_string())
+            .map_err(|e| AbiError::Json(e.to_string()))?;
+        let events = abi.events().cloned().collect::<Vec<_>>();
+        for event in &events {
+            types(event)?;
+        }
+
+        Ok(Self { events })
+    }
+}
+
+/// Decodes the topics and data of a log into an object keyed by the parameter names,
+/// unnamed parameters being keyed by their position (`arg0`, `arg1`, ..).
+///
+/// Indexed parameters of reference types (strings, bytes, arrays and tuples) are only present
+/// as the hash of their value in the topics, they're returned as that hash. Any non-canonical
+/// encoding (dirty padding, out of range values, invalid utf-8) is rejected rather than guessed
+/// at.
+pub fn decode(event: &Event, topics: &[B256], data: &[u8]) -> Result<Map<String, Value>, AbiError> {
+    let decoded = event.decode_log_parts(topics.iter().copied(), data, true)?;
+    params(event, decoded, topics, data)
+}
+
+/// Works out which inputs are indexed from the number of topics of a log, returning the
+/// event with them marked along with the decoded log.
+///
+/// `None` unless exactly one choice of indexed inputs decodes the topics and data.
+pub fn infer(event: &Event, topics: &[B256], data: &[u8]) -> Option<(Event, Map<String, Value>)> {
+    let indexed = topics.len().checked_sub(1)?;
+    if event.anonymous || indexed > event.inputs.len() || event.inputs.len() > MAX_INFERRED_INPUTS {
+        return None;
+    }
+    let types = types(event).ok()?;
+
+    let mut found = None;
+    for layout in combinations(event.inputs.len(), indexed) {
+        let mut event = event.clone();
+        for (i, input) in event.inputs.iter_mut().enumerate() {
+            input.indexed = layout.contains(&i);
+        }
+
+        let params = resolve(&event, &types)
+            .decode_log_parts(topics.iter().copied(), data, true)
+            .map_err(AbiError::from)
+            .and_then(|decoded| params(&event, decoded, topics, data));
+        if let Ok(params) = params {
+            if found.is_some() {
+                return None;
+            }
+            found = Some((event, params));
+        }
+    }
+
+    found
+}
+
+/// The types of the event's inputs.
+fn types(event: &Event) -> Result<Vec<DynSolType>, AbiError> {
+    Ok(event
+        .inputs
+        .iter()
+        .map(|p| p.resolve())
+        .collect::<Result<_, _>>()?)
+}
+
+/// The event given the already resolved types of its inputs, sparing [`infer`] from parsing
+/// them again for every layout it tries.
+fn resolve(event: &Event, types: &[DynSolType]) -> DynSolEvent {
+    let indexed = event.inputs.iter().map(|p| p.indexed);
+    let (topics, body): (Vec<_>, Vec<_>) = types.iter().cloned().zip(indexed).partition(|t| t.1);
+    DynSolEvent::new_unchecked(
+        (!event.anonymous).then(|| event.selector()),
+        topics.into_iter().map(|t| t.0).collect(),
+        DynSolType::Tuple(body.into_iter().map(|t| t.0).collect()),
+    )
+}
+
+/// The decoded values keyed by the parameter names, once checked to be what the topics and
+/// data encode canonically.
+fn params(
+    event: &Event,
+    decoded: DecodedEvent,
+    topics: &[B256],
+    data: &[u8],
+) -> Result<Map<String, Value>, AbiError> {
+    let words = topics.iter().skip(usize::from(!event.anonymous));
+    let canonical = decoded.indexed.iter().zip(words).all(|(value, topic)| {
+        value.as_word().is_some_and(|word| word == *topic) && in_range(value)
+    }) && decoded.body.iter().all(in_range)
+        && DynSolValue::Tuple(decoded.body.clone())
+            .abi_encode_sequence()
+            .is_some_and(|encoded| encoded == data);
+    if !canonical {
+        return Err(AbiError::NonCanonical(event.signature()));
+    }
+
+    let mut indexed = decoded.indexed.into_iter();
+    let mut body = decoded.body.into_iter();
+    let mut params = Map::new();
+    for (i, input) in event.inputs.iter().enumerate() {
+        let value = if input.indexed {
+            indexed.next()
+        } else {
+            body.next()
+        }
+        .expect("a value per input");
+
+        let key = match input.name.as_str() {
+            "" => format!("arg{}", i),
+            name => name.to_string(),
+        };
+        params.insert(key, json(value, &input.components));
+    }
+
+    Ok(params)
+}
+
+/// Whether the value fits its type, the decoder reading integers and fixed bytes from whole
+/// words.
+fn in_range(value: &DynSolValue) -> bool {
+    match value {
+        DynSolValue::Uint(value, bits) => value.bit_len() <= *bits,
+        DynSolValue::Int(value, bits) => {
+            let high = value.into_raw() >> (bits - 1);
+            high == U256::ZERO || high == U256::MAX >> (bits - 1)
+        }
+        DynSolValue::FixedBytes(word, len) => word[*len..].iter().all(|b| *b == 0),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => values.iter().all(in_range),
+        _ => true,
+    }
+}
+
+/// The value as JSON, numbers as decimal strings and tuples as objects when all of their
+/// components are named, as arrays otherwise.
+fn json(value: DynSolValue, components: &[Param]) -> Value {
+    match value {
+        DynSolValue::Address(address) => Value::String(address.to_checksum(None)),
+        DynSolValue::Bool(value) => Value::Bool(value),
+        DynSolValue::Uint(value, _) => Value::String(value.to_string()),
+        DynSolValue::Int(value, _) => Value::String(value.to_string()),
+        DynSolValue::FixedBytes(word, len) => Value::String(hex::encode_prefixed(&word[..len])),
+        DynSolValue::Function(function) => Value::String(hex::encode_prefixed(function)),
+        DynSolValue::Bytes(bytes) => Value::String(hex::encode_prefixed(bytes)),
+        DynSolValue::String(string) => Value::String(string),
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| json(value, components))
+                .collect(),
+        ),
+        DynSolValue::Tuple(values) if components.iter().all(|p| !p.name.is_empty()) => {
+            Value::Object(
+                components
+                    .iter()
+                    .zip(values)
+                    .map(|(p, value)| (p.name.clone(), json(value, &p.components)))
+                    .collect(),
+            )
+        }
+        DynSolValue::Tuple(values) => Value::Array(
+            components
+                .iter()
+                .zip(values)
+                .map(|(p, value)| json(value, &p.components))
+                .collect(),
+        ),
+    }
+}
+
+/// Every way of picking `k` out of `n` positions, in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    fn pick(start: usize, n: usize, k: usize, picked: &mut Vec<usize>, all: &mut Vec<Vec<usize>>) {
+        if picked.len() == k {
+            all.push(picked.clone());
+            return;
+        }
+        for i in start..n {
+            picked.push(i);
+            pick(i + 1, n, k, picked, all);
+            picked.pop();
+        }
+    }
+
+    let mut all = vec![];
+    pick(0, n, k, &mut Vec::with_capacity(k), &mut all);
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{b256, keccak256};
+    use serde_json::json;
+
+    use super::*;
+
+    fn word(hex: &str) -> Vec<u8> {
+        let bytes = hex::decode(hex).unwrap();
+        let mut word = vec![0u8; 32 - bytes.len()];
+        word.extend(bytes);
+        word
+    }
+
+    #[test]
+    fn parse_abis() {
+        let abi = Abi::from_json(
+            r#"{"abi": [
+                {"type": "function", "name": "swap", "inputs": []},
+                {"type": "event", "name": "Swap", "inputs": [
+                    {"name": "sender", "type": "address", "indexed": true},
+                    {"name": "recipient", "type": "address", "indexed": true},
+                    {"name": "amount0", "type": "int256", "indexed": false},
+                    {"name": "amount1", "type": "int256", "indexed": false},
+                    {"name": "sqrtPriceX96", "type": "uint160", "indexed": false},
+                    {"name": "liquidity", "type": "uint128", "indexed": false},
+                    {"name": "tick", "type": "int24", "indexed": false}
+                ]}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(abi.events.len(), 1);
+        assert_eq!(
+            abi.events[0].signature(),
+            "Swap(address,address,int256,int256,uint160,uint128,int24)"
+        );
+        assert_eq!(
+            abi.events[0].selector(),
+            b256!("c42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67")
+        );
+
+        for json in [
+            r#"{"abis": []}"#,
+            r#"[{"type": "event", "name": "Swap", "inputs": [
+                {"name": "tick", "type": "uint7", "indexed": false}
+            ]}]"#,
+            r#"[{"type": "event", "name": "Swap", "inputs": [
+                {"name": "tick", "type": "foo", "indexed": false}
+            ]}]"#,
+        ] {
+            assert!(Abi::from_json(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn decode_indexed_and_static_params() {
+        let abi = Abi::from_json(
+            r#"[{"type": "event", "name": "Transfer", "inputs": [
+                {"name": "from", "type": "address", "indexed": true},
+                {"name": "to", "type": "address", "indexed": true},
+                {"name": "", "type": "int8", "indexed": false},
+                {"name": "ok", "type": "bool", "indexed": false}
+            ]}]"#,
+        )
+        .unwrap();
+        let event = &abi.events[0];
+        let topics = [
+            event.selector(),
+            B256::left_padding_from(&[0x11; 20]),
+            B256::left_padding_from(&[0x22; 20]),
+        ];
+        let data = [
+            word("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff85"),
+            word("01"),
+        ]
+        .concat();
+
+        assert_eq!(
+            Value::Object(decode(event, &topics, &data).unwrap()),
+            json!({
+                "from": "0x1111111111111111111111111111111111111111",
+                "to": "0x2222222222222222222222222222222222222222",
+                "arg2": "-123",
+                "ok": true,
+            })
+        );
+
+        assert!(matches!(
+            decode(event, &topics[..2], &data),
+            Err(AbiError::Decode(_))
+        ));
+        for dirty in [
+            [word("0185"), word("01")].concat(),
+            [data[..32].to_vec(), word("02")].concat(),
+            [data.clone(), word("01")].concat(),
+        ] {
+            assert_eq!(
+                decode(event, &topics, &dirty),
+                Err(AbiError::NonCanonical(
+                    "Transfer(address,address,int8,bool)".to_string()
+                ))
+            );
+        }
+        let dirty = [topics[0], topics[1], B256::repeat_byte(0x22)];
+        assert!(decode(event, &dirty, &data).is_err());
+    }
+
+    #[test]
+    fn infer_unambiguous_layouts() {
+        let event = Event::parse("Transfer(address,address,uint256)").unwrap();
+        assert!(event.inputs.iter().all(|p| !p.indexed && p.name.is_empty()));
+        let from = B256::left_padding_from(&[0x11; 20]);
+        let to = B256::left_padding_from(&[0x22; 20]);
+
+        // a value no address can hold leaves a single layout
+        let value = word("ffffffffffffffffffffffffffffffffffffffffffffffff");
+        let (inferred, params) = infer(&event, &[event.selector(), from, to], &value).unwrap();
+        assert_eq!(
+            inferred
+                .inputs
+                .iter()
+                .map(|p| p.indexed)
+                .collect::<Vec<_>>(),
+            vec![true, true, false]
+        );
+        assert_eq!(
+            params["arg2"],
+            "6277101735386680763835789423207666416102355444464034512895"
+        );
+
+        // while a small one could be either an address or the amount
+        assert!(infer(&event, &[event.selector(), from, to], &word("0f4240")).is_none());
+
+        // every input indexed
+        let (_, params) = infer(
+            &event,
+            &[event.selector(), from, to, B256::with_last_byte(7)],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(params["arg2"], "7");
+
+        // data that doesn't fit any layout
+        assert!(infer(
+            &event,
+            &[event.selector(), from, to],
+            &[value.clone(), value].concat()
+        )
+        .is_none());
+
+        // unknown types
+        let event = Event::parse("Transfer(address,foo)").unwrap();
+        assert!(infer(&event, &[event.selector(), from], &word("01")).is_none());
+
+        assert_eq!(combinations(4, 2).len(), 6);
+        assert_eq!(combinations(3, 0), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn decode_dynamic_params() {
+        let abi = Abi::from_json(
+            r#"[{"type": "event", "name": "Set", "inputs": [
+                {"name": "key", "type": "string", "indexed": true},
+                {"name": "value", "type": "string", "indexed": false},
+                {"name": "ids", "type": "uint256[]", "indexed": false},
+                {"name": "order", "type": "tuple", "indexed": false, "components": [
+                    {"name": "maker", "type": "address"},
+                    {"name": "data", "type": "bytes"}
+                ]}
+            ]}]"#,
+        )
+        .unwrap();
+        let event = &abi.events[0];
+        assert_eq!(
+            event.signature(),
+            "Set(string,string,uint256[],(address,bytes))"
+        );
+
+        let key = keccak256("key");
+        let data = [
+            // heads
+            word("60"),
+            word("a0"),
+            word("0100"),
+            // "value"
+            word("05"),
+            hex::decode("76616c7565000000000000000000000000000000000000000000000000000000")
+                .unwrap(),
+            // [1, 2]
+            word("02"),
+            word("01"),
+            word("02"),
+            // (maker, 0xbeef)
+            word("3333333333333333333333333333333333333333"),
+            word("40"),
+            word("02"),
+            hex::decode("beef000000000000000000000000000000000000000000000000000000000000")
+                .unwrap(),
+        ]
+        .concat();
+
+        assert_eq!(
+            Value::Object(decode(event, &[event.selector(), key], &data).unwrap()),
+            json!({
+                "key": key.to_string(),
+                "value": "value",
+                "ids": ["1", "2"],
+                "order": {
+                    "maker": "0x3333333333333333333333333333333333333333",
+                    "data": "0xbeef",
+                },
+            })
+        );
+
+        assert!(decode(event, &[event.selector(), key], &data[..data.len() - 32]).is_err());
+    }
+}
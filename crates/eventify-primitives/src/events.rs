@@ -1,12 +1,123 @@
+pub mod access_control;
+pub(crate) mod allowance;
+pub(crate) mod balance;
+pub mod chainlink;
+pub mod decoded;
+pub mod eip1967;
+pub mod entry_point;
 pub mod erc1155;
 pub mod erc20;
 pub mod erc4626;
+pub mod erc4906;
+pub mod erc5192;
+pub mod erc6551;
 pub mod erc721;
 pub mod erc777;
+pub mod ownable;
+pub(crate) mod ownership;
+pub mod permit2;
+pub mod safe;
+pub mod uniswap_v2;
+pub mod uniswap_v3;
+pub(crate) mod vault;
+pub mod weth;
 
 use std::fmt::Debug;
 
-use alloy_sol_types::sol;
+use alloy_primitives::{Address, B256, U64};
+use alloy_sol_types::{sol, SolEvent, TopicList};
+
+use crate::{
+    networks::{core::CoreLog, NetworkKind},
+    token::Token,
+};
+
+/// An event along with the log it was decoded from, for the tables keyed by the log rather than
+/// the transaction it was emitted in.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Emitted<E> {
+    pub network: NetworkKind,
+    /// the emitting contract
+    pub address: Address,
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<B256>,
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<U64>,
+    #[serde(rename = "transactionHash")]
+    pub tx_hash: Option<B256>,
+    #[serde(rename = "logIndex")]
+    pub log_index: Option<U64>,
+    /// whether the log was removed by a reorg
+    #[serde(default)]
+    pub removed: bool,
+    /// the metadata of the emitting token contract, once resolved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<Token>,
+    /// the amount moved/approved in the token's display unit, once its decimals are resolved
+    #[serde(
+        rename = "formattedValue",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub formatted_value: Option<String>,
+    #[serde(flatten)]
+    pub event: E,
+}
+
+impl<E: SolEvent> Emitted<E> {
+    /// Decodes `log` as `E`, which has to match the event's topic count and (strictly validated)
+    /// data layout exactly.
+    pub fn decode(network: NetworkKind, log: &CoreLog) -> alloy_sol_types::Result<Self> {
+        if log.topics.len() != E::TopicList::COUNT {
+            return Err(alloy_sol_types::Error::Other(
+                format!(
+                    "expected {} topics, got {}",
+                    E::TopicList::COUNT,
+                    log.topics.len()
+                )
+                .into(),
+            ));
+        }
+        let event = E::decode_raw_log(log.topics.iter().copied(), &log.data, true)?;
+
+        Ok(Self::from_log(network, log, event))
+    }
+
+    /// Decodes `log` as `E`, declared without indexed parameters, whether or not the emitting
+    /// contract indexed some of its leading (static) parameters, as contracts revising an event
+    /// tend to do.
+    pub fn decode_unindexed(network: NetworkKind, log: &CoreLog) -> alloy_sol_types::Result<Self> {
+        let Some((topic0, indexed)) = log.topics.split_first() else {
+            return Err(alloy_sol_types::Error::Other("expected topic0".into()));
+        };
+        let data = indexed
+            .iter()
+            .flat_map(|t| t.0)
+            .chain(log.data.iter().copied())
+            .collect::<Vec<_>>();
+        let event = E::decode_raw_log([*topic0], &data, true)?;
+
+        Ok(Self::from_log(network, log, event))
+    }
+}
+
+impl<E> Emitted<E> {
+    /// `event`, decoded by other means, along with `log`.
+    pub fn from_log(network: NetworkKind, log: &CoreLog, event: E) -> Self {
+        Self {
+            network,
+            address: log.address,
+            block_hash: log.block_hash,
+            block_number: log.block_number,
+            tx_hash: log.tx_hash,
+            log_index: log.log_index,
+            removed: log.removed,
+            token: None,
+            formatted_value: None,
+            event,
+        }
+    }
+}
 
 sol! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
@@ -52,3 +163,200 @@ sol! {
         event Withdraw(address sender, address receiver, address owner, uint256 assets, uint256 shares);
     }
 }
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface UniswapV2 {
+        event Swap(address indexed sender, uint amount0In, uint amount1In, uint amount0Out, uint amount1Out, address indexed to);
+        event Mint(address indexed sender, uint amount0, uint amount1);
+        event Burn(address indexed sender, uint amount0, uint amount1, address indexed to);
+        event Sync(uint112 reserve0, uint112 reserve1);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface UniswapV3 {
+        event Swap(address indexed sender, address indexed recipient, int256 amount0, int256 amount1, uint160 sqrtPriceX96, uint128 liquidity, int24 tick);
+        event Mint(address sender, address indexed owner, int24 indexed tickLower, int24 indexed tickUpper, uint128 amount, uint256 amount0, uint256 amount1);
+        event Burn(address indexed owner, int24 indexed tickLower, int24 indexed tickUpper, uint128 amount, uint256 amount0, uint256 amount1);
+        event Collect(address indexed owner, address recipient, int24 indexed tickLower, int24 indexed tickUpper, uint128 amount0, uint128 amount1);
+        event Initialize(uint160 sqrtPriceX96, int24 tick);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface WETH {
+        event Deposit(address indexed dst, uint wad);
+        event Withdrawal(address indexed src, uint wad);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface EIP1967 {
+        event Upgraded(address indexed implementation);
+        event AdminChanged(address previousAdmin, address newAdmin);
+        event BeaconUpgraded(address indexed beacon);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface Ownable {
+        event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface AccessControl {
+        event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+        event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+        event RoleAdminChanged(bytes32 indexed role, bytes32 indexed previousAdminRole, bytes32 indexed newAdminRole);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface EntryPoint {
+        event UserOperationEvent(bytes32 indexed userOpHash, address indexed sender, address indexed paymaster, uint256 nonce, bool success, uint256 actualGasCost, uint256 actualGasUsed);
+        event AccountDeployed(bytes32 indexed userOpHash, address indexed sender, address factory, address paymaster);
+        event UserOperationRevertReason(bytes32 indexed userOpHash, address indexed sender, uint256 nonce, bytes revertReason);
+        event PostOpRevertReason(bytes32 indexed userOpHash, address indexed sender, uint256 nonce, bytes revertReason);
+        event BeforeExecution();
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface ERC4906 {
+        event MetadataUpdate(uint256 tokenId);
+        event BatchMetadataUpdate(uint256 fromTokenId, uint256 toTokenId);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface ERC5192 {
+        event Locked(uint256 tokenId);
+        event Unlocked(uint256 tokenId);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface ERC6551Registry {
+        event ERC6551AccountCreated(address account, address indexed implementation, bytes32 salt, uint256 chainId, address indexed tokenContract, uint256 indexed tokenId);
+    }
+}
+
+sol! {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface Permit2 {
+        event Approval(address indexed owner, address indexed token, address indexed spender, uint160 amount, uint48 expiration);
+        event Permit(address indexed owner, address indexed token, address indexed spender, uint160 amount, uint48 expiration, uint48 nonce);
+        event Lockdown(address indexed owner, address token, address spender);
+        event NonceInvalidation(address indexed owner, address indexed token, address indexed spender, uint48 newNonce, uint48 oldNonce);
+    }
+}
+
+sol! {
+    /// Safe (formerly Gnosis Safe) v1.3.0, whose v1.4 indexes the leading parameters of most.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface Safe {
+        event ExecutionSuccess(bytes32 txHash, uint256 payment);
+        event ExecutionFailure(bytes32 txHash, uint256 payment);
+        event AddedOwner(address owner);
+        event RemovedOwner(address owner);
+        event ChangedThreshold(uint256 threshold);
+        event SafeReceived(address indexed sender, uint256 value);
+        event ProxyCreation(address proxy, address singleton);
+    }
+}
+
+sol! {
+    /// A Chainlink aggregator, the contract behind a feed's proxy.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+    interface Chainlink {
+        event AnswerUpdated(int256 indexed current, uint256 indexed roundId, uint256 updatedAt);
+        event NewRound(uint256 indexed roundId, address indexed startedBy, uint256 startedAt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, I256, U256};
+
+    use super::*;
+
+    #[test]
+    fn decode_emitted_event() {
+        let swap = UniswapV3::Swap {
+            sender: Address::repeat_byte(0x11),
+            recipient: Address::repeat_byte(0x22),
+            amount0: I256::try_from(-1_000_000).unwrap(),
+            amount1: I256::try_from(5_000).unwrap(),
+            sqrtPriceX96: U256::from(1u128 << 96).to(),
+            liquidity: 42,
+            tick: -887272,
+        };
+        let log = CoreLog {
+            address: Address::repeat_byte(0xaa),
+            topics: swap.encode_topics().into_iter().map(|t| t.0).collect(),
+            data: Bytes::from(swap.encode_data()),
+            log_index: Some(U64::from(3)),
+            ..Default::default()
+        };
+
+        let emitted = Emitted::<UniswapV3::Swap>::decode(NetworkKind::Ethereum, &log).unwrap();
+        assert_eq!(emitted.address, log.address);
+        assert_eq!(emitted.log_index, Some(U64::from(3)));
+        assert_eq!(emitted.event, swap);
+
+        // the same signature with the recipient left out of the topics
+        let log = CoreLog {
+            topics: log.topics[..2].to_vec(),
+            ..log
+        };
+        assert!(Emitted::<UniswapV3::Swap>::decode(NetworkKind::Ethereum, &log).is_err());
+    }
+
+    #[test]
+    fn decode_unindexed_event() {
+        let added = Safe::AddedOwner {
+            owner: Address::repeat_byte(0x11),
+        };
+        let topic0 = Safe::AddedOwner::SIGNATURE_HASH;
+        let owner = B256::left_padding_from(added.owner.as_slice());
+
+        // v1.3, the owner being data
+        let log = CoreLog {
+            topics: vec![topic0],
+            data: Bytes::from(owner.to_vec()),
+            ..Default::default()
+        };
+        let emitted = Emitted::<Safe::AddedOwner>::decode_unindexed(NetworkKind::Ethereum, &log);
+        assert_eq!(emitted.unwrap().event, added);
+
+        // v1.4, the owner being indexed
+        let log = CoreLog {
+            topics: vec![topic0, owner],
+            data: Bytes::new(),
+            ..Default::default()
+        };
+        let emitted = Emitted::<Safe::AddedOwner>::decode_unindexed(NetworkKind::Ethereum, &log);
+        assert_eq!(emitted.unwrap().event, added);
+
+        // neither
+        let log = CoreLog {
+            topics: vec![topic0, owner],
+            data: Bytes::from(owner.to_vec()),
+            ..Default::default()
+        };
+        assert!(
+            Emitted::<Safe::AddedOwner>::decode_unindexed(NetworkKind::Ethereum, &log).is_err()
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use alloy_primitives::B256;
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{AccessControl, Emitted};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<AccessControl::RoleGranted> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let role = self.event.role.as_slice();
+        let account = self.event.account.as_slice();
+        let sender = self.event.sender.as_slice();
+
+        let sql = r#"INSERT INTO access_control_role_granted (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            role,
+            account,
+            sender
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(role)
+            .bind(account)
+            .bind(sender)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<AccessControl::RoleGranted> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::AccessControl_RoleGranted)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<AccessControl::RoleRevoked> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let role = self.event.role.as_slice();
+        let account = self.event.account.as_slice();
+        let sender = self.event.sender.as_slice();
+
+        let sql = r#"INSERT INTO access_control_role_revoked (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            role,
+            account,
+            sender
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(role)
+            .bind(account)
+            .bind(sender)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<AccessControl::RoleRevoked> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::AccessControl_RoleRevoked)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<AccessControl::RoleAdminChanged> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let role = self.event.role.as_slice();
+        let previous_admin_role = self.event.previousAdminRole.as_slice();
+        let new_admin_role = self.event.newAdminRole.as_slice();
+
+        let sql = r#"INSERT INTO access_control_role_admin_changed (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            role,
+            previous_admin_role,
+            new_admin_role
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(role)
+            .bind(previous_admin_role)
+            .bind(new_admin_role)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<AccessControl::RoleAdminChanged> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::AccessControl_RoleAdminChanged)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
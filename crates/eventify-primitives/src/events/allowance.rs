@@ -0,0 +1,207 @@
+//! The latest allowances, granted through the tokens themselves or Permit2.
+//!
+//! Each change is recorded in `allowance_change` and applied to `allowance`. A change removed
+//! by a reorg is deleted, the allowance being recomputed from the remaining ones.
+
+use alloy_primitives::{Address, U256};
+use sqlx::{Error as SqlError, PgPool};
+
+use super::Emitted;
+
+/// Where an allowance was granted.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Source {
+    /// the token's own `approve`
+    Erc20,
+    /// Uniswap's Permit2, on top of the token's allowance to Permit2 itself
+    Permit2,
+}
+
+impl Source {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Source::Erc20 => "erc20",
+            Source::Permit2 => "permit2",
+        }
+    }
+}
+
+/// A change of the allowance `owner` gave `spender` over `token`, the fields left out being
+/// kept as they are.
+#[derive(Debug)]
+pub(crate) struct Allowance {
+    pub(crate) source: Source,
+    pub(crate) token: Address,
+    pub(crate) owner: Address,
+    pub(crate) spender: Address,
+    pub(crate) amount: Option<U256>,
+    pub(crate) expiration: Option<u64>,
+    pub(crate) nonce: Option<u64>,
+}
+
+impl Allowance {
+    /// Records the change, emitted in `log`, and applies it to the `allowance` table unless a
+    /// later one has been applied already (e.g. while backfilling), or deletes and reverts it
+    /// when the log was removed by a reorg.
+    pub(crate) async fn upsert<E>(&self, pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+        if log.removed {
+            self.revert(pool, log).await
+        } else {
+            self.apply(pool, log).await
+        }
+    }
+
+    async fn apply<E>(&self, pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+        let amount = self.amount.map(|v| v.to_le_bytes::<32>());
+        let block_hash = log.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = log.block_number.map(|v| v.to::<i64>());
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+
+        let sql = r#"WITH change AS (
+                INSERT INTO allowance_change (
+                    network,
+                    token,
+                    "owner",
+                    spender,
+                    source,
+                    amount,
+                    expiration,
+                    nonce,
+                    block_hash,
+                    block_number,
+                    tx_hash,
+                    log_index
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $12, $9, $10, $11
+                ) ON CONFLICT DO NOTHING
+            )
+            INSERT INTO allowance (
+            network,
+            token,
+            "owner",
+            spender,
+            source,
+            amount,
+            expiration,
+            nonce,
+            block_number,
+            tx_hash,
+            log_index
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+            ) ON CONFLICT (network, token, "owner", spender, source) DO UPDATE SET
+                amount = COALESCE(EXCLUDED.amount, allowance.amount),
+                expiration = COALESCE(EXCLUDED.expiration, allowance.expiration),
+                nonce = COALESCE(EXCLUDED.nonce, allowance.nonce),
+                block_number = EXCLUDED.block_number,
+                tx_hash = EXCLUDED.tx_hash,
+                log_index = EXCLUDED.log_index,
+                updated_at = NOW()
+            WHERE (COALESCE(allowance.block_number, -1), COALESCE(allowance.log_index, -1))
+                <= (COALESCE(EXCLUDED.block_number, -1), COALESCE(EXCLUDED.log_index, -1))"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(self.token.as_slice())
+            .bind(self.owner.as_slice())
+            .bind(self.spender.as_slice())
+            .bind(self.source.as_str())
+            .bind(amount.as_ref().map(|v| v.as_slice()))
+            .bind(self.expiration.map(|v| v as i64))
+            .bind(self.nonce.map(|v| v as i64))
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(block_hash)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revert<E>(&self, pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+        let block_hash = log.block_hash.as_ref().map(|v| v.as_slice());
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+        let mut tx = pool.begin().await?;
+
+        let sql = r#"DELETE FROM allowance_change
+            WHERE network = $1
+                AND token = $2
+                AND "owner" = $3
+                AND spender = $4
+                AND source = $5
+                AND tx_hash = $6
+                AND log_index = $7
+                AND block_hash IS NOT DISTINCT FROM $8"#;
+
+        let deleted = sqlx::query(sql)
+            .bind(log.network)
+            .bind(self.token.as_slice())
+            .bind(self.owner.as_slice())
+            .bind(self.spender.as_slice())
+            .bind(self.source.as_str())
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(block_hash)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        if deleted == 0 {
+            return Ok(());
+        }
+
+        let sql = r#"DELETE FROM allowance
+            WHERE network = $1 AND token = $2 AND "owner" = $3 AND spender = $4 AND source = $5"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(self.token.as_slice())
+            .bind(self.owner.as_slice())
+            .bind(self.spender.as_slice())
+            .bind(self.source.as_str())
+            .execute(&mut *tx)
+            .await?;
+
+        // each field as of the latest change setting it, the allowance being forgotten if no
+        // change is left
+        let sql = r#"INSERT INTO allowance (
+                network,
+                token,
+                "owner",
+                spender,
+                source,
+                amount,
+                expiration,
+                nonce,
+                block_number,
+                tx_hash,
+                log_index
+            )
+            SELECT $1, $2, $3, $4, $5,
+                (ARRAY_AGG(amount ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST)
+                    FILTER (WHERE amount IS NOT NULL))[1],
+                (ARRAY_AGG(expiration ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST)
+                    FILTER (WHERE expiration IS NOT NULL))[1],
+                (ARRAY_AGG(nonce ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST)
+                    FILTER (WHERE nonce IS NOT NULL))[1],
+                (ARRAY_AGG(block_number ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST))[1],
+                (ARRAY_AGG(tx_hash ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST))[1],
+                (ARRAY_AGG(log_index ORDER BY block_number DESC NULLS LAST, log_index DESC NULLS LAST))[1]
+            FROM allowance_change
+            WHERE network = $1 AND token = $2 AND "owner" = $3 AND spender = $4 AND source = $5
+            HAVING COUNT(*) > 0"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(self.token.as_slice())
+            .bind(self.owner.as_slice())
+            .bind(self.spender.as_slice())
+            .bind(self.source.as_str())
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await
+    }
+}
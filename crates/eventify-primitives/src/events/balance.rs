@@ -0,0 +1,154 @@
+//! The ledger of ERC20 balances, fed by the transfers of every token.
+//!
+//! Each transfer records the change of both sides' balances in `erc20_balance_change`, from which
+//! the balances as of any block are summed, and applies it to the latest balances of
+//! `erc20_balance`. Mints come from and burns go to the zero address, whose balance is thus the
+//! opposite of the token's supply (see the `erc20_supply` view); WETH's `Deposit`s and
+//! `Withdrawal`s, which come without a `Transfer`, are recorded as such. A transfer removed by a
+//! reorg has its changes deleted and reverted.
+//!
+//! The logs aren't flagged `removed` on every path (e.g. when polled for through `eth_getLogs`
+//! or backfilled), so the changes are also keyed by the hash of their block: once a block is
+//! seen under another hash than the one its changes were recorded with, the changes recorded
+//! from it on that aren't from the new block are deleted and reverted as well. Logs without a
+//! block hash (i.e. pending) aren't recorded.
+
+use alloy_primitives::{Address, U256};
+use sqlx::{Error as SqlError, PgPool};
+
+use super::Emitted;
+
+/// Records and applies the balance changes of `value` of the token emitting `log` moving from
+/// `from` to `to`.
+pub(crate) async fn apply<E>(
+    pool: &PgPool,
+    log: &Emitted<E>,
+    from: Address,
+    to: Address,
+    value: U256,
+) -> Result<(), SqlError> {
+    // a transfer to oneself changes nothing
+    if from == to {
+        return Ok(());
+    }
+    let Some(block_hash) = log.block_hash.as_ref().map(|v| v.as_slice()) else {
+        return Ok(());
+    };
+    let block_number = log.block_number.map(|v| v.to::<i64>());
+    let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+    let log_index = log.log_index.map(|v| v.to::<i64>());
+
+    if let Some(block_number) = block_number {
+        reorg(pool, log, block_number, block_hash).await?;
+    }
+
+    let sql = r#"WITH change AS (
+            INSERT INTO erc20_balance_change (
+                network,
+                token,
+                holder,
+                block_hash,
+                block_number,
+                tx_hash,
+                log_index,
+                delta
+            ) VALUES
+                ($1, $2, $3, $4, $5, $6, $7, -($9::numeric)),
+                ($1, $2, $8, $4, $5, $6, $7, $9::numeric)
+            ON CONFLICT DO NOTHING
+            RETURNING network, token, holder, delta
+        )
+        INSERT INTO erc20_balance (network, token, holder, balance)
+        SELECT network, token, holder, delta FROM change
+        ON CONFLICT (network, token, holder) DO UPDATE SET
+            balance = erc20_balance.balance + EXCLUDED.balance,
+            updated_at = NOW()"#;
+
+    sqlx::query(sql)
+        .bind(log.network)
+        .bind(log.address.as_slice())
+        .bind(from.as_slice())
+        .bind(block_hash)
+        .bind(block_number)
+        .bind(tx_hash)
+        .bind(log_index)
+        .bind(to.as_slice())
+        .bind(value.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Deletes and reverts the balance changes recorded from the block `number` on if the block was
+/// replaced, i.e. if changes were recorded from it under another hash than `hash`.
+async fn reorg<E>(
+    pool: &PgPool,
+    log: &Emitted<E>,
+    number: i64,
+    hash: &[u8],
+) -> Result<(), SqlError> {
+    let sql = r#"WITH change AS (
+            DELETE FROM erc20_balance_change
+            WHERE network = $1
+                AND block_number >= $2
+                AND block_hash <> $3
+                AND EXISTS (
+                    SELECT 1 FROM erc20_balance_change
+                    WHERE network = $1 AND block_number = $2 AND block_hash <> $3
+                )
+            RETURNING network, token, holder, delta
+        ), total AS (
+            SELECT network, token, holder, SUM(delta) AS delta FROM change
+            GROUP BY network, token, holder
+        )
+        UPDATE erc20_balance b SET
+            balance = b.balance - total.delta,
+            updated_at = NOW()
+        FROM total
+        WHERE b.network = total.network AND b.token = total.token AND b.holder = total.holder"#;
+
+    sqlx::query(sql)
+        .bind(log.network)
+        .bind(number)
+        .bind(hash)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Deletes and reverts the balance changes recorded for `log`, removed by a reorg.
+pub(crate) async fn revert<E>(pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+    let Some(block_hash) = log.block_hash.as_ref().map(|v| v.as_slice()) else {
+        return Ok(());
+    };
+    let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+    let log_index = log.log_index.map(|v| v.to::<i64>());
+
+    let sql = r#"WITH change AS (
+            DELETE FROM erc20_balance_change
+            WHERE network = $1
+                AND token = $2
+                AND tx_hash = $3
+                AND log_index = $4
+                AND block_hash = $5
+            RETURNING network, token, holder, delta
+        )
+        UPDATE erc20_balance b SET
+            balance = b.balance - change.delta,
+            updated_at = NOW()
+        FROM change
+        WHERE b.network = change.network AND b.token = change.token AND b.holder = change.holder"#;
+
+    sqlx::query(sql)
+        .bind(log.network)
+        .bind(log.address.as_slice())
+        .bind(tx_hash)
+        .bind(log_index)
+        .bind(block_hash)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use alloy_primitives::B256;
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Chainlink, Emitted};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+/// The `AnswerUpdated` of an aggregator of a configured feed.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PriceUpdate {
+    pub feed: String,
+    /// Number of decimals of the answer
+    pub decimals: u8,
+    #[serde(flatten)]
+    pub update: Emitted<Chainlink::AnswerUpdated>,
+}
+
+impl Insert for PriceUpdate {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let update = &self.update;
+        let address = update.address.as_slice();
+        let block_hash = update.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = update.block_number.map(|v| v.to::<i64>());
+        let tx_hash = update.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = update.log_index.map(|v| v.to::<i64>());
+        let round_id = update.event.roundId.as_le_slice();
+        let answer = update.event.current.into_raw().to_le_bytes::<32>();
+        let answered_at = update.event.updatedAt.saturating_to::<i64>();
+
+        let sql = r#"INSERT INTO chainlink_price (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            feed,
+            decimals,
+            round_id,
+            answer,
+            answered_at
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(update.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(&self.feed)
+            .bind(self.decimals as i16)
+            .bind(round_id)
+            .bind(answer.as_slice())
+            .bind(answered_at)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for PriceUpdate {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::Chainlink_AnswerUpdated)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<Chainlink::NewRound> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let round_id = self.event.roundId.as_le_slice();
+        let started_by = self.event.startedBy.as_slice();
+        let started_at = self.event.startedAt.as_le_slice();
+
+        let sql = r#"INSERT INTO chainlink_new_round (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            round_id,
+            started_by,
+            started_at
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(round_id)
+            .bind(started_by)
+            .bind(started_at)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<Chainlink::NewRound> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::Chainlink_NewRound)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
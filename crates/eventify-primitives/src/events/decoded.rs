@@ -0,0 +1,159 @@
+use alloy_primitives::{Address, B256, U64};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use crate::{
+    abi::{self, AbiError, Event},
+    networks::{core::CoreLog, LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+/// A log decoded against a user supplied ABI.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DecodedEvent {
+    pub network: NetworkKind,
+    pub address: Address,
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<B256>,
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<U64>,
+    #[serde(rename = "transactionHash")]
+    pub tx_hash: Option<B256>,
+    #[serde(rename = "logIndex")]
+    pub log_index: Option<U64>,
+    pub name: String,
+    pub signature: String,
+    pub topic0: B256,
+    /// the parameters keyed by their name
+    pub params: serde_json::Value,
+}
+
+impl DecodedEvent {
+    pub fn new(network: NetworkKind, log: &CoreLog, event: &Event) -> Result<Self, AbiError> {
+        let params = abi::decode(event, &log.topics, &log.data)?;
+
+        Ok(Self {
+            network,
+            address: log.address,
+            block_hash: log.block_hash,
+            block_number: log.block_number,
+            tx_hash: log.tx_hash,
+            log_index: log.log_index,
+            name: event.name.clone(),
+            signature: event.signature(),
+            topic0: log.topics.first().copied().unwrap_or_default(),
+            params: serde_json::Value::Object(params),
+        })
+    }
+}
+
+impl Insert for DecodedEvent {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let topic0 = self.topic0.as_slice();
+        let params = self.params.to_string();
+
+        let sql = r#"INSERT INTO decoded_event (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            name,
+            signature,
+            topic0,
+            params
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10::jsonb
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(&self.name)
+            .bind(&self.signature)
+            .bind(topic0)
+            .bind(params)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for DecodedEvent {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::Decoded));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U256};
+    use serde_json::json;
+
+    use super::*;
+    use crate::abi::Abi;
+
+    #[test]
+    fn decode_core_log() {
+        let abi = Abi::from_json(
+            r#"[{"type": "event", "name": "Transfer", "inputs": [
+                {"name": "from", "type": "address", "indexed": true},
+                {"name": "to", "type": "address", "indexed": true},
+                {"name": "value", "type": "uint256", "indexed": false}
+            ]}]"#,
+        )
+        .unwrap();
+        let event = &abi.events[0];
+        let log = CoreLog {
+            address: Address::repeat_byte(0xaa),
+            block_number: Some(U64::from(19_000_000)),
+            log_index: Some(U64::from(7)),
+            topics: vec![
+                event.selector(),
+                B256::left_padding_from(&[0x11; 20]),
+                B256::left_padding_from(&[0x22; 20]),
+            ],
+            data: Bytes::from(U256::from(1_000_000).to_be_bytes::<32>().to_vec()),
+            ..Default::default()
+        };
+
+        let decoded = DecodedEvent::new(NetworkKind::Ethereum, &log, event).unwrap();
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.signature, "Transfer(address,address,uint256)");
+        assert_eq!(decoded.topic0, event.selector());
+        assert_eq!(decoded.log_index, Some(U64::from(7)));
+        assert_eq!(
+            decoded.params,
+            json!({
+                "from": "0x1111111111111111111111111111111111111111",
+                "to": "0x2222222222222222222222222222222222222222",
+                "value": "1000000",
+            })
+        );
+
+        let log = CoreLog {
+            topics: log.topics[..2].to_vec(),
+            ..log
+        };
+        assert!(DecodedEvent::new(NetworkKind::Ethereum, &log, event).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use alloy_primitives::B256;
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Emitted, EIP1967};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<EIP1967::Upgraded> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let implementation = self.event.implementation.as_slice();
+
+        let sql = r#"INSERT INTO eip1967_upgraded (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            implementation
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(implementation)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<EIP1967::Upgraded> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::EIP1967_Upgraded)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<EIP1967::AdminChanged> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let previous_admin = self.event.previousAdmin.as_slice();
+        let new_admin = self.event.newAdmin.as_slice();
+
+        let sql = r#"INSERT INTO eip1967_admin_changed (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            previous_admin,
+            new_admin
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(previous_admin)
+            .bind(new_admin)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<EIP1967::AdminChanged> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::EIP1967_AdminChanged)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<EIP1967::BeaconUpgraded> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let beacon = self.event.beacon.as_slice();
+
+        let sql = r#"INSERT INTO eip1967_beacon_upgraded (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            beacon
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(beacon)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<EIP1967::BeaconUpgraded> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::EIP1967_BeaconUpgraded)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+use alloy_primitives::{address, Address, B256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Emitted, EntryPoint};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+/// The canonical v0.6 ERC-4337 EntryPoint, deployed at the same address on every network.
+pub const ENTRY_POINT_V06: Address = address!("5FF137D4b0FDCD49DcA30c7CF57E578a026d2789");
+/// The canonical v0.7 ERC-4337 EntryPoint.
+pub const ENTRY_POINT_V07: Address = address!("0000000071727De22E5E9d8BAf0edAc6f37da032");
+/// The EntryPoints whose events are decoded.
+pub const ENTRY_POINTS: [Address; 2] = [ENTRY_POINT_V06, ENTRY_POINT_V07];
+
+// Every event of a user operation is folded into its `user_operation` row, keyed by the
+// userOpHash; `AccountDeployed` and the revert reasons are emitted before the
+// `UserOperationEvent` concluding it, so any of them may create the row.
+
+impl Insert for Emitted<EntryPoint::UserOperationEvent> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let entry_point = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let user_op_hash = self.event.userOpHash.as_slice();
+        let sender = self.event.sender.as_slice();
+        let paymaster = self.event.paymaster.as_slice();
+        let nonce = self.event.nonce.as_le_slice();
+        let actual_gas_cost = self.event.actualGasCost.as_le_slice();
+        let actual_gas_used = self.event.actualGasUsed.as_le_slice();
+
+        let sql = r#"INSERT INTO user_operation (
+            network,
+            entry_point,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            user_op_hash,
+            sender,
+            paymaster,
+            nonce,
+            success,
+            actual_gas_cost,
+            actual_gas_used
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+            ) ON CONFLICT (network, user_op_hash) DO UPDATE SET
+                entry_point = EXCLUDED.entry_point,
+                block_hash = EXCLUDED.block_hash,
+                block_number = EXCLUDED.block_number,
+                tx_hash = EXCLUDED.tx_hash,
+                log_index = EXCLUDED.log_index,
+                sender = EXCLUDED.sender,
+                paymaster = EXCLUDED.paymaster,
+                nonce = EXCLUDED.nonce,
+                success = EXCLUDED.success,
+                actual_gas_cost = EXCLUDED.actual_gas_cost,
+                actual_gas_used = EXCLUDED.actual_gas_used,
+                updated_at = NOW()"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(entry_point)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(user_op_hash)
+            .bind(sender)
+            .bind(paymaster)
+            .bind(nonce)
+            .bind(self.event.success)
+            .bind(actual_gas_cost)
+            .bind(actual_gas_used)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<EntryPoint::AccountDeployed> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let entry_point = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let user_op_hash = self.event.userOpHash.as_slice();
+        let sender = self.event.sender.as_slice();
+        let factory = self.event.factory.as_slice();
+        let paymaster = self.event.paymaster.as_slice();
+
+        let sql = r#"INSERT INTO user_operation (
+            network,
+            entry_point,
+            block_hash,
+            block_number,
+            tx_hash,
+            user_op_hash,
+            sender,
+            factory,
+            paymaster
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT (network, user_op_hash) DO UPDATE SET
+                factory = EXCLUDED.factory,
+                updated_at = NOW()"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(entry_point)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(user_op_hash)
+            .bind(sender)
+            .bind(factory)
+            .bind(paymaster)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<EntryPoint::UserOperationRevertReason> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        insert_revert_reason(
+            pool,
+            self,
+            "revert_reason",
+            self.event.userOpHash,
+            self.event.sender,
+            &self.event.revertReason,
+        )
+        .await
+    }
+}
+
+impl Insert for Emitted<EntryPoint::PostOpRevertReason> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        insert_revert_reason(
+            pool,
+            self,
+            "post_op_revert_reason",
+            self.event.userOpHash,
+            self.event.sender,
+            &self.event.revertReason,
+        )
+        .await
+    }
+}
+
+impl Insert for Emitted<EntryPoint::BeforeExecution> {
+    /// Marks the start of a bundle's execution, nothing to store.
+    async fn insert(&self, _: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Ok(())
+    }
+}
+
+async fn insert_revert_reason<E>(
+    pool: &PgPool,
+    emitted: &Emitted<E>,
+    column: &str,
+    user_op_hash: B256,
+    sender: Address,
+    reason: &[u8],
+) -> Result<(), SqlError> {
+    let entry_point = emitted.address.as_slice();
+    let block_hash = emitted.block_hash.as_ref().map(|v| v.as_slice());
+    let block_number = emitted.block_number.map(|v| v.to::<i64>());
+    let tx_hash = emitted.tx_hash.as_ref().map(|v| v.as_slice());
+
+    let sql = format!(
+        r#"INSERT INTO user_operation (
+            network,
+            entry_point,
+            block_hash,
+            block_number,
+            tx_hash,
+            user_op_hash,
+            sender,
+            {column}
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT (network, user_op_hash) DO UPDATE SET
+                {column} = EXCLUDED.{column},
+                updated_at = NOW()"#
+    );
+
+    sqlx::query(&sql)
+        .bind(emitted.network)
+        .bind(entry_point)
+        .bind(block_hash)
+        .bind(block_number)
+        .bind(tx_hash)
+        .bind(user_op_hash.as_slice())
+        .bind(sender.as_slice())
+        .bind(reason)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+impl Emit for Emitted<EntryPoint::UserOperationEvent> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::EntryPoint_UserOperationEvent, self).await
+    }
+}
+
+impl Emit for Emitted<EntryPoint::AccountDeployed> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::EntryPoint_AccountDeployed, self).await
+    }
+}
+
+impl Emit for Emitted<EntryPoint::UserOperationRevertReason> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(
+            queue,
+            network,
+            LogKind::EntryPoint_UserOperationRevertReason,
+            self,
+        )
+        .await
+    }
+}
+
+impl Emit for Emitted<EntryPoint::PostOpRevertReason> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::EntryPoint_PostOpRevertReason, self).await
+    }
+}
+
+impl Emit for Emitted<EntryPoint::BeforeExecution> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::EntryPoint_BeforeExecution, self).await
+    }
+}
+
+async fn emit<E: serde::Serialize>(
+    queue: &redis::Client,
+    network: &NetworkKind,
+    kind: LogKind,
+    emitted: &Emitted<E>,
+) -> Result<(), EmitError> {
+    let mut con = queue.get_async_connection().await?;
+
+    let channel = format!("{}:{}", network, ResourceKind::Log(kind));
+    con.lpush::<_, _, ()>(channel, serde_json::to_string(emitted)?)
+        .await?;
+
+    Ok(())
+}
@@ -3,9 +3,12 @@ use eyre::Result;
 use redis::AsyncCommands;
 use sqlx::{Error as SqlError, PgPool};
 
-use super::ERC1155;
+use super::{
+    ownership::{Standard, Transfer},
+    Emitted, ERC1155,
+};
 use crate::{
-    networks::{LogKind, ResourceKind},
+    networks::{LogKind, NetworkKind, ResourceKind},
     traits::{Emit, Insert},
     EmitError,
 };
@@ -57,7 +60,8 @@ impl Emit for ERC1155::TransferSingle {
             network,
             ResourceKind::Log(LogKind::ERC1155_TransferSingle)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -114,7 +118,84 @@ impl Emit for ERC1155::TransferBatch {
             network,
             ResourceKind::Log(LogKind::ERC1155_TransferBatch)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC1155::TransferSingle> {
+    /// Stores the transfer and moves the quantity between the owners of the token.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        Transfer {
+            standard: Standard::Erc1155,
+            operator: Some(self.event.operator),
+            from: self.event.from,
+            to: self.event.to,
+            id: self.event.id,
+            quantity: self.event.value,
+            batch_index: 0,
+        }
+        .record(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<ERC1155::TransferSingle> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC1155_TransferSingle)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC1155::TransferBatch> {
+    /// Stores the batch and moves each of its quantities between the owners of their token.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        for (batch_index, (id, quantity)) in
+            self.event.ids.iter().zip(&self.event.values).enumerate()
+        {
+            Transfer {
+                standard: Standard::Erc1155,
+                operator: Some(self.event.operator),
+                from: self.event.from,
+                to: self.event.to,
+                id: *id,
+                quantity: *quantity,
+                batch_index: batch_index as i32,
+            }
+            .record(pool, self)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<ERC1155::TransferBatch> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC1155_TransferBatch)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -154,7 +235,59 @@ impl Emit for ERC1155::URI {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC1155_URI));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC1155::URI> {
+    /// Stores the URI and queues the metadata of the token to be fetched from it, anew if the
+    /// token pointed elsewhere before.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+        if self.removed {
+            return Ok(());
+        }
+
+        let sql = r#"INSERT INTO nft_metadata (
+            network,
+            contract,
+            token_id,
+            standard,
+            uri
+            ) VALUES (
+                $1, $2, $3::numeric, $4, $5
+            ) ON CONFLICT (network, contract, token_id) DO UPDATE SET
+                uri = EXCLUDED.uri,
+                status = 'pending',
+                attempts = 0,
+                error = NULL,
+                next_attempt_at = NOW(),
+                updated_at = NOW()
+            WHERE nft_metadata.uri IS DISTINCT FROM EXCLUDED.uri"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(self.address.as_slice())
+            .bind(self.event.id.to_string())
+            .bind(Standard::Erc1155.as_str())
+            .bind(self.event.value.as_str())
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<ERC1155::URI> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC1155_URI));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -3,9 +3,12 @@ use eyre::Result;
 use redis::AsyncCommands;
 use sqlx::{Error as SqlError, PgPool};
 
-use super::ERC20;
+use super::{
+    allowance::{Allowance, Source},
+    balance, Emitted, ERC20,
+};
 use crate::{
-    networks::{LogKind, ResourceKind},
+    networks::{LogKind, NetworkKind, ResourceKind},
     traits::{Emit, Insert},
     EmitError,
 };
@@ -47,7 +50,35 @@ impl Emit for ERC20::Transfer {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC20_Transfer));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC20::Transfer> {
+    /// Stores the transfer and applies it to the balances of the token, or reverts it when
+    /// removed by a reorg.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        if self.removed {
+            balance::revert(pool, self).await
+        } else {
+            let ERC20::Transfer { from, to, value } = self.event;
+            balance::apply(pool, self, from, to, value).await
+        }
+    }
+}
+
+impl Emit for Emitted<ERC20::Transfer> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC20_Transfer));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -85,6 +116,37 @@ impl Insert for ERC20::Approval {
     }
 }
 
+impl Insert for Emitted<ERC20::Approval> {
+    /// Stores the approval and sets the current ERC20 allowance of the token.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        Allowance {
+            source: Source::Erc20,
+            token: self.address,
+            owner: self.event.owner,
+            spender: self.event.spender,
+            amount: Some(self.event.value),
+            expiration: None,
+            nonce: None,
+        }
+        .upsert(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<ERC20::Approval> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC20_Approval));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
 impl Emit for ERC20::Approval {
     async fn emit(
         &self,
@@ -94,7 +156,8 @@ impl Emit for ERC20::Approval {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC20_Approval));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -3,9 +3,12 @@ use eyre::Result;
 use redis::AsyncCommands;
 use sqlx::{Error as SqlError, PgPool};
 
-use super::ERC4626;
+use super::{
+    vault::{Direction, Flow},
+    Emitted, ERC4626,
+};
 use crate::{
-    networks::{LogKind, ResourceKind},
+    networks::{LogKind, NetworkKind, ResourceKind},
     traits::{Emit, Insert},
     EmitError,
 };
@@ -54,7 +57,8 @@ impl Emit for ERC4626::Deposit {
             network,
             ResourceKind::Log(LogKind::ERC4626_Deposit)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -107,7 +111,76 @@ impl Emit for ERC4626::Withdraw {
             network,
             ResourceKind::Log(LogKind::ERC4626_Withdraw)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC4626::Deposit> {
+    /// Stores the deposit and records it among the vault's flows.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        Flow {
+            direction: Direction::Deposit,
+            sender: self.event.sender,
+            receiver: None,
+            owner: self.event.owner,
+            assets: self.event.assets,
+            shares: self.event.shares,
+        }
+        .record(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<ERC4626::Deposit> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC4626_Deposit)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC4626::Withdraw> {
+    /// Stores the withdrawal and records it among the vault's flows.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        Flow {
+            direction: Direction::Withdraw,
+            sender: self.event.sender,
+            receiver: Some(self.event.receiver),
+            owner: self.event.owner,
+            assets: self.event.assets,
+            shares: self.event.shares,
+        }
+        .record(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<ERC4626::Withdraw> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC4626_Withdraw)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -0,0 +1,118 @@
+use alloy_primitives::B256;
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Emitted, ERC4906};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<ERC4906::MetadataUpdate> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let token_id = self.event.tokenId.as_le_slice();
+
+        let sql = r#"INSERT INTO erc4906_metadata_update (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            token_id
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(token_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<ERC4906::MetadataUpdate> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC4906_MetadataUpdate)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC4906::BatchMetadataUpdate> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let from_token_id = self.event.fromTokenId.as_le_slice();
+        let to_token_id = self.event.toTokenId.as_le_slice();
+
+        let sql = r#"INSERT INTO erc4906_batch_metadata_update (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            from_token_id,
+            to_token_id
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(from_token_id)
+            .bind(to_token_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<ERC4906::BatchMetadataUpdate> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC4906_BatchMetadataUpdate)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use alloy_primitives::B256;
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{ERC6551Registry, Emitted};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<ERC6551Registry::ERC6551AccountCreated> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let account = self.event.account.as_slice();
+        let implementation = self.event.implementation.as_slice();
+        let salt = self.event.salt.as_slice();
+        let chain_id = self.event.chainId.as_le_slice();
+        let token_contract = self.event.tokenContract.as_slice();
+        let token_id = self.event.tokenId.as_le_slice();
+
+        let sql = r#"INSERT INTO erc6551_account_created (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            account,
+            implementation,
+            salt,
+            chain_id,
+            token_contract,
+            token_id
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(account)
+            .bind(implementation)
+            .bind(salt)
+            .bind(chain_id)
+            .bind(token_contract)
+            .bind(token_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<ERC6551Registry::ERC6551AccountCreated> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC6551Registry_ERC6551AccountCreated)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
-use alloy_primitives::B256;
+use alloy_primitives::{B256, U256};
 use eyre::Result;
 use redis::AsyncCommands;
 use sqlx::{Error as SqlError, PgPool};
 
-use super::ERC721;
+use super::{
+    ownership::{Standard, Transfer},
+    Emitted, ERC721,
+};
 use crate::{
-    networks::{LogKind, ResourceKind},
+    networks::{LogKind, NetworkKind, ResourceKind},
     traits::{Emit, Insert},
     EmitError,
 };
@@ -51,7 +54,43 @@ impl Emit for ERC721::Transfer {
             network,
             ResourceKind::Log(LogKind::ERC721_Transfer)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<ERC721::Transfer> {
+    /// Stores the transfer and hands the token over to its new owner.
+    async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> Result<(), SqlError> {
+        self.event.insert(pool, tx_hash).await?;
+
+        Transfer {
+            standard: Standard::Erc721,
+            operator: None,
+            from: self.event.from,
+            to: self.event.to,
+            id: self.event.tokenId,
+            quantity: U256::from(1),
+            batch_index: 0,
+        }
+        .record(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<ERC721::Transfer> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::ERC721_Transfer)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -98,7 +137,8 @@ impl Emit for ERC721::Approval {
             network,
             ResourceKind::Log(LogKind::ERC721_Approval)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -145,7 +185,8 @@ impl Emit for ERC721::ApprovalForAll {
             network,
             ResourceKind::Log(LogKind::ERC721_ApprovalForAll)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
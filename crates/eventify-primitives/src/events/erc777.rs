@@ -56,7 +56,8 @@ impl Emit for ERC777::Sent {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC777_Sent));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -105,7 +106,8 @@ impl Emit for ERC777::Minted {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC777_Minted));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -154,7 +156,8 @@ impl Emit for ERC777::Burned {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::ERC777_Burned));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -198,7 +201,8 @@ impl Emit for ERC777::AuthorizedOperator {
             network,
             ResourceKind::Log(LogKind::ERC777_AuthorizedOperator)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -242,7 +246,8 @@ impl Emit for ERC777::RevokedOperator {
             network,
             ResourceKind::Log(LogKind::ERC777_RevokedOperator)
         );
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
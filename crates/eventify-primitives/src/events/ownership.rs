@@ -0,0 +1,222 @@
+//! The ownership of ERC721 and ERC1155 tokens, fed by their transfers.
+//!
+//! Each transfer of a token is recorded in `nft_transfer`, its history, and applied to the
+//! quantities held by its owners in `nft_owner`. Mints come from and burns go to the zero
+//! address, which owns nothing. A transfer removed by a reorg is deleted and reverted. The token
+//! is queued in `nft_metadata` the first time it's transferred.
+//!
+//! As with the ERC20 balances (see `balance`), the logs aren't flagged `removed` on every path,
+//! so the transfers recorded from a block since seen under another hash are deleted and reverted
+//! too, and logs without a block hash (i.e. pending) aren't recorded.
+
+use alloy_primitives::{Address, U256};
+use sqlx::{Error as SqlError, PgPool};
+
+use super::Emitted;
+
+/// The standard a token is transferred under.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Standard {
+    Erc721,
+    Erc1155,
+}
+
+impl Standard {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Standard::Erc721 => "erc721",
+            Standard::Erc1155 => "erc1155",
+        }
+    }
+}
+
+/// The transfer of `quantity` of the token `id`, the `batch_index`th of its log.
+#[derive(Debug)]
+pub(crate) struct Transfer {
+    pub(crate) standard: Standard,
+    pub(crate) operator: Option<Address>,
+    pub(crate) from: Address,
+    pub(crate) to: Address,
+    pub(crate) id: U256,
+    pub(crate) quantity: U256,
+    pub(crate) batch_index: i32,
+}
+
+impl Transfer {
+    /// Records the transfer, emitted in `log`, and applies it to the owners of the token, or
+    /// deletes and reverts it when the log was removed by a reorg.
+    pub(crate) async fn record<E>(&self, pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+        let Some(block_hash) = log.block_hash.as_ref().map(|v| v.as_slice()) else {
+            return Ok(());
+        };
+
+        if log.removed {
+            self.revert(pool, log, block_hash).await
+        } else {
+            if let Some(block_number) = log.block_number.map(|v| v.to::<i64>()) {
+                reorg(pool, log, block_number, block_hash).await?;
+            }
+            self.apply(pool, log, block_hash).await
+        }
+    }
+
+    async fn apply<E>(
+        &self,
+        pool: &PgPool,
+        log: &Emitted<E>,
+        block_hash: &[u8],
+    ) -> Result<(), SqlError> {
+        let block_number = log.block_number.map(|v| v.to::<i64>());
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+
+        let sql = r#"WITH transfer AS (
+                INSERT INTO nft_transfer (
+                    network,
+                    contract,
+                    token_id,
+                    standard,
+                    operator,
+                    "from",
+                    "to",
+                    quantity,
+                    block_hash,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                    batch_index
+                ) VALUES (
+                    $1, $2, $3::numeric, $4, $5, $6, $7, $8::numeric, $9, $10, $11, $12, $13
+                ) ON CONFLICT DO NOTHING
+                RETURNING network, contract, token_id, "from", "to", quantity
+            ), queued AS (
+                -- the metadata of the token is fetched once, see `nft_metadata`
+                INSERT INTO nft_metadata (network, contract, token_id, standard)
+                SELECT network, contract, token_id, $4 FROM transfer
+                ON CONFLICT DO NOTHING
+            ), change AS (
+                SELECT network, contract, token_id, "from" AS "owner", -quantity AS delta FROM transfer
+                UNION ALL
+                SELECT network, contract, token_id, "to", quantity FROM transfer
+            )
+            INSERT INTO nft_owner (network, contract, token_id, "owner", quantity)
+            SELECT network, contract, token_id, "owner", SUM(delta) FROM change
+            WHERE "owner" <> '\x0000000000000000000000000000000000000000'
+            GROUP BY network, contract, token_id, "owner"
+            ON CONFLICT (network, contract, token_id, "owner") DO UPDATE SET
+                quantity = nft_owner.quantity + EXCLUDED.quantity,
+                updated_at = NOW()"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(log.address.as_slice())
+            .bind(self.id.to_string())
+            .bind(self.standard.as_str())
+            .bind(self.operator.as_ref().map(|v| v.as_slice()))
+            .bind(self.from.as_slice())
+            .bind(self.to.as_slice())
+            .bind(self.quantity.to_string())
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(self.batch_index)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revert<E>(
+        &self,
+        pool: &PgPool,
+        log: &Emitted<E>,
+        block_hash: &[u8],
+    ) -> Result<(), SqlError> {
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+
+        let sql = r#"WITH transfer AS (
+                DELETE FROM nft_transfer
+                WHERE network = $1
+                    AND contract = $2
+                    AND tx_hash = $3
+                    AND log_index = $4
+                    AND batch_index = $5
+                    AND block_hash = $6
+                RETURNING network, contract, token_id, "from", "to", quantity
+            ), change AS (
+                SELECT network, contract, token_id, "owner", SUM(delta) AS delta FROM (
+                    SELECT network, contract, token_id, "from" AS "owner", -quantity AS delta FROM transfer
+                    UNION ALL
+                    SELECT network, contract, token_id, "to", quantity FROM transfer
+                ) c
+                GROUP BY network, contract, token_id, "owner"
+            )
+            UPDATE nft_owner o SET
+                quantity = o.quantity - change.delta,
+                updated_at = NOW()
+            FROM change
+            WHERE o.network = change.network
+                AND o.contract = change.contract
+                AND o.token_id = change.token_id
+                AND o."owner" = change."owner""#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(log.address.as_slice())
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(self.batch_index)
+            .bind(block_hash)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Deletes and reverts the transfers recorded from the block `number` on if the block was
+/// replaced, i.e. if transfers were recorded from it under another hash than `hash`.
+async fn reorg<E>(
+    pool: &PgPool,
+    log: &Emitted<E>,
+    number: i64,
+    hash: &[u8],
+) -> Result<(), SqlError> {
+    let sql = r#"WITH transfer AS (
+            DELETE FROM nft_transfer
+            WHERE network = $1
+                AND block_number >= $2
+                AND block_hash <> $3
+                AND EXISTS (
+                    SELECT 1 FROM nft_transfer
+                    WHERE network = $1 AND block_number = $2 AND block_hash <> $3
+                )
+            RETURNING network, contract, token_id, "from", "to", quantity
+        ), change AS (
+            SELECT network, contract, token_id, "owner", SUM(delta) AS delta FROM (
+                SELECT network, contract, token_id, "from" AS "owner", -quantity AS delta FROM transfer
+                UNION ALL
+                SELECT network, contract, token_id, "to", quantity FROM transfer
+            ) c
+            GROUP BY network, contract, token_id, "owner"
+        )
+        UPDATE nft_owner o SET
+            quantity = o.quantity - change.delta,
+            updated_at = NOW()
+        FROM change
+        WHERE o.network = change.network
+            AND o.contract = change.contract
+            AND o.token_id = change.token_id
+            AND o."owner" = change."owner""#;
+
+    sqlx::query(sql)
+        .bind(log.network)
+        .bind(number)
+        .bind(hash)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
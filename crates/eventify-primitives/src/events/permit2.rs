@@ -0,0 +1,302 @@
+use alloy_primitives::{B256, U256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{
+    allowance::{Allowance, Source},
+    Emitted, Permit2,
+};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+// Besides their own tables, the events of Permit2 (see `NetworkKind::permit2`) are folded into
+// the `allowance` table along with the ERC20 approvals.
+
+impl Insert for Emitted<Permit2::Approval> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let token = self.event.token.as_slice();
+        let spender = self.event.spender.as_slice();
+        let amount = U256::from(self.event.amount).to_le_bytes::<32>();
+
+        let sql = r#"INSERT INTO permit2_approval (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            token,
+            spender,
+            amount,
+            expiration
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(token)
+            .bind(spender)
+            .bind(amount.as_slice())
+            .bind(self.event.expiration as i64)
+            .execute(pool)
+            .await?;
+
+        Allowance {
+            source: Source::Permit2,
+            token: self.event.token,
+            owner: self.event.owner,
+            spender: self.event.spender,
+            amount: Some(U256::from(self.event.amount)),
+            expiration: Some(self.event.expiration),
+            nonce: None,
+        }
+        .upsert(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<Permit2::Approval> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::Permit2_Approval)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<Permit2::Permit> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let token = self.event.token.as_slice();
+        let spender = self.event.spender.as_slice();
+        let amount = U256::from(self.event.amount).to_le_bytes::<32>();
+
+        let sql = r#"INSERT INTO permit2_permit (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            token,
+            spender,
+            amount,
+            expiration,
+            nonce
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(token)
+            .bind(spender)
+            .bind(amount.as_slice())
+            .bind(self.event.expiration as i64)
+            .bind(self.event.nonce as i64)
+            .execute(pool)
+            .await?;
+
+        Allowance {
+            source: Source::Permit2,
+            token: self.event.token,
+            owner: self.event.owner,
+            spender: self.event.spender,
+            amount: Some(U256::from(self.event.amount)),
+            expiration: Some(self.event.expiration),
+            nonce: Some(self.event.nonce),
+        }
+        .upsert(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<Permit2::Permit> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::Permit2_Permit));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<Permit2::Lockdown> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let token = self.event.token.as_slice();
+        let spender = self.event.spender.as_slice();
+
+        let sql = r#"INSERT INTO permit2_lockdown (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            token,
+            spender
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(token)
+            .bind(spender)
+            .execute(pool)
+            .await?;
+
+        // revokes the allowance, leaving the nonce be
+        Allowance {
+            source: Source::Permit2,
+            token: self.event.token,
+            owner: self.event.owner,
+            spender: self.event.spender,
+            amount: Some(U256::ZERO),
+            expiration: None,
+            nonce: None,
+        }
+        .upsert(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<Permit2::Lockdown> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::Permit2_Lockdown)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<Permit2::NonceInvalidation> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let token = self.event.token.as_slice();
+        let spender = self.event.spender.as_slice();
+
+        let sql = r#"INSERT INTO permit2_nonce_invalidation (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            token,
+            spender,
+            new_nonce,
+            old_nonce
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(token)
+            .bind(spender)
+            .bind(self.event.newNonce as i64)
+            .bind(self.event.oldNonce as i64)
+            .execute(pool)
+            .await?;
+
+        Allowance {
+            source: Source::Permit2,
+            token: self.event.token,
+            owner: self.event.owner,
+            spender: self.event.spender,
+            amount: None,
+            expiration: None,
+            nonce: Some(self.event.newNonce),
+        }
+        .upsert(pool, self)
+        .await
+    }
+}
+
+impl Emit for Emitted<Permit2::NonceInvalidation> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::Permit2_NonceInvalidation)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
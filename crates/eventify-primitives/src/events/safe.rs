@@ -0,0 +1,229 @@
+use alloy_primitives::{address, Address, B256, U256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Emitted, Safe};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+/// The canonical SafeProxyFactory deployments of v1.3 (and its EIP-155 variant) and v1.4, the
+/// only emitters whose `ProxyCreation` events are decoded.
+pub const SAFE_PROXY_FACTORIES: [Address; 3] = [
+    address!("a6B71E26C5e0845f74c812102Ca7114b6a896AB2"),
+    address!("C22834581EbC8527d974F8a1c97E1bEA4EF910BC"),
+    address!("4e1DCf7AD4e460CfD30791CCC4F9c8a4f820ec67"),
+];
+
+/// An entry of a Safe's history, the columns left out being irrelevant to its kind.
+#[derive(Debug, Default)]
+struct Entry {
+    safe_tx_hash: Option<B256>,
+    payment: Option<U256>,
+    owner: Option<Address>,
+    threshold: Option<U256>,
+    sender: Option<Address>,
+    value: Option<U256>,
+    singleton: Option<Address>,
+}
+
+impl Entry {
+    /// Stores the entry of `safe`, emitted in `log`, in the `safe_event` table.
+    async fn insert<E>(
+        &self,
+        pool: &PgPool,
+        log: &Emitted<E>,
+        safe: Address,
+        kind: &str,
+    ) -> Result<(), SqlError> {
+        let block_hash = log.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = log.block_number.map(|v| v.to::<i64>());
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+        let payment = self.payment.map(|v| v.to_le_bytes::<32>());
+        let threshold = self.threshold.map(|v| v.to_le_bytes::<32>());
+        let value = self.value.map(|v| v.to_le_bytes::<32>());
+
+        let sql = r#"INSERT INTO safe_event (
+            network,
+            safe,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            kind,
+            safe_tx_hash,
+            payment,
+            "owner",
+            threshold,
+            sender,
+            "value",
+            singleton
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(safe.as_slice())
+            .bind(log.address.as_slice())
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(kind)
+            .bind(self.safe_tx_hash.as_ref().map(|v| v.as_slice()))
+            .bind(payment.as_ref().map(|v| v.as_slice()))
+            .bind(self.owner.as_ref().map(|v| v.as_slice()))
+            .bind(threshold.as_ref().map(|v| v.as_slice()))
+            .bind(self.sender.as_ref().map(|v| v.as_slice()))
+            .bind(value.as_ref().map(|v| v.as_slice()))
+            .bind(self.singleton.as_ref().map(|v| v.as_slice()))
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<Safe::ExecutionSuccess> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            safe_tx_hash: Some(self.event.txHash),
+            payment: Some(self.event.payment),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "execution_success")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::ExecutionFailure> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            safe_tx_hash: Some(self.event.txHash),
+            payment: Some(self.event.payment),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "execution_failure")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::AddedOwner> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            owner: Some(self.event.owner),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "added_owner")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::RemovedOwner> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            owner: Some(self.event.owner),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "removed_owner")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::ChangedThreshold> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            threshold: Some(self.event.threshold),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "changed_threshold")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::SafeReceived> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            sender: Some(self.event.sender),
+            value: Some(self.event.value),
+            ..Default::default()
+        }
+        .insert(pool, self, self.address, "safe_received")
+        .await
+    }
+}
+
+impl Insert for Emitted<Safe::ProxyCreation> {
+    /// Starts the history of the created Safe, `address` being the factory.
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        Entry {
+            singleton: Some(self.event.singleton),
+            ..Default::default()
+        }
+        .insert(pool, self, self.event.proxy, "proxy_creation")
+        .await
+    }
+}
+
+impl Emit for Emitted<Safe::ExecutionSuccess> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_ExecutionSuccess, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::ExecutionFailure> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_ExecutionFailure, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::AddedOwner> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_AddedOwner, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::RemovedOwner> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_RemovedOwner, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::ChangedThreshold> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_ChangedThreshold, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::SafeReceived> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_SafeReceived, self).await
+    }
+}
+
+impl Emit for Emitted<Safe::ProxyCreation> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        emit(queue, network, LogKind::Safe_ProxyCreation, self).await
+    }
+}
+
+async fn emit<E: serde::Serialize>(
+    queue: &redis::Client,
+    network: &NetworkKind,
+    kind: LogKind,
+    emitted: &Emitted<E>,
+) -> Result<(), EmitError> {
+    let mut con = queue.get_async_connection().await?;
+
+    let channel = format!("{}:{}", network, ResourceKind::Log(kind));
+    con.lpush::<_, _, ()>(channel, serde_json::to_string(emitted)?)
+        .await?;
+
+    Ok(())
+}
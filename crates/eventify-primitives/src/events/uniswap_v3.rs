@@ -0,0 +1,320 @@
+use alloy_primitives::{B256, U256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{Emitted, UniswapV3};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<UniswapV3::Swap> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let sender = self.event.sender.as_slice();
+        let recipient = self.event.recipient.as_slice();
+        let amount0 = self.event.amount0.into_raw().to_le_bytes::<32>();
+        let amount1 = self.event.amount1.into_raw().to_le_bytes::<32>();
+        let sqrt_price_x96 = U256::from(self.event.sqrtPriceX96).to_le_bytes::<32>();
+        let liquidity = U256::from(self.event.liquidity).to_le_bytes::<32>();
+
+        let sql = r#"INSERT INTO uniswap_v3_swap (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            sender,
+            recipient,
+            amount0,
+            amount1,
+            sqrt_price_x96,
+            liquidity,
+            tick
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(sender)
+            .bind(recipient)
+            .bind(amount0.as_slice())
+            .bind(amount1.as_slice())
+            .bind(sqrt_price_x96.as_slice())
+            .bind(liquidity.as_slice())
+            .bind(self.event.tick)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<UniswapV3::Swap> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::UniswapV3_Swap));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<UniswapV3::Mint> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let sender = self.event.sender.as_slice();
+        let owner = self.event.owner.as_slice();
+        let amount = U256::from(self.event.amount).to_le_bytes::<32>();
+        let amount0 = self.event.amount0.as_le_slice();
+        let amount1 = self.event.amount1.as_le_slice();
+
+        let sql = r#"INSERT INTO uniswap_v3_mint (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            sender,
+            "owner",
+            tick_lower,
+            tick_upper,
+            amount,
+            amount0,
+            amount1
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(sender)
+            .bind(owner)
+            .bind(self.event.tickLower)
+            .bind(self.event.tickUpper)
+            .bind(amount.as_slice())
+            .bind(amount0)
+            .bind(amount1)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<UniswapV3::Mint> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::UniswapV3_Mint));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<UniswapV3::Burn> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let amount = U256::from(self.event.amount).to_le_bytes::<32>();
+        let amount0 = self.event.amount0.as_le_slice();
+        let amount1 = self.event.amount1.as_le_slice();
+
+        let sql = r#"INSERT INTO uniswap_v3_burn (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            tick_lower,
+            tick_upper,
+            amount,
+            amount0,
+            amount1
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(self.event.tickLower)
+            .bind(self.event.tickUpper)
+            .bind(amount.as_slice())
+            .bind(amount0)
+            .bind(amount1)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<UniswapV3::Burn> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::UniswapV3_Burn));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<UniswapV3::Collect> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let owner = self.event.owner.as_slice();
+        let recipient = self.event.recipient.as_slice();
+        let amount0 = U256::from(self.event.amount0).to_le_bytes::<32>();
+        let amount1 = U256::from(self.event.amount1).to_le_bytes::<32>();
+
+        let sql = r#"INSERT INTO uniswap_v3_collect (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            "owner",
+            recipient,
+            tick_lower,
+            tick_upper,
+            amount0,
+            amount1
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(owner)
+            .bind(recipient)
+            .bind(self.event.tickLower)
+            .bind(self.event.tickUpper)
+            .bind(amount0.as_slice())
+            .bind(amount1.as_slice())
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<UniswapV3::Collect> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::UniswapV3_Collect)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<UniswapV3::Initialize> {
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let sqrt_price_x96 = U256::from(self.event.sqrtPriceX96).to_le_bytes::<32>();
+
+        let sql = r#"INSERT INTO uniswap_v3_initialize (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            sqrt_price_x96,
+            tick
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(sqrt_price_x96.as_slice())
+            .bind(self.event.tick)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Emitted<UniswapV3::Initialize> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::UniswapV3_Initialize)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
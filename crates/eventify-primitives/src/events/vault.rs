@@ -0,0 +1,139 @@
+//! The accounting of ERC4626 vaults, fed by their deposits and withdrawals.
+//!
+//! Each deposit or withdrawal is recorded in `vault_flow` with the assets and shares it moved
+//! in or out of the vault, from which the `vault_total` and `vault_share_price` views derive the
+//! totals and the assets paid per share at each block. A flow removed by a reorg is deleted.
+//!
+//! As with the ERC20 balances (see `balance`), the logs aren't flagged `removed` on every path,
+//! so the flows recorded from a block since seen under another hash are deleted too, and logs
+//! without a block hash (i.e. pending) aren't recorded.
+
+use alloy_primitives::{Address, U256};
+use sqlx::{Error as SqlError, PgPool};
+
+use super::Emitted;
+
+/// Which way the assets went.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Direction {
+    Deposit,
+    Withdraw,
+}
+
+impl Direction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Deposit => "deposit",
+            Direction::Withdraw => "withdraw",
+        }
+    }
+}
+
+/// `assets` exchanged for `shares` of the vault that emitted the log, the shares being minted
+/// to or burned from `owner`.
+#[derive(Debug)]
+pub(crate) struct Flow {
+    pub(crate) direction: Direction,
+    pub(crate) sender: Address,
+    pub(crate) receiver: Option<Address>,
+    pub(crate) owner: Address,
+    pub(crate) assets: U256,
+    pub(crate) shares: U256,
+}
+
+impl Flow {
+    /// Records the flow, emitted in `log`, or deletes it when the log was removed by a reorg.
+    pub(crate) async fn record<E>(&self, pool: &PgPool, log: &Emitted<E>) -> Result<(), SqlError> {
+        let Some(block_hash) = log.block_hash.as_ref().map(|v| v.as_slice()) else {
+            return Ok(());
+        };
+        let block_number = log.block_number.map(|v| v.to::<i64>());
+        let tx_hash = log.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = log.log_index.map(|v| v.to::<i64>());
+
+        if log.removed {
+            let sql = r#"DELETE FROM vault_flow
+                WHERE network = $1
+                    AND vault = $2
+                    AND tx_hash = $3
+                    AND log_index = $4
+                    AND block_hash = $5"#;
+
+            sqlx::query(sql)
+                .bind(log.network)
+                .bind(log.address.as_slice())
+                .bind(tx_hash)
+                .bind(log_index)
+                .bind(block_hash)
+                .execute(pool)
+                .await?;
+
+            return Ok(());
+        }
+        if let Some(block_number) = block_number {
+            reorg(pool, log, block_number, block_hash).await?;
+        }
+
+        let sql = r#"INSERT INTO vault_flow (
+            network,
+            vault,
+            direction,
+            sender,
+            "receiver",
+            "owner",
+            "assets",
+            shares,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7::numeric, $8::numeric, $9, $10, $11, $12
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(log.network)
+            .bind(log.address.as_slice())
+            .bind(self.direction.as_str())
+            .bind(self.sender.as_slice())
+            .bind(self.receiver.as_ref().map(|v| v.as_slice()))
+            .bind(self.owner.as_slice())
+            .bind(self.assets.to_string())
+            .bind(self.shares.to_string())
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Deletes the flows recorded from the block `number` on if the block was replaced, i.e. if flows
+/// were recorded from it under another hash than `hash`.
+async fn reorg<E>(
+    pool: &PgPool,
+    log: &Emitted<E>,
+    number: i64,
+    hash: &[u8],
+) -> Result<(), SqlError> {
+    let sql = r#"DELETE FROM vault_flow
+        WHERE network = $1
+            AND block_number >= $2
+            AND block_hash <> $3
+            AND EXISTS (
+                SELECT 1 FROM vault_flow
+                WHERE network = $1 AND block_number = $2 AND block_hash <> $3
+            )"#;
+
+    sqlx::query(sql)
+        .bind(log.network)
+        .bind(number)
+        .bind(hash)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use alloy_primitives::{Address, B256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, PgPool};
+
+use super::{balance, Emitted, WETH};
+use crate::{
+    networks::{LogKind, NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+impl Insert for Emitted<WETH::Deposit> {
+    /// Stores the deposit and applies it to the balances of the token as a mint, or reverts it
+    /// when removed by a reorg.
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let dst = self.event.dst.as_slice();
+        let wad = self.event.wad.as_le_slice();
+
+        let sql = r#"INSERT INTO weth_deposit (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            dst,
+            wad
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(dst)
+            .bind(wad)
+            .execute(pool)
+            .await?;
+
+        if self.removed {
+            balance::revert(pool, self).await
+        } else {
+            balance::apply(pool, self, Address::ZERO, self.event.dst, self.event.wad).await
+        }
+    }
+}
+
+impl Emit for Emitted<WETH::Deposit> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::WETH_Deposit));
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Insert for Emitted<WETH::Withdrawal> {
+    /// Stores the withdrawal and applies it to the balances of the token as a burn, or reverts it
+    /// when removed by a reorg.
+    async fn insert(&self, pool: &PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let address = self.address.as_slice();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let log_index = self.log_index.map(|v| v.to::<i64>());
+        let src = self.event.src.as_slice();
+        let wad = self.event.wad.as_le_slice();
+
+        let sql = r#"INSERT INTO weth_withdrawal (
+            network,
+            address,
+            block_hash,
+            block_number,
+            tx_hash,
+            log_index,
+            src,
+            wad
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(address)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(log_index)
+            .bind(src)
+            .bind(wad)
+            .execute(pool)
+            .await?;
+
+        if self.removed {
+            balance::revert(pool, self).await
+        } else {
+            balance::apply(pool, self, self.event.src, Address::ZERO, self.event.wad).await
+        }
+    }
+}
+
+impl Emit for Emitted<WETH::Withdrawal> {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!(
+            "{}:{}",
+            network,
+            ResourceKind::Log(LogKind::WETH_Withdrawal)
+        );
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -2,49 +2,79 @@
 #![warn(missing_debug_implementations, unreachable_pub, rustdoc::all)]
 #![deny(unused_must_use, rust_2018_idioms)]
 
+pub mod abi;
 pub mod criteria;
 pub mod events;
 pub mod networks;
+pub mod token;
 
 pub mod ethereum {
-    pub use crate::networks::ethereum::{block::EthBlock as Block, log::EthLog as Log};
+    pub use crate::networks::ethereum::{
+        block::EthBlock as Block, log::EthLog as Log, transaction::EthTransaction as Transaction,
+        EthReceipt as Receipt,
+    };
 }
 
 pub mod zksync {
-    pub use crate::networks::zksync::{block::ZksyncBlock as Block, log::ZksyncLog as Log};
+    pub use crate::networks::zksync::{
+        block::ZksyncBlock as Block, log::ZksyncLog as Log,
+        transaction::ZksyncTransaction as Transaction, ZksyncReceipt as Receipt,
+    };
 }
 
 pub mod polygon {
-    pub use crate::networks::polygon::{block::PolygonBlock as Block, log::PolygonLog as Log};
+    pub use crate::networks::polygon::{
+        block::PolygonBlock as Block, log::PolygonLog as Log, PolygonReceipt as Receipt,
+        PolygonTransaction as Transaction,
+    };
 }
 
 pub mod optimism {
-    pub use crate::networks::optimism::{block::OptimismBlock as Block, log::OptimismLog as Log};
+    pub use crate::networks::optimism::{
+        block::OptimismBlock as Block, log::OptimismLog as Log, OptimismReceipt as Receipt,
+        OptimismTransaction as Transaction,
+    };
 }
 
 pub mod arbitrum {
-    pub use crate::networks::arbitrum::{block::ArbitrumBlock as Block, log::ArbitrumLog as Log};
+    pub use crate::networks::arbitrum::{
+        block::ArbitrumBlock as Block, log::ArbitrumLog as Log,
+        receipt::ArbitrumReceipt as Receipt, ArbitrumTransaction as Transaction,
+    };
 }
 
 pub mod linea {
-    pub use crate::networks::linea::{block::LineaBlock as Block, log::LineaLog as Log};
+    pub use crate::networks::linea::{
+        block::LineaBlock as Block, log::LineaLog as Log, LineaReceipt as Receipt,
+        LineaTransaction as Transaction,
+    };
 }
 
 pub mod avalanche {
     pub use crate::networks::avalanche::{
-        block::AvalancheBlock as Block, log::AvalancheLog as Log,
+        block::AvalancheBlock as Block, log::AvalancheLog as Log, AvalancheReceipt as Receipt,
+        AvalancheTransaction as Transaction,
     };
 }
 
 pub mod bsc {
-    pub use crate::networks::bsc::{block::BscBlock as Block, log::BscLog as Log};
+    pub use crate::networks::bsc::{
+        block::BscBlock as Block, log::BscLog as Log, BscReceipt as Receipt,
+        BscTransaction as Transaction,
+    };
 }
 
 pub mod base {
-    pub use crate::networks::base::{block::BaseBlock as Block, log::BaseLog as Log};
+    pub use crate::networks::base::{
+        block::BaseBlock as Block, log::BaseLog as Log, BaseReceipt as Receipt,
+        BaseTransaction as Transaction,
+    };
 }
 
-pub use traits::{Block as BlockT, Emit as EmitT, Insert as InsertT, Log as LogT};
+pub use traits::{
+    Block as BlockT, Emit as EmitT, Insert as InsertT, Log as LogT, Receipt as ReceiptT,
+    Transaction as TransactionT,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum EmitError {
@@ -103,5 +133,41 @@ mod traits {
         + Send
     {
         fn core(&self) -> &crate::networks::core::CoreLog;
+        fn core_mut(&mut self) -> &mut crate::networks::core::CoreLog;
+    }
+
+    pub trait Transaction:
+        Insert
+        + Emit
+        + Clone
+        + std::fmt::Debug
+        + Default
+        + PartialEq
+        + Eq
+        + std::hash::Hash
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + Sync
+        + Send
+    {
+        fn core(&self) -> &crate::networks::core::CoreTransaction;
+        fn core_mut(&mut self) -> &mut crate::networks::core::CoreTransaction;
+    }
+
+    pub trait Receipt:
+        Insert
+        + Emit
+        + Clone
+        + std::fmt::Debug
+        + Default
+        + PartialEq
+        + Eq
+        + std::hash::Hash
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + Sync
+        + Send
+    {
+        fn core(&self) -> &crate::networks::core::CoreReceipt;
     }
 }
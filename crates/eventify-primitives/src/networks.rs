@@ -7,14 +7,22 @@ pub mod ethereum;
 pub mod linea;
 pub mod optimism;
 pub mod polygon;
+pub mod trace;
 pub mod zksync;
 
-use alloy_primitives::B256;
+mod op_stack;
+
+use alloy_primitives::{address, Address, B256};
+use redis::AsyncCommands;
 use sqlx::{Error as SqlError, PgPool};
 
 use crate::{
-    events::{ERC1155, ERC20, ERC4626, ERC721, ERC777},
-    BlockT, EmitError, EmitT, InsertT, LogT,
+    events::{
+        chainlink::PriceUpdate, decoded::DecodedEvent, AccessControl, Chainlink, ERC6551Registry,
+        Emitted, EntryPoint, Ownable, Permit2, Safe, UniswapV2, UniswapV3, EIP1967, ERC1155, ERC20,
+        ERC4626, ERC4906, ERC5192, ERC721, ERC777, WETH,
+    },
+    BlockT, EmitError, EmitT, InsertT, LogT, ReceiptT, TransactionT,
 };
 
 #[derive(Clone, Debug)]
@@ -70,6 +78,32 @@ impl std::fmt::Display for NetworkKind {
     }
 }
 
+impl NetworkKind {
+    /// The canonical wrapped native token of the network (WETH, WMATIC, WBNB, WAVAX).
+    pub fn wrapped_native(&self) -> Address {
+        match self {
+            NetworkKind::Ethereum => address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            NetworkKind::Zksync => address!("5AEa5775959fBC2557Cc8789bC1bf90A239D9a91"),
+            NetworkKind::Polygon => address!("0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+            NetworkKind::Optimism | NetworkKind::Base => {
+                address!("4200000000000000000000000000000000000006")
+            }
+            NetworkKind::Arbitrum => address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+            NetworkKind::Linea => address!("e5D7C2a44FfDDf6b295A15c148167daaAf5Cf34f"),
+            NetworkKind::Avalanche => address!("B31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+            NetworkKind::Bsc => address!("bb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+        }
+    }
+
+    /// Uniswap's Permit2, deployed at the same address on every network but zkSync.
+    pub fn permit2(&self) -> Address {
+        match self {
+            NetworkKind::Zksync => address!("0000000000225e31D15943971F47aD3022F714Fa"),
+            _ => address!("000000000022D473030F116dDEE9F6B43aC78BA3"),
+        }
+    }
+}
+
 impl std::str::FromStr for NetworkKind {
     type Err = NetworkKindError;
 
@@ -94,10 +128,10 @@ impl std::str::FromStr for NetworkKind {
 pub enum Logs<L: LogT> {
     Raw(L),
 
-    ERC20_Transfer(ERC20::Transfer),
-    ERC20_Approval(ERC20::Approval),
+    ERC20_Transfer(Emitted<ERC20::Transfer>),
+    ERC20_Approval(Emitted<ERC20::Approval>),
 
-    ERC721_Transfer(ERC721::Transfer),
+    ERC721_Transfer(Emitted<ERC721::Transfer>),
     ERC721_Approval(ERC721::Approval),
     ERC721_ApprovalForAll(ERC721::ApprovalForAll),
 
@@ -107,12 +141,68 @@ pub enum Logs<L: LogT> {
     ERC777_AuthorizedOperator(ERC777::AuthorizedOperator),
     ERC777_RevokedOperator(ERC777::RevokedOperator),
 
-    ERC1155_TransferSingle(ERC1155::TransferSingle),
-    ERC1155_TransferBatch(ERC1155::TransferBatch),
-    ERC1155_URI(ERC1155::URI),
+    ERC1155_TransferSingle(Emitted<ERC1155::TransferSingle>),
+    ERC1155_TransferBatch(Emitted<ERC1155::TransferBatch>),
+    ERC1155_URI(Emitted<ERC1155::URI>),
+
+    ERC4626_Deposit(Emitted<ERC4626::Deposit>),
+    ERC4626_Withdraw(Emitted<ERC4626::Withdraw>),
+
+    UniswapV2_Swap(Emitted<UniswapV2::Swap>),
+    UniswapV2_Mint(Emitted<UniswapV2::Mint>),
+    UniswapV2_Burn(Emitted<UniswapV2::Burn>),
+    UniswapV2_Sync(Emitted<UniswapV2::Sync>),
+
+    UniswapV3_Swap(Emitted<UniswapV3::Swap>),
+    UniswapV3_Mint(Emitted<UniswapV3::Mint>),
+    UniswapV3_Burn(Emitted<UniswapV3::Burn>),
+    UniswapV3_Collect(Emitted<UniswapV3::Collect>),
+    UniswapV3_Initialize(Emitted<UniswapV3::Initialize>),
 
-    ERC4626_Deposit(ERC4626::Deposit),
-    ERC4626_Withdraw(ERC4626::Withdraw),
+    WETH_Deposit(Emitted<WETH::Deposit>),
+    WETH_Withdrawal(Emitted<WETH::Withdrawal>),
+
+    EIP1967_Upgraded(Emitted<EIP1967::Upgraded>),
+    EIP1967_AdminChanged(Emitted<EIP1967::AdminChanged>),
+    EIP1967_BeaconUpgraded(Emitted<EIP1967::BeaconUpgraded>),
+
+    Ownable_OwnershipTransferred(Emitted<Ownable::OwnershipTransferred>),
+
+    AccessControl_RoleGranted(Emitted<AccessControl::RoleGranted>),
+    AccessControl_RoleRevoked(Emitted<AccessControl::RoleRevoked>),
+    AccessControl_RoleAdminChanged(Emitted<AccessControl::RoleAdminChanged>),
+
+    EntryPoint_UserOperationEvent(Emitted<EntryPoint::UserOperationEvent>),
+    EntryPoint_AccountDeployed(Emitted<EntryPoint::AccountDeployed>),
+    EntryPoint_UserOperationRevertReason(Emitted<EntryPoint::UserOperationRevertReason>),
+    EntryPoint_PostOpRevertReason(Emitted<EntryPoint::PostOpRevertReason>),
+    EntryPoint_BeforeExecution(Emitted<EntryPoint::BeforeExecution>),
+
+    ERC4906_MetadataUpdate(Emitted<ERC4906::MetadataUpdate>),
+    ERC4906_BatchMetadataUpdate(Emitted<ERC4906::BatchMetadataUpdate>),
+
+    ERC5192_Locked(Emitted<ERC5192::Locked>),
+    ERC5192_Unlocked(Emitted<ERC5192::Unlocked>),
+
+    ERC6551Registry_ERC6551AccountCreated(Emitted<ERC6551Registry::ERC6551AccountCreated>),
+
+    Permit2_Approval(Emitted<Permit2::Approval>),
+    Permit2_Permit(Emitted<Permit2::Permit>),
+    Permit2_Lockdown(Emitted<Permit2::Lockdown>),
+    Permit2_NonceInvalidation(Emitted<Permit2::NonceInvalidation>),
+
+    Safe_ExecutionSuccess(Emitted<Safe::ExecutionSuccess>),
+    Safe_ExecutionFailure(Emitted<Safe::ExecutionFailure>),
+    Safe_AddedOwner(Emitted<Safe::AddedOwner>),
+    Safe_RemovedOwner(Emitted<Safe::RemovedOwner>),
+    Safe_ChangedThreshold(Emitted<Safe::ChangedThreshold>),
+    Safe_SafeReceived(Emitted<Safe::SafeReceived>),
+    Safe_ProxyCreation(Emitted<Safe::ProxyCreation>),
+
+    Chainlink_AnswerUpdated(PriceUpdate),
+    Chainlink_NewRound(Emitted<Chainlink::NewRound>),
+
+    Decoded(DecodedEvent),
 }
 
 impl<L: LogT> InsertT for Logs<L> {
@@ -138,6 +228,62 @@ impl<L: LogT> InsertT for Logs<L> {
 
             Logs::ERC4626_Deposit(e) => e.insert(pool, tx_hash).await?,
             Logs::ERC4626_Withdraw(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::UniswapV2_Swap(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV2_Mint(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV2_Burn(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV2_Sync(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::UniswapV3_Swap(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV3_Mint(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV3_Burn(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV3_Collect(e) => e.insert(pool, tx_hash).await?,
+            Logs::UniswapV3_Initialize(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::WETH_Deposit(e) => e.insert(pool, tx_hash).await?,
+            Logs::WETH_Withdrawal(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::EIP1967_Upgraded(e) => e.insert(pool, tx_hash).await?,
+            Logs::EIP1967_AdminChanged(e) => e.insert(pool, tx_hash).await?,
+            Logs::EIP1967_BeaconUpgraded(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::Ownable_OwnershipTransferred(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::AccessControl_RoleGranted(e) => e.insert(pool, tx_hash).await?,
+            Logs::AccessControl_RoleRevoked(e) => e.insert(pool, tx_hash).await?,
+            Logs::AccessControl_RoleAdminChanged(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::EntryPoint_UserOperationEvent(e) => e.insert(pool, tx_hash).await?,
+            Logs::EntryPoint_AccountDeployed(e) => e.insert(pool, tx_hash).await?,
+            Logs::EntryPoint_UserOperationRevertReason(e) => e.insert(pool, tx_hash).await?,
+            Logs::EntryPoint_PostOpRevertReason(e) => e.insert(pool, tx_hash).await?,
+            Logs::EntryPoint_BeforeExecution(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::ERC4906_MetadataUpdate(e) => e.insert(pool, tx_hash).await?,
+            Logs::ERC4906_BatchMetadataUpdate(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::ERC5192_Locked(e) => e.insert(pool, tx_hash).await?,
+            Logs::ERC5192_Unlocked(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::ERC6551Registry_ERC6551AccountCreated(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::Permit2_Approval(e) => e.insert(pool, tx_hash).await?,
+            Logs::Permit2_Permit(e) => e.insert(pool, tx_hash).await?,
+            Logs::Permit2_Lockdown(e) => e.insert(pool, tx_hash).await?,
+            Logs::Permit2_NonceInvalidation(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::Safe_ExecutionSuccess(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_ExecutionFailure(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_AddedOwner(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_RemovedOwner(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_ChangedThreshold(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_SafeReceived(e) => e.insert(pool, tx_hash).await?,
+            Logs::Safe_ProxyCreation(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::Chainlink_AnswerUpdated(e) => e.insert(pool, tx_hash).await?,
+            Logs::Chainlink_NewRound(e) => e.insert(pool, tx_hash).await?,
+
+            Logs::Decoded(e) => e.insert(pool, tx_hash).await?,
         }
 
         Ok(())
@@ -171,41 +317,152 @@ impl<L: LogT + serde::Serialize> EmitT for Logs<L> {
 
             Logs::ERC4626_Deposit(e) => e.emit(queue, network).await?,
             Logs::ERC4626_Withdraw(e) => e.emit(queue, network).await?,
+
+            Logs::UniswapV2_Swap(e) => e.emit(queue, network).await?,
+            Logs::UniswapV2_Mint(e) => e.emit(queue, network).await?,
+            Logs::UniswapV2_Burn(e) => e.emit(queue, network).await?,
+            Logs::UniswapV2_Sync(e) => e.emit(queue, network).await?,
+
+            Logs::UniswapV3_Swap(e) => e.emit(queue, network).await?,
+            Logs::UniswapV3_Mint(e) => e.emit(queue, network).await?,
+            Logs::UniswapV3_Burn(e) => e.emit(queue, network).await?,
+            Logs::UniswapV3_Collect(e) => e.emit(queue, network).await?,
+            Logs::UniswapV3_Initialize(e) => e.emit(queue, network).await?,
+
+            Logs::WETH_Deposit(e) => e.emit(queue, network).await?,
+            Logs::WETH_Withdrawal(e) => e.emit(queue, network).await?,
+
+            Logs::EIP1967_Upgraded(e) => e.emit(queue, network).await?,
+            Logs::EIP1967_AdminChanged(e) => e.emit(queue, network).await?,
+            Logs::EIP1967_BeaconUpgraded(e) => e.emit(queue, network).await?,
+
+            Logs::Ownable_OwnershipTransferred(e) => e.emit(queue, network).await?,
+
+            Logs::AccessControl_RoleGranted(e) => e.emit(queue, network).await?,
+            Logs::AccessControl_RoleRevoked(e) => e.emit(queue, network).await?,
+            Logs::AccessControl_RoleAdminChanged(e) => e.emit(queue, network).await?,
+
+            Logs::EntryPoint_UserOperationEvent(e) => e.emit(queue, network).await?,
+            Logs::EntryPoint_AccountDeployed(e) => e.emit(queue, network).await?,
+            Logs::EntryPoint_UserOperationRevertReason(e) => e.emit(queue, network).await?,
+            Logs::EntryPoint_PostOpRevertReason(e) => e.emit(queue, network).await?,
+            Logs::EntryPoint_BeforeExecution(e) => e.emit(queue, network).await?,
+
+            Logs::ERC4906_MetadataUpdate(e) => e.emit(queue, network).await?,
+            Logs::ERC4906_BatchMetadataUpdate(e) => e.emit(queue, network).await?,
+
+            Logs::ERC5192_Locked(e) => e.emit(queue, network).await?,
+            Logs::ERC5192_Unlocked(e) => e.emit(queue, network).await?,
+
+            Logs::ERC6551Registry_ERC6551AccountCreated(e) => e.emit(queue, network).await?,
+
+            Logs::Permit2_Approval(e) => e.emit(queue, network).await?,
+            Logs::Permit2_Permit(e) => e.emit(queue, network).await?,
+            Logs::Permit2_Lockdown(e) => e.emit(queue, network).await?,
+            Logs::Permit2_NonceInvalidation(e) => e.emit(queue, network).await?,
+
+            Logs::Safe_ExecutionSuccess(e) => e.emit(queue, network).await?,
+            Logs::Safe_ExecutionFailure(e) => e.emit(queue, network).await?,
+            Logs::Safe_AddedOwner(e) => e.emit(queue, network).await?,
+            Logs::Safe_RemovedOwner(e) => e.emit(queue, network).await?,
+            Logs::Safe_ChangedThreshold(e) => e.emit(queue, network).await?,
+            Logs::Safe_SafeReceived(e) => e.emit(queue, network).await?,
+            Logs::Safe_ProxyCreation(e) => e.emit(queue, network).await?,
+
+            Logs::Chainlink_AnswerUpdated(e) => e.emit(queue, network).await?,
+            Logs::Chainlink_NewRound(e) => e.emit(queue, network).await?,
+
+            Logs::Decoded(e) => e.emit(queue, network).await?,
         }
 
         Ok(())
     }
 }
 
+/// A log whose kind is an alert, see [`LogKind::is_alert`]. It's raised by the collector along
+/// with the log itself and pushed to the `<network>:alert` channel.
+#[derive(Debug, Clone)]
+pub struct Alert<L: LogT> {
+    kind: LogKind,
+    event: Logs<L>,
+}
+
+impl<L: LogT> Alert<L> {
+    pub fn new(event: &Logs<L>) -> Option<Self> {
+        let kind = LogKind::from(event);
+        kind.is_alert().then(|| Self {
+            kind,
+            event: event.clone(),
+        })
+    }
+}
+
+impl<L: LogT> serde::Serialize for Alert<L> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_json::json!({ "kind": self.kind.to_string(), "event": self.event })
+            .serialize(serializer)
+    }
+}
+
+impl<L: LogT> EmitT for Alert<L> {
+    async fn emit(
+        &self,
+        queue: &redis::Client,
+        network: &crate::networks::NetworkKind,
+    ) -> eyre::Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+        let alert = serde_json::to_string(self)?;
+        con.lpush::<_, _, ()>(format!("{}:alert", network), alert)
+            .await?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
-pub enum Resource<B, L>
+pub enum Resource<B, L, T, R>
 where
     B: BlockT,
     L: LogT,
+    T: TransactionT,
+    R: ReceiptT,
 {
     Block(B),
     Log(Logs<L>),
+    Transaction(T),
+    Receipt(R),
+    Trace(trace::Trace),
+    Alert(Alert<L>),
 }
 
-impl<B, L> InsertT for Resource<B, L>
+impl<B, L, T, R> InsertT for Resource<B, L, T, R>
 where
     B: BlockT,
     L: LogT,
+    T: TransactionT,
+    R: ReceiptT,
 {
     async fn insert(&self, pool: &PgPool, tx_hash: &Option<B256>) -> eyre::Result<(), SqlError> {
         match self {
             Resource::Block(block) => block.insert(pool, tx_hash).await?,
             Resource::Log(log) => log.insert(pool, tx_hash).await?,
+            Resource::Transaction(tx) => tx.insert(pool, tx_hash).await?,
+            Resource::Receipt(receipt) => receipt.insert(pool, tx_hash).await?,
+            Resource::Trace(trace) => trace.insert(pool, tx_hash).await?,
+            // the log raising it is inserted on its own
+            Resource::Alert(_) => {}
         }
 
         Ok(())
     }
 }
 
-impl<B, L> EmitT for Resource<B, L>
+impl<B, L, T, R> EmitT for Resource<B, L, T, R>
 where
     B: BlockT,
     L: LogT,
+    T: TransactionT,
+    R: ReceiptT,
 {
     async fn emit(
         &self,
@@ -215,6 +472,10 @@ where
         match self {
             Resource::Block(block) => block.emit(queue, network).await?,
             Resource::Log(log) => log.emit(queue, network).await?,
+            Resource::Transaction(tx) => tx.emit(queue, network).await?,
+            Resource::Receipt(receipt) => receipt.emit(queue, network).await?,
+            Resource::Trace(trace) => trace.emit(queue, network).await?,
+            Resource::Alert(alert) => alert.emit(queue, network).await?,
         }
 
         Ok(())
@@ -228,6 +489,29 @@ where
 pub enum ResourceKind {
     Block,
     Log(LogKind),
+    Transaction,
+    Receipt,
+    Trace,
+    Alert,
+}
+
+impl<B, L, T, R> From<&Resource<B, L, T, R>> for ResourceKind
+where
+    B: BlockT,
+    L: LogT,
+    T: TransactionT,
+    R: ReceiptT,
+{
+    fn from(resource: &Resource<B, L, T, R>) -> Self {
+        match resource {
+            Resource::Block(_) => ResourceKind::Block,
+            Resource::Log(log) => ResourceKind::Log(LogKind::from(log)),
+            Resource::Transaction(_) => ResourceKind::Transaction,
+            Resource::Receipt(_) => ResourceKind::Receipt,
+            Resource::Trace(_) => ResourceKind::Trace,
+            Resource::Alert(_) => ResourceKind::Alert,
+        }
+    }
 }
 
 impl ResourceKind {
@@ -236,6 +520,9 @@ impl ResourceKind {
             .map(|x| match x.trim().to_lowercase().as_str() {
                 "block" | "blocks" => ResourceKind::Block,
                 "log" | "logs" => ResourceKind::Log(LogKind::Raw),
+                "transaction" | "transactions" => ResourceKind::Transaction,
+                "receipt" | "receipts" => ResourceKind::Receipt,
+                "trace" | "traces" => ResourceKind::Trace,
                 _ => {
                     panic!("invalid resource: {}", x);
                 }
@@ -253,6 +540,10 @@ impl std::fmt::Display for ResourceKind {
         match self {
             ResourceKind::Block => write!(f, "block"),
             ResourceKind::Log(kind) => write!(f, "{}", kind),
+            ResourceKind::Transaction => write!(f, "transaction"),
+            ResourceKind::Receipt => write!(f, "receipt"),
+            ResourceKind::Trace => write!(f, "trace"),
+            ResourceKind::Alert => write!(f, "alert"),
         }
     }
 }
@@ -295,6 +586,169 @@ pub enum LogKind {
 
     ERC4626_Deposit,
     ERC4626_Withdraw,
+
+    UniswapV2_Swap,
+    UniswapV2_Mint,
+    UniswapV2_Burn,
+    UniswapV2_Sync,
+
+    UniswapV3_Swap,
+    UniswapV3_Mint,
+    UniswapV3_Burn,
+    UniswapV3_Collect,
+    UniswapV3_Initialize,
+
+    WETH_Deposit,
+    WETH_Withdrawal,
+
+    EIP1967_Upgraded,
+    EIP1967_AdminChanged,
+    EIP1967_BeaconUpgraded,
+
+    Ownable_OwnershipTransferred,
+
+    AccessControl_RoleGranted,
+    AccessControl_RoleRevoked,
+    AccessControl_RoleAdminChanged,
+
+    EntryPoint_UserOperationEvent,
+    EntryPoint_AccountDeployed,
+    EntryPoint_UserOperationRevertReason,
+    EntryPoint_PostOpRevertReason,
+    EntryPoint_BeforeExecution,
+
+    ERC4906_MetadataUpdate,
+    ERC4906_BatchMetadataUpdate,
+
+    ERC5192_Locked,
+    ERC5192_Unlocked,
+
+    ERC6551Registry_ERC6551AccountCreated,
+
+    Permit2_Approval,
+    Permit2_Permit,
+    Permit2_Lockdown,
+    Permit2_NonceInvalidation,
+
+    Safe_ExecutionSuccess,
+    Safe_ExecutionFailure,
+    Safe_AddedOwner,
+    Safe_RemovedOwner,
+    Safe_ChangedThreshold,
+    Safe_SafeReceived,
+    Safe_ProxyCreation,
+
+    Chainlink_AnswerUpdated,
+    Chainlink_NewRound,
+
+    Decoded,
+}
+
+impl<L: LogT> From<&Logs<L>> for LogKind {
+    fn from(log: &Logs<L>) -> Self {
+        match log {
+            Logs::Raw(_) => LogKind::Raw,
+            Logs::ERC20_Transfer(_) => LogKind::ERC20_Transfer,
+            Logs::ERC20_Approval(_) => LogKind::ERC20_Approval,
+
+            Logs::ERC721_Transfer(_) => LogKind::ERC721_Transfer,
+            Logs::ERC721_Approval(_) => LogKind::ERC721_Approval,
+            Logs::ERC721_ApprovalForAll(_) => LogKind::ERC721_ApprovalForAll,
+
+            Logs::ERC777_Sent(_) => LogKind::ERC777_Sent,
+            Logs::ERC777_Minted(_) => LogKind::ERC777_Minted,
+            Logs::ERC777_Burned(_) => LogKind::ERC777_Burned,
+            Logs::ERC777_AuthorizedOperator(_) => LogKind::ERC777_AuthorizedOperator,
+            Logs::ERC777_RevokedOperator(_) => LogKind::ERC777_RevokedOperator,
+
+            Logs::ERC1155_TransferSingle(_) => LogKind::ERC1155_TransferSingle,
+            Logs::ERC1155_TransferBatch(_) => LogKind::ERC1155_TransferBatch,
+            Logs::ERC1155_URI(_) => LogKind::ERC1155_URI,
+
+            Logs::ERC4626_Deposit(_) => LogKind::ERC4626_Deposit,
+            Logs::ERC4626_Withdraw(_) => LogKind::ERC4626_Withdraw,
+
+            Logs::UniswapV2_Swap(_) => LogKind::UniswapV2_Swap,
+            Logs::UniswapV2_Mint(_) => LogKind::UniswapV2_Mint,
+            Logs::UniswapV2_Burn(_) => LogKind::UniswapV2_Burn,
+            Logs::UniswapV2_Sync(_) => LogKind::UniswapV2_Sync,
+
+            Logs::UniswapV3_Swap(_) => LogKind::UniswapV3_Swap,
+            Logs::UniswapV3_Mint(_) => LogKind::UniswapV3_Mint,
+            Logs::UniswapV3_Burn(_) => LogKind::UniswapV3_Burn,
+            Logs::UniswapV3_Collect(_) => LogKind::UniswapV3_Collect,
+            Logs::UniswapV3_Initialize(_) => LogKind::UniswapV3_Initialize,
+
+            Logs::WETH_Deposit(_) => LogKind::WETH_Deposit,
+            Logs::WETH_Withdrawal(_) => LogKind::WETH_Withdrawal,
+
+            Logs::EIP1967_Upgraded(_) => LogKind::EIP1967_Upgraded,
+            Logs::EIP1967_AdminChanged(_) => LogKind::EIP1967_AdminChanged,
+            Logs::EIP1967_BeaconUpgraded(_) => LogKind::EIP1967_BeaconUpgraded,
+
+            Logs::Ownable_OwnershipTransferred(_) => LogKind::Ownable_OwnershipTransferred,
+
+            Logs::AccessControl_RoleGranted(_) => LogKind::AccessControl_RoleGranted,
+            Logs::AccessControl_RoleRevoked(_) => LogKind::AccessControl_RoleRevoked,
+            Logs::AccessControl_RoleAdminChanged(_) => LogKind::AccessControl_RoleAdminChanged,
+
+            Logs::EntryPoint_UserOperationEvent(_) => LogKind::EntryPoint_UserOperationEvent,
+            Logs::EntryPoint_AccountDeployed(_) => LogKind::EntryPoint_AccountDeployed,
+            Logs::EntryPoint_UserOperationRevertReason(_) => {
+                LogKind::EntryPoint_UserOperationRevertReason
+            }
+            Logs::EntryPoint_PostOpRevertReason(_) => LogKind::EntryPoint_PostOpRevertReason,
+            Logs::EntryPoint_BeforeExecution(_) => LogKind::EntryPoint_BeforeExecution,
+
+            Logs::ERC4906_MetadataUpdate(_) => LogKind::ERC4906_MetadataUpdate,
+            Logs::ERC4906_BatchMetadataUpdate(_) => LogKind::ERC4906_BatchMetadataUpdate,
+
+            Logs::ERC5192_Locked(_) => LogKind::ERC5192_Locked,
+            Logs::ERC5192_Unlocked(_) => LogKind::ERC5192_Unlocked,
+
+            Logs::ERC6551Registry_ERC6551AccountCreated(_) => {
+                LogKind::ERC6551Registry_ERC6551AccountCreated
+            }
+
+            Logs::Permit2_Approval(_) => LogKind::Permit2_Approval,
+            Logs::Permit2_Permit(_) => LogKind::Permit2_Permit,
+            Logs::Permit2_Lockdown(_) => LogKind::Permit2_Lockdown,
+            Logs::Permit2_NonceInvalidation(_) => LogKind::Permit2_NonceInvalidation,
+
+            Logs::Safe_ExecutionSuccess(_) => LogKind::Safe_ExecutionSuccess,
+            Logs::Safe_ExecutionFailure(_) => LogKind::Safe_ExecutionFailure,
+            Logs::Safe_AddedOwner(_) => LogKind::Safe_AddedOwner,
+            Logs::Safe_RemovedOwner(_) => LogKind::Safe_RemovedOwner,
+            Logs::Safe_ChangedThreshold(_) => LogKind::Safe_ChangedThreshold,
+            Logs::Safe_SafeReceived(_) => LogKind::Safe_SafeReceived,
+            Logs::Safe_ProxyCreation(_) => LogKind::Safe_ProxyCreation,
+
+            Logs::Chainlink_AnswerUpdated(_) => LogKind::Chainlink_AnswerUpdated,
+            Logs::Chainlink_NewRound(_) => LogKind::Chainlink_NewRound,
+
+            Logs::Decoded(_) => LogKind::Decoded,
+        }
+    }
+}
+
+impl LogKind {
+    /// Whether the event is also pushed to the `<network>:alert` channel, for changes of who
+    /// controls a contract.
+    pub fn is_alert(&self) -> bool {
+        matches!(
+            self,
+            LogKind::EIP1967_Upgraded
+                | LogKind::EIP1967_AdminChanged
+                | LogKind::EIP1967_BeaconUpgraded
+                | LogKind::Ownable_OwnershipTransferred
+                | LogKind::AccessControl_RoleGranted
+                | LogKind::AccessControl_RoleRevoked
+                | LogKind::AccessControl_RoleAdminChanged
+                | LogKind::Safe_AddedOwner
+                | LogKind::Safe_RemovedOwner
+                | LogKind::Safe_ChangedThreshold
+        )
+    }
 }
 
 impl std::fmt::Display for LogKind {
@@ -320,6 +774,72 @@ impl std::fmt::Display for LogKind {
 
             LogKind::ERC4626_Deposit => write!(f, "log_erc4626_deposit"),
             LogKind::ERC4626_Withdraw => write!(f, "log_erc4626_withdraw"),
+
+            LogKind::UniswapV2_Swap => write!(f, "log_uniswap_v2_swap"),
+            LogKind::UniswapV2_Mint => write!(f, "log_uniswap_v2_mint"),
+            LogKind::UniswapV2_Burn => write!(f, "log_uniswap_v2_burn"),
+            LogKind::UniswapV2_Sync => write!(f, "log_uniswap_v2_sync"),
+
+            LogKind::UniswapV3_Swap => write!(f, "log_uniswap_v3_swap"),
+            LogKind::UniswapV3_Mint => write!(f, "log_uniswap_v3_mint"),
+            LogKind::UniswapV3_Burn => write!(f, "log_uniswap_v3_burn"),
+            LogKind::UniswapV3_Collect => write!(f, "log_uniswap_v3_collect"),
+            LogKind::UniswapV3_Initialize => write!(f, "log_uniswap_v3_initialize"),
+
+            LogKind::WETH_Deposit => write!(f, "log_weth_deposit"),
+            LogKind::WETH_Withdrawal => write!(f, "log_weth_withdrawal"),
+
+            LogKind::EIP1967_Upgraded => write!(f, "log_eip1967_upgraded"),
+            LogKind::EIP1967_AdminChanged => write!(f, "log_eip1967_admin_changed"),
+            LogKind::EIP1967_BeaconUpgraded => write!(f, "log_eip1967_beacon_upgraded"),
+
+            LogKind::Ownable_OwnershipTransferred => write!(f, "log_ownable_ownership_transferred"),
+
+            LogKind::AccessControl_RoleGranted => write!(f, "log_access_control_role_granted"),
+            LogKind::AccessControl_RoleRevoked => write!(f, "log_access_control_role_revoked"),
+            LogKind::AccessControl_RoleAdminChanged => {
+                write!(f, "log_access_control_role_admin_changed")
+            }
+
+            LogKind::EntryPoint_UserOperationEvent => {
+                write!(f, "log_entry_point_user_operation_event")
+            }
+            LogKind::EntryPoint_AccountDeployed => write!(f, "log_entry_point_account_deployed"),
+            LogKind::EntryPoint_UserOperationRevertReason => {
+                write!(f, "log_entry_point_user_operation_revert_reason")
+            }
+            LogKind::EntryPoint_PostOpRevertReason => {
+                write!(f, "log_entry_point_post_op_revert_reason")
+            }
+            LogKind::EntryPoint_BeforeExecution => write!(f, "log_entry_point_before_execution"),
+
+            LogKind::ERC4906_MetadataUpdate => write!(f, "log_erc4906_metadata_update"),
+            LogKind::ERC4906_BatchMetadataUpdate => write!(f, "log_erc4906_batch_metadata_update"),
+
+            LogKind::ERC5192_Locked => write!(f, "log_erc5192_locked"),
+            LogKind::ERC5192_Unlocked => write!(f, "log_erc5192_unlocked"),
+
+            LogKind::ERC6551Registry_ERC6551AccountCreated => {
+                write!(f, "log_erc6551_account_created")
+            }
+
+            LogKind::Permit2_Approval => write!(f, "log_permit2_approval"),
+            LogKind::Permit2_Permit => write!(f, "log_permit2_permit"),
+            LogKind::Permit2_Lockdown => write!(f, "log_permit2_lockdown"),
+            LogKind::Permit2_NonceInvalidation => write!(f, "log_permit2_nonce_invalidation"),
+
+            LogKind::Safe_ExecutionSuccess => write!(f, "log_safe_execution_success"),
+            LogKind::Safe_ExecutionFailure => write!(f, "log_safe_execution_failure"),
+            LogKind::Safe_AddedOwner => write!(f, "log_safe_added_owner"),
+            LogKind::Safe_RemovedOwner => write!(f, "log_safe_removed_owner"),
+            LogKind::Safe_ChangedThreshold => write!(f, "log_safe_changed_threshold"),
+            LogKind::Safe_SafeReceived => write!(f, "log_safe_received"),
+            LogKind::Safe_ProxyCreation => write!(f, "log_safe_proxy_creation"),
+
+            LogKind::Chainlink_AnswerUpdated => write!(f, "log_chainlink_answer_updated"),
+            LogKind::Chainlink_NewRound => write!(f, "log_chainlink_new_round"),
+
+            LogKind::Decoded => write!(f, "log_decoded"),
         }
     }
 }
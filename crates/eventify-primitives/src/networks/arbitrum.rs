@@ -1,5 +1,11 @@
 pub mod block;
 pub mod log;
+pub mod receipt;
 
 pub use block::ArbitrumBlock;
 pub use log::ArbitrumLog;
+pub use receipt::ArbitrumReceipt;
+
+use crate::networks::core::core_transaction;
+
+core_transaction!(ArbitrumTransaction, Arbitrum);
@@ -0,0 +1,157 @@
+use alloy_primitives::{B256, U256, U64};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, FromRow};
+use utoipa::ToSchema;
+
+use crate::{
+    networks::{core::CoreReceipt, NetworkKind, ResourceKind},
+    traits::{Emit, Insert, Receipt},
+    EmitError,
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct ArbitrumReceipt {
+    #[serde(flatten)]
+    core: CoreReceipt,
+
+    /// part of `gasUsed` spent on posting the transaction to L1
+    #[serde(rename = "gasUsedForL1")]
+    pub gas_used_for_l1: Option<U256>,
+
+    #[serde(rename = "l1BlockNumber")]
+    pub l1_block_number: Option<U64>,
+}
+
+impl Receipt for ArbitrumReceipt {
+    fn core(&self) -> &CoreReceipt {
+        &self.core
+    }
+}
+
+impl Insert for ArbitrumReceipt {
+    async fn insert(&self, pool: &sqlx::PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let (
+            tx_hash,
+            tx_index,
+            block_hash,
+            block_number,
+            from,
+            to,
+            cumulative_gas_used,
+            gas_used,
+            effective_gas_price,
+            contract_address,
+            tx_type,
+            status,
+            root,
+            blob_gas_used,
+            blob_gas_price,
+        ) = self.core().db_repr();
+
+        let gas_used_for_l1 = self.gas_used_for_l1.as_ref().map(|v| v.as_le_slice());
+        let l1_block_number = self.l1_block_number.map(|v| v.to::<i64>());
+
+        let sql = r#"INSERT INTO receipt (
+            network,
+            tx_hash,
+            tx_index,
+            block_hash,
+            block_number,
+            "from",
+            "to",
+            cumulative_gas_used,
+            gas_used,
+            effective_gas_price,
+            contract_address,
+            tx_type,
+            status,
+            root,
+            blob_gas_used,
+            blob_gas_price,
+
+            gas_used_for_l1,
+            l1_block_number
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(NetworkKind::Arbitrum)
+            .bind(tx_hash)
+            .bind(tx_index)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(from)
+            .bind(to)
+            .bind(cumulative_gas_used)
+            .bind(gas_used)
+            .bind(effective_gas_price)
+            .bind(contract_address)
+            .bind(tx_type)
+            .bind(status)
+            .bind(root)
+            .bind(blob_gas_used)
+            .bind(blob_gas_price)
+            .bind(gas_used_for_l1)
+            .bind(l1_block_number)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for ArbitrumReceipt {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Receipt);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_arbitrum_receipt() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x1775bda4d8d29f8c836bebb61c48c6e4b4c1a6f6cb5f754ccf34da48e37bbb0c",
+                "blockNumber": "0xb2c3d4e",
+                "contractAddress": null,
+                "cumulativeGasUsed": "0x1c9c38",
+                "effectiveGasPrice": "0x4a817c800",
+                "from": "0x57a949001df5821b7db54ea0dc54869ea8344e73",
+                "gasUsed": "0xb41d",
+                "logs": [],
+                "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "status": "0x1",
+                "to": "0xdaa4cb755eb7d65d26b9ab146541c083de4155ba",
+                "transactionHash": "0x89702e1f179446696ebce85f280e77e1df594f10b1bf280d98a134feb4644592",
+                "transactionIndex": "0x7",
+                "type": "0x2",
+                "gasUsedForL1": "0x1f4a",
+                "l1BlockNumber": "0x12e5a30"
+            }
+        );
+
+        assert!(serde_json::from_value::<ArbitrumReceipt>(json).is_ok());
+    }
+}
@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::AvalancheBlock;
 pub use log::AvalancheLog;
+
+use crate::networks::core::{core_receipt, core_transaction};
+
+core_receipt!(AvalancheReceipt, Avalanche);
+core_transaction!(AvalancheTransaction, Avalanche);
@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::BaseBlock;
 pub use log::BaseLog;
+
+use crate::networks::op_stack::{op_stack_receipt, op_stack_transaction};
+
+op_stack_receipt!(BaseReceipt, Base);
+op_stack_transaction!(BaseTransaction, Base);
@@ -50,4 +50,8 @@ impl Log for BaseLog {
     fn core(&self) -> &CoreLog {
         &self.core
     }
+
+    fn core_mut(&mut self) -> &mut CoreLog {
+        &mut self.core
+    }
 }
@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::BscBlock;
 pub use log::BscLog;
+
+use crate::networks::core::{core_receipt, core_transaction};
+
+core_receipt!(BscReceipt, Bsc);
+core_transaction!(BscTransaction, Bsc);
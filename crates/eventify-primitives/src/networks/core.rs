@@ -1,6 +1,6 @@
 use std::{fmt::Debug, hash::Hash};
 
-use alloy_primitives::{Address, Bytes, B256, B64, U256, U64};
+use alloy_primitives::{Address, Bytes, FixedBytes, B256, B64, U256, U64};
 use eyre::Result;
 use redis::AsyncCommands;
 use sqlx::{Error as SqlError, FromRow};
@@ -195,7 +195,8 @@ impl CoreBlock {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Block);
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -286,6 +287,9 @@ pub struct CoreLog {
     pub tx_index: Option<U64>,
     #[serde(rename = "transactionHash")]
     pub tx_hash: Option<B256>,
+    /// the event topic0 resolved to, not part of the node's response
+    #[serde(rename = "eventSignature", default)]
+    pub event_sig: Option<String>,
 }
 
 impl CoreLog {
@@ -302,6 +306,7 @@ impl CoreLog {
         let topic3 = self.topics.get(3).map(|v| v.as_slice());
         let tx_index = self.tx_index.map(|v| v.to::<i64>());
         let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let event_sig = self.event_sig.as_deref();
 
         let query = r#"
             INSERT INTO log (
@@ -317,10 +322,11 @@ impl CoreLog {
                 topic2,
                 topic3,
                 tx_index,
-                tx_hash
+                tx_hash,
+                event_sig
             )
             VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14
             ) ON CONFLICT DO NOTHING
         "#;
 
@@ -338,6 +344,7 @@ impl CoreLog {
             .bind(topic3)
             .bind(tx_index)
             .bind(tx_hash)
+            .bind(event_sig)
             .execute(pool)
             .await?;
 
@@ -352,7 +359,8 @@ impl CoreLog {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::Raw));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -373,6 +381,7 @@ impl CoreLog {
         Option<&[u8]>,
         Option<i64>,
         Option<&[u8]>,
+        Option<&str>,
     ) {
         let address = self.address.as_slice();
         let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
@@ -386,6 +395,7 @@ impl CoreLog {
         let topic3 = self.topics.get(3).map(|v| v.as_slice());
         let tx_index = self.tx_index.map(|v| v.to::<i64>());
         let tx_hash = self.tx_hash.as_ref().map(|v| v.as_slice());
+        let event_sig = self.event_sig.as_deref();
 
         (
             address,
@@ -400,10 +410,559 @@ impl CoreLog {
             topic3,
             tx_index,
             tx_hash,
+            event_sig,
+        )
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct AccessListItem {
+    pub address: Address,
+    #[serde(rename = "storageKeys")]
+    pub storage_keys: Vec<B256>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct CoreTransaction {
+    pub hash: B256,
+    pub nonce: U64,
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<B256>,
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<U64>,
+    #[serde(rename = "transactionIndex")]
+    pub tx_index: Option<U64>,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: U256,
+    #[serde(rename = "gasPrice")]
+    pub gas_price: Option<U256>,
+    pub gas: U256,
+    pub input: Bytes,
+    #[serde(rename = "type")]
+    pub tx_type: Option<U64>,
+    #[serde(rename = "chainId")]
+    pub chain_id: Option<U64>,
+    pub v: Option<U256>,
+    pub r: Option<U256>,
+    pub s: Option<U256>,
+
+    /// added by EIP-1559
+    #[serde(rename = "maxFeePerGas")]
+    pub max_fee_per_gas: Option<U256>,
+    /// added by EIP-1559
+    #[serde(rename = "maxPriorityFeePerGas")]
+    pub max_priority_fee_per_gas: Option<U256>,
+    /// added by EIP-2930
+    #[serde(rename = "accessList")]
+    pub access_list: Option<Vec<AccessListItem>>,
+
+    /// the function the input's selector resolved to, not part of the node's response
+    #[serde(rename = "functionSignature", default)]
+    pub fn_sig: Option<String>,
+}
+
+impl CoreTransaction {
+    /// The first 4 bytes of the input, identifying the called function.
+    pub fn selector(&self) -> Option<FixedBytes<4>> {
+        (self.input.len() >= 4).then(|| FixedBytes::from_slice(&self.input[..4]))
+    }
+
+    pub async fn insert(&self, pool: &sqlx::PgPool, network: NetworkKind) -> Result<(), SqlError> {
+        let (
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            from,
+            to,
+            value,
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
+        ) = self.db_repr();
+
+        let query = r#"
+            INSERT INTO transaction (
+                network,
+
+                hash,
+                nonce,
+                block_hash,
+                block_number,
+                tx_index,
+                "from",
+                "to",
+                "value",
+                gas_price,
+                gas,
+                input,
+                tx_type,
+                chain_id,
+                v,
+                r,
+                s,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                access_list,
+                fn_sig
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19,
+                $20::jsonb, $21
+            ) ON CONFLICT DO NOTHING
+        "#;
+
+        sqlx::query(query)
+            .bind(network)
+            .bind(hash)
+            .bind(nonce)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_index)
+            .bind(from)
+            .bind(to)
+            .bind(value)
+            .bind(gas_price)
+            .bind(gas)
+            .bind(input)
+            .bind(tx_type)
+            .bind(chain_id)
+            .bind(v)
+            .bind(r)
+            .bind(s)
+            .bind(max_fee_per_gas)
+            .bind(max_priority_fee_per_gas)
+            .bind(access_list)
+            .bind(fn_sig)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn emit(
+        &self,
+        queue: &redis::Client,
+        network: &NetworkKind,
+    ) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Transaction);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn db_repr(
+        &self,
+    ) -> (
+        &[u8],
+        i64,
+        Option<&[u8]>,
+        Option<i64>,
+        Option<i64>,
+        &[u8],
+        Option<&[u8]>,
+        &[u8],
+        Option<&[u8]>,
+        &[u8],
+        &[u8],
+        Option<i32>,
+        Option<i64>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<String>,
+        Option<&str>,
+    ) {
+        let hash = self.hash.as_slice();
+        let nonce = self.nonce.to::<i64>();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let tx_index = self.tx_index.map(|v| v.to::<i64>());
+        let from = self.from.as_slice();
+        let to = self.to.as_ref().map(|v| v.as_slice());
+        let value = self.value.as_le_slice();
+        let gas_price = self.gas_price.as_ref().map(|v| v.as_le_slice());
+        let gas = self.gas.as_le_slice();
+        let input = self.input.0.as_ref();
+        let tx_type = self.tx_type.map(|v| v.to::<i32>());
+        let chain_id = self.chain_id.map(|v| v.to::<i64>());
+        let v = self.v.as_ref().map(|v| v.as_le_slice());
+        let r = self.r.as_ref().map(|v| v.as_le_slice());
+        let s = self.s.as_ref().map(|v| v.as_le_slice());
+        let max_fee_per_gas = self.max_fee_per_gas.as_ref().map(|v| v.as_le_slice());
+        let max_priority_fee_per_gas = self
+            .max_priority_fee_per_gas
+            .as_ref()
+            .map(|v| v.as_le_slice());
+        let access_list = self
+            .access_list
+            .as_ref()
+            .and_then(|v| serde_json::to_string(v).ok());
+        let fn_sig = self.fn_sig.as_deref();
+
+        (
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            from,
+            to,
+            value,
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
         )
     }
 }
 
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct CoreReceipt {
+    #[serde(rename = "transactionHash")]
+    pub tx_hash: B256,
+    #[serde(rename = "transactionIndex")]
+    pub tx_index: U64,
+    #[serde(rename = "blockHash")]
+    pub block_hash: Option<B256>,
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<U64>,
+    pub from: Address,
+    pub to: Option<Address>,
+    #[serde(rename = "cumulativeGasUsed")]
+    pub cumulative_gas_used: U256,
+    #[serde(rename = "gasUsed")]
+    pub gas_used: Option<U256>,
+    #[serde(rename = "effectiveGasPrice")]
+    pub effective_gas_price: Option<U256>,
+    /// set when the transaction created a contract
+    #[serde(rename = "contractAddress")]
+    pub contract_address: Option<Address>,
+    #[serde(rename = "type")]
+    pub tx_type: Option<U64>,
+
+    /// added by EIP-658, `1` on success and `0` on failure
+    pub status: Option<U64>,
+    /// post-transaction state root, only set before EIP-658
+    pub root: Option<B256>,
+
+    /// added by EIP-4844
+    #[serde(rename = "blobGasUsed")]
+    pub blob_gas_used: Option<U256>,
+    /// added by EIP-4844
+    #[serde(rename = "blobGasPrice")]
+    pub blob_gas_price: Option<U256>,
+}
+
+impl CoreReceipt {
+    pub async fn insert(&self, pool: &sqlx::PgPool, network: NetworkKind) -> Result<(), SqlError> {
+        let (
+            tx_hash,
+            tx_index,
+            block_hash,
+            block_number,
+            from,
+            to,
+            cumulative_gas_used,
+            gas_used,
+            effective_gas_price,
+            contract_address,
+            tx_type,
+            status,
+            root,
+            blob_gas_used,
+            blob_gas_price,
+        ) = self.db_repr();
+
+        let query = r#"
+            INSERT INTO receipt (
+                network,
+
+                tx_hash,
+                tx_index,
+                block_hash,
+                block_number,
+                "from",
+                "to",
+                cumulative_gas_used,
+                gas_used,
+                effective_gas_price,
+                contract_address,
+                tx_type,
+                status,
+                root,
+                blob_gas_used,
+                blob_gas_price
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16
+            ) ON CONFLICT DO NOTHING
+        "#;
+
+        sqlx::query(query)
+            .bind(network)
+            .bind(tx_hash)
+            .bind(tx_index)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(from)
+            .bind(to)
+            .bind(cumulative_gas_used)
+            .bind(gas_used)
+            .bind(effective_gas_price)
+            .bind(contract_address)
+            .bind(tx_type)
+            .bind(status)
+            .bind(root)
+            .bind(blob_gas_used)
+            .bind(blob_gas_price)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn emit(
+        &self,
+        queue: &redis::Client,
+        network: &NetworkKind,
+    ) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Receipt);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Whether the transaction succeeded; unknown for receipts predating EIP-658.
+    pub fn succeeded(&self) -> Option<bool> {
+        self.status.map(|s| s == U64::from(1))
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn db_repr(
+        &self,
+    ) -> (
+        &[u8],
+        i64,
+        Option<&[u8]>,
+        Option<i64>,
+        &[u8],
+        Option<&[u8]>,
+        &[u8],
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<i32>,
+        Option<i32>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+        Option<&[u8]>,
+    ) {
+        let tx_hash = self.tx_hash.as_slice();
+        let tx_index = self.tx_index.to::<i64>();
+        let block_hash = self.block_hash.as_ref().map(|v| v.as_slice());
+        let block_number = self.block_number.map(|v| v.to::<i64>());
+        let from = self.from.as_slice();
+        let to = self.to.as_ref().map(|v| v.as_slice());
+        let cumulative_gas_used = self.cumulative_gas_used.as_le_slice();
+        let gas_used = self.gas_used.as_ref().map(|v| v.as_le_slice());
+        let effective_gas_price = self.effective_gas_price.as_ref().map(|v| v.as_le_slice());
+        let contract_address = self.contract_address.as_ref().map(|v| v.as_slice());
+        let tx_type = self.tx_type.map(|v| v.to::<i32>());
+        let status = self.status.map(|v| v.to::<i32>());
+        let root = self.root.as_ref().map(|v| v.as_slice());
+        let blob_gas_used = self.blob_gas_used.as_ref().map(|v| v.as_le_slice());
+        let blob_gas_price = self.blob_gas_price.as_ref().map(|v| v.as_le_slice());
+
+        (
+            tx_hash,
+            tx_index,
+            block_hash,
+            block_number,
+            from,
+            to,
+            cumulative_gas_used,
+            gas_used,
+            effective_gas_price,
+            contract_address,
+            tx_type,
+            status,
+            root,
+            blob_gas_used,
+            blob_gas_price,
+        )
+    }
+}
+
+/// Defines `$name`, the receipt of a network whose receipts carry no fields beyond
+/// [`CoreReceipt`], stored and emitted under `NetworkKind::$network`.
+macro_rules! core_receipt {
+    ($name:ident, $network:ident) => {
+        #[derive(
+            Clone,
+            Debug,
+            Default,
+            serde::Deserialize,
+            serde::Serialize,
+            PartialEq,
+            Eq,
+            Hash,
+            sqlx::FromRow,
+            utoipa::ToSchema,
+        )]
+        pub struct $name {
+            #[serde(flatten)]
+            core: $crate::networks::core::CoreReceipt,
+        }
+
+        impl $crate::traits::Receipt for $name {
+            fn core(&self) -> &$crate::networks::core::CoreReceipt {
+                &self.core
+            }
+        }
+
+        impl $crate::traits::Insert for $name {
+            async fn insert(
+                &self,
+                pool: &sqlx::PgPool,
+                _: &Option<alloy_primitives::B256>,
+            ) -> Result<(), sqlx::Error> {
+                self.core
+                    .insert(pool, $crate::networks::NetworkKind::$network)
+                    .await
+            }
+        }
+
+        impl $crate::traits::Emit for $name {
+            async fn emit(
+                &self,
+                queue: &redis::Client,
+                network: &$crate::networks::NetworkKind,
+            ) -> Result<(), $crate::EmitError> {
+                self.core.emit(queue, network).await
+            }
+        }
+    };
+}
+pub(crate) use core_receipt;
+
+/// Defines `$name`, the transaction of a network whose transactions carry no fields beyond
+/// [`CoreTransaction`], stored and emitted under `NetworkKind::$network`.
+macro_rules! core_transaction {
+    ($name:ident, $network:ident) => {
+        #[derive(
+            Clone,
+            Debug,
+            Default,
+            serde::Deserialize,
+            serde::Serialize,
+            PartialEq,
+            Eq,
+            Hash,
+            sqlx::FromRow,
+            utoipa::ToSchema,
+        )]
+        pub struct $name {
+            #[serde(flatten)]
+            core: $crate::networks::core::CoreTransaction,
+        }
+
+        impl $crate::traits::Transaction for $name {
+            fn core(&self) -> &$crate::networks::core::CoreTransaction {
+                &self.core
+            }
+
+            fn core_mut(&mut self) -> &mut $crate::networks::core::CoreTransaction {
+                &mut self.core
+            }
+        }
+
+        impl $crate::traits::Insert for $name {
+            async fn insert(
+                &self,
+                pool: &sqlx::PgPool,
+                _: &Option<alloy_primitives::B256>,
+            ) -> Result<(), sqlx::Error> {
+                self.core
+                    .insert(pool, $crate::networks::NetworkKind::$network)
+                    .await
+            }
+        }
+
+        impl $crate::traits::Emit for $name {
+            async fn emit(
+                &self,
+                queue: &redis::Client,
+                network: &$crate::networks::NetworkKind,
+            ) -> Result<(), $crate::EmitError> {
+                self.core.emit(queue, network).await
+            }
+        }
+    };
+}
+pub(crate) use core_transaction;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -456,4 +1015,59 @@ mod tests {
         assert!(serde_json::from_value::<CoreLog>(json).is_ok());
         assert!(serde_json::from_value::<CoreLog>(serde_json::json!({})).is_err());
     }
+
+    #[test]
+    fn deserialize_core_transaction() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x1d59ff54b1eb26b013ce3cb5fc9dab3705b415a67127a003c3e61eb445bb8df2",
+                "blockNumber": "0x5daf3b",
+                "from": "0xa7d9ddbe1f17865597fbd27ec712455208b6b76d",
+                "gas": "0xc350",
+                "gasPrice": "0x4a817c800",
+                "hash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+                "input": "0x68656c6c6f21",
+                "nonce": "0x15",
+                "to": "0xf02c1c8e6114b1dbe8937a39260b5b0a374432bb",
+                "transactionIndex": "0x41",
+                "value": "0xf3dbb76162000",
+                "type": "0x0",
+                "v": "0x25",
+                "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+                "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c"
+            }
+        );
+
+        let tx = serde_json::from_value::<CoreTransaction>(json).unwrap();
+        assert_eq!(tx.nonce, U64::from(0x15));
+        assert!(tx.max_fee_per_gas.is_none());
+        assert!(serde_json::from_value::<CoreTransaction>(serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn deserialize_core_receipt() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0xa957d47df264a31badc3ae823e10ac1d444b098d9b73d204c40426e57f47e8c3",
+                "blockNumber": "0xeff35f",
+                "contractAddress": null,
+                "cumulativeGasUsed": "0xa12515",
+                "effectiveGasPrice": "0x5a9c688d4",
+                "from": "0x6221a9c005f6e47eb398fd867784cacfdcfff4e7",
+                "gasUsed": "0xb4c8",
+                "logs": [],
+                "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "status": "0x1",
+                "to": "0xa7d9ddbe1f17865597fbd27ec712455208b6b76d",
+                "transactionHash": "0x85d995eba9763907fdf35cd2034144dd9d53ce32cbec21349d4b12823c6860c5",
+                "transactionIndex": "0x66",
+                "type": "0x2"
+            }
+        );
+
+        let receipt = serde_json::from_value::<CoreReceipt>(json).unwrap();
+        assert_eq!(receipt.succeeded(), Some(true));
+        assert!(receipt.blob_gas_used.is_none());
+        assert!(serde_json::from_value::<CoreReceipt>(serde_json::json!({})).is_err());
+    }
 }
@@ -1,5 +1,11 @@
 pub mod block;
 pub mod log;
+pub mod transaction;
 
 pub use block::EthBlock;
 pub use log::EthLog;
+pub use transaction::EthTransaction;
+
+use crate::networks::core::core_receipt;
+
+core_receipt!(EthReceipt, Ethereum);
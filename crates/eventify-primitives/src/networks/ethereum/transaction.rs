@@ -0,0 +1,189 @@
+use alloy_primitives::{B256, U256};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, FromRow};
+use utoipa::ToSchema;
+
+use crate::{
+    networks::{core::CoreTransaction, NetworkKind, ResourceKind},
+    traits::{Emit, Insert, Transaction},
+    EmitError,
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct EthTransaction {
+    #[serde(flatten)]
+    core: CoreTransaction,
+
+    /// added by EIP-4844
+    #[serde(rename = "maxFeePerBlobGas")]
+    pub max_fee_per_blob_gas: Option<U256>,
+
+    /// added by EIP-4844
+    #[serde(rename = "blobVersionedHashes")]
+    pub blob_versioned_hashes: Option<Vec<B256>>,
+}
+
+impl Transaction for EthTransaction {
+    fn core(&self) -> &CoreTransaction {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut CoreTransaction {
+        &mut self.core
+    }
+}
+
+impl Insert for EthTransaction {
+    async fn insert(&self, pool: &sqlx::PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let (
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            from,
+            to,
+            value,
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
+        ) = self.core().db_repr();
+
+        let max_fee_per_blob_gas = self.max_fee_per_blob_gas.as_ref().map(|v| v.as_le_slice());
+        let blob_versioned_hashes = self
+            .blob_versioned_hashes
+            .as_ref()
+            .map(|v| v.iter().map(|h| h.to_vec()).collect::<Vec<_>>());
+
+        let sql = r#"INSERT INTO transaction (
+            network,
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            "from",
+            "to",
+            "value",
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
+
+            max_fee_per_blob_gas,
+            blob_versioned_hashes
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                $19, $20::jsonb, $21, $22, $23
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(NetworkKind::Ethereum)
+            .bind(hash)
+            .bind(nonce)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_index)
+            .bind(from)
+            .bind(to)
+            .bind(value)
+            .bind(gas_price)
+            .bind(gas)
+            .bind(input)
+            .bind(tx_type)
+            .bind(chain_id)
+            .bind(v)
+            .bind(r)
+            .bind(s)
+            .bind(max_fee_per_gas)
+            .bind(max_priority_fee_per_gas)
+            .bind(access_list)
+            .bind(fn_sig)
+            .bind(max_fee_per_blob_gas)
+            .bind(blob_versioned_hashes)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for EthTransaction {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Transaction);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_eth_transaction() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x2d8b0b8b1bf0a1b3ba1bd0b3d0c8e1d1a2a4cdc1f7a0a7f60c0a1d2b3c4d5e6f",
+                "blockNumber": "0x12a05f2",
+                "from": "0xc1b634853cb333d3ad8663715b08f41a3aec47cc",
+                "gas": "0x5208",
+                "gasPrice": "0x2e90edd000",
+                "maxFeePerGas": "0x2e90edd000",
+                "maxPriorityFeePerGas": "0x3b9aca00",
+                "maxFeePerBlobGas": "0x3b9aca00",
+                "hash": "0x7c2b7b3d4c5a1e1f2d3c4b5a69788796a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0",
+                "input": "0x",
+                "nonce": "0x4e2a",
+                "to": "0xff00000000000000000000000000000000000010",
+                "transactionIndex": "0x5",
+                "value": "0x0",
+                "type": "0x3",
+                "accessList": [],
+                "chainId": "0x1",
+                "blobVersionedHashes": [
+                    "0x01a8a1d2c9d6f0d5b8b1c7e4e4c0d2b6f2a2c2b3a4f5e6d7c8b9a0f1e2d3c4b5"
+                ],
+                "v": "0x0",
+                "r": "0x5d1e3c1a0f7b9c2e4d6a8b0c2e4f6a8b0c2d4e6f8a0b2c4d6e8f0a2b4c6d8e0f",
+                "s": "0x3a5c7e9f1b3d5f7a9c1e3f5a7c9e1b3d5f7a9c1e3f5a7c9e1b3d5f7a9c1e3f5a",
+                "yParity": "0x0"
+            }
+        );
+
+        assert!(serde_json::from_value::<EthTransaction>(json).is_ok());
+    }
+}
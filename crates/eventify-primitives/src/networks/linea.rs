@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::LineaBlock;
 pub use log::LineaLog;
+
+use crate::networks::core::{core_receipt, core_transaction};
+
+core_receipt!(LineaReceipt, Linea);
+core_transaction!(LineaTransaction, Linea);
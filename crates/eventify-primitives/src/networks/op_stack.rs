@@ -0,0 +1,362 @@
+//! Receipts and transactions of the OP-stack networks (Optimism, Base), which share the L1 fee
+//! fields of receipts and the deposit fields of transactions.
+
+/// Defines `$name`, the receipt of an OP-stack network, stored under `NetworkKind::$network`.
+macro_rules! op_stack_receipt {
+    ($name:ident, $network:ident) => {
+        #[derive(
+            Clone,
+            Debug,
+            Default,
+            serde::Deserialize,
+            serde::Serialize,
+            PartialEq,
+            Eq,
+            Hash,
+            sqlx::FromRow,
+            utoipa::ToSchema,
+        )]
+        pub struct $name {
+            #[serde(flatten)]
+            core: $crate::networks::core::CoreReceipt,
+
+            /// fee paid for posting the transaction's data to L1
+            #[serde(rename = "l1Fee")]
+            pub l1_fee: Option<alloy_primitives::U256>,
+
+            #[serde(rename = "l1GasUsed")]
+            pub l1_gas_used: Option<alloy_primitives::U256>,
+
+            #[serde(rename = "l1GasPrice")]
+            pub l1_gas_price: Option<alloy_primitives::U256>,
+        }
+
+        impl $crate::traits::Receipt for $name {
+            fn core(&self) -> &$crate::networks::core::CoreReceipt {
+                &self.core
+            }
+        }
+
+        impl $crate::traits::Insert for $name {
+            async fn insert(
+                &self,
+                pool: &sqlx::PgPool,
+                _: &Option<alloy_primitives::B256>,
+            ) -> Result<(), sqlx::Error> {
+                let (
+                    tx_hash,
+                    tx_index,
+                    block_hash,
+                    block_number,
+                    from,
+                    to,
+                    cumulative_gas_used,
+                    gas_used,
+                    effective_gas_price,
+                    contract_address,
+                    tx_type,
+                    status,
+                    root,
+                    blob_gas_used,
+                    blob_gas_price,
+                ) = self.core.db_repr();
+
+                let l1_fee = self.l1_fee.as_ref().map(|v| v.as_le_slice());
+                let l1_gas_used = self.l1_gas_used.as_ref().map(|v| v.as_le_slice());
+                let l1_gas_price = self.l1_gas_price.as_ref().map(|v| v.as_le_slice());
+
+                let sql = r#"INSERT INTO receipt (
+                    network,
+                    tx_hash,
+                    tx_index,
+                    block_hash,
+                    block_number,
+                    "from",
+                    "to",
+                    cumulative_gas_used,
+                    gas_used,
+                    effective_gas_price,
+                    contract_address,
+                    tx_type,
+                    status,
+                    root,
+                    blob_gas_used,
+                    blob_gas_price,
+
+                    l1_fee,
+                    l1_gas_used,
+                    l1_gas_price
+                    ) VALUES (
+                        $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19
+                    ) ON CONFLICT DO NOTHING"#;
+
+                sqlx::query(sql)
+                    .bind($crate::networks::NetworkKind::$network)
+                    .bind(tx_hash)
+                    .bind(tx_index)
+                    .bind(block_hash)
+                    .bind(block_number)
+                    .bind(from)
+                    .bind(to)
+                    .bind(cumulative_gas_used)
+                    .bind(gas_used)
+                    .bind(effective_gas_price)
+                    .bind(contract_address)
+                    .bind(tx_type)
+                    .bind(status)
+                    .bind(root)
+                    .bind(blob_gas_used)
+                    .bind(blob_gas_price)
+                    .bind(l1_fee)
+                    .bind(l1_gas_used)
+                    .bind(l1_gas_price)
+                    .execute(pool)
+                    .await?;
+
+                Ok(())
+            }
+        }
+
+        impl $crate::traits::Emit for $name {
+            async fn emit(
+                &self,
+                queue: &redis::Client,
+                network: &$crate::networks::NetworkKind,
+            ) -> Result<(), $crate::EmitError> {
+                let mut con = queue.get_async_connection().await?;
+
+                let channel = format!("{}:{}", network, $crate::networks::ResourceKind::Receipt);
+                let item = serde_json::to_string(self)?;
+                redis::AsyncCommands::lpush::<_, _, ()>(&mut con, channel, item).await?;
+
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use op_stack_receipt;
+
+/// Defines `$name`, the transaction of an OP-stack network, stored under `NetworkKind::$network`.
+macro_rules! op_stack_transaction {
+    ($name:ident, $network:ident) => {
+        #[derive(
+            Clone,
+            Debug,
+            Default,
+            serde::Deserialize,
+            serde::Serialize,
+            PartialEq,
+            Eq,
+            Hash,
+            sqlx::FromRow,
+            utoipa::ToSchema,
+        )]
+        pub struct $name {
+            #[serde(flatten)]
+            core: $crate::networks::core::CoreTransaction,
+
+            /// set on deposit (0x7e) transactions only
+            #[serde(rename = "sourceHash")]
+            pub source_hash: Option<alloy_primitives::B256>,
+
+            /// set on deposit (0x7e) transactions only
+            pub mint: Option<alloy_primitives::U256>,
+
+            /// set on deposit (0x7e) transactions only
+            #[serde(rename = "isSystemTx")]
+            pub is_system_tx: Option<bool>,
+        }
+
+        impl $crate::traits::Transaction for $name {
+            fn core(&self) -> &$crate::networks::core::CoreTransaction {
+                &self.core
+            }
+
+            fn core_mut(&mut self) -> &mut $crate::networks::core::CoreTransaction {
+                &mut self.core
+            }
+        }
+
+        impl $crate::traits::Insert for $name {
+            async fn insert(
+                &self,
+                pool: &sqlx::PgPool,
+                _: &Option<alloy_primitives::B256>,
+            ) -> Result<(), sqlx::Error> {
+                let (
+                    hash,
+                    nonce,
+                    block_hash,
+                    block_number,
+                    tx_index,
+                    from,
+                    to,
+                    value,
+                    gas_price,
+                    gas,
+                    input,
+                    tx_type,
+                    chain_id,
+                    v,
+                    r,
+                    s,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    access_list,
+                    fn_sig,
+                ) = self.core.db_repr();
+
+                let source_hash = self.source_hash.as_ref().map(|v| v.as_slice());
+                let mint = self.mint.as_ref().map(|v| v.as_le_slice());
+                let is_system_tx = self.is_system_tx;
+
+                let sql = r#"INSERT INTO transaction (
+                    network,
+                    hash,
+                    nonce,
+                    block_hash,
+                    block_number,
+                    tx_index,
+                    "from",
+                    "to",
+                    "value",
+                    gas_price,
+                    gas,
+                    input,
+                    tx_type,
+                    chain_id,
+                    v,
+                    r,
+                    s,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    access_list,
+                    fn_sig,
+
+                    source_hash,
+                    mint,
+                    is_system_tx
+                    ) VALUES (
+                        $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                        $19, $20::jsonb, $21, $22, $23, $24
+                    ) ON CONFLICT DO NOTHING"#;
+
+                sqlx::query(sql)
+                    .bind($crate::networks::NetworkKind::$network)
+                    .bind(hash)
+                    .bind(nonce)
+                    .bind(block_hash)
+                    .bind(block_number)
+                    .bind(tx_index)
+                    .bind(from)
+                    .bind(to)
+                    .bind(value)
+                    .bind(gas_price)
+                    .bind(gas)
+                    .bind(input)
+                    .bind(tx_type)
+                    .bind(chain_id)
+                    .bind(v)
+                    .bind(r)
+                    .bind(s)
+                    .bind(max_fee_per_gas)
+                    .bind(max_priority_fee_per_gas)
+                    .bind(access_list)
+                    .bind(fn_sig)
+                    .bind(source_hash)
+                    .bind(mint)
+                    .bind(is_system_tx)
+                    .execute(pool)
+                    .await?;
+
+                Ok(())
+            }
+        }
+
+        impl $crate::traits::Emit for $name {
+            async fn emit(
+                &self,
+                queue: &redis::Client,
+                network: &$crate::networks::NetworkKind,
+            ) -> Result<(), $crate::EmitError> {
+                let mut con = queue.get_async_connection().await?;
+
+                let channel = format!(
+                    "{}:{}",
+                    network,
+                    $crate::networks::ResourceKind::Transaction
+                );
+                let item = serde_json::to_string(self)?;
+                redis::AsyncCommands::lpush::<_, _, ()>(&mut con, channel, item).await?;
+
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use op_stack_transaction;
+
+#[cfg(test)]
+mod tests {
+    use crate::networks::optimism::{OptimismReceipt, OptimismTransaction};
+
+    #[test]
+    fn deserialize_op_stack_receipt() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x21146d4317552adfdf24d07770b535a5336763ef4edbfb7870453f5f18735ad3",
+                "blockNumber": "0x6f1c2a8",
+                "contractAddress": null,
+                "cumulativeGasUsed": "0x1c9c38",
+                "effectiveGasPrice": "0x4a817c800",
+                "from": "0x0e8b977142674ccaffe21563493312f2de8e7321",
+                "gasUsed": "0xb41d",
+                "logs": [],
+                "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "status": "0x1",
+                "to": "0xe3a32c4b2ba5ad4418ce171c344a44538153b29e",
+                "transactionHash": "0x499ca3745775da57b9d4217cfc11dadfafd2d4dbddd05ade778c5ca0c3a3a439",
+                "transactionIndex": "0x7",
+                "type": "0x2",
+                "l1Fee": "0x1d0f9b8e3a4",
+                "l1GasUsed": "0x640",
+                "l1GasPrice": "0x3b9aca07"
+            }
+        );
+
+        let receipt = serde_json::from_value::<OptimismReceipt>(json).unwrap();
+        assert!(receipt.l1_fee.is_some());
+        assert!(receipt.l1_gas_used.is_some());
+    }
+
+    #[test]
+    fn deserialize_op_stack_deposit_transaction() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b",
+                "blockNumber": "0x6f8a1c2",
+                "from": "0xdeaddeaddeaddeaddeaddeaddeaddeaddead0001",
+                "gas": "0xf4240",
+                "gasPrice": "0x0",
+                "hash": "0x3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f",
+                "input": "0x440a5e20",
+                "mint": "0x0",
+                "nonce": "0x6f8a1c1",
+                "sourceHash": "0x6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c",
+                "to": "0x4200000000000000000000000000000000000015",
+                "transactionIndex": "0x0",
+                "value": "0x0",
+                "type": "0x7e",
+                "isSystemTx": false,
+                "chainId": "0xa",
+                "v": "0x0",
+                "r": "0x0",
+                "s": "0x0"
+            }
+        );
+
+        let tx = serde_json::from_value::<OptimismTransaction>(json).unwrap();
+        assert_eq!(tx.is_system_tx, Some(false));
+        assert!(tx.source_hash.is_some());
+    }
+}
@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::OptimismBlock;
 pub use log::OptimismLog;
+
+use crate::networks::op_stack::{op_stack_receipt, op_stack_transaction};
+
+op_stack_receipt!(OptimismReceipt, Optimism);
+op_stack_transaction!(OptimismTransaction, Optimism);
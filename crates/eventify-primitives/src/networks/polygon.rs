@@ -3,3 +3,8 @@ pub mod log;
 
 pub use block::PolygonBlock;
 pub use log::PolygonLog;
+
+use crate::networks::core::{core_receipt, core_transaction};
+
+core_receipt!(PolygonReceipt, Polygon);
+core_transaction!(PolygonTransaction, Polygon);
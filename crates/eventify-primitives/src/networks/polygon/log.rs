@@ -50,6 +50,10 @@ impl Log for PolygonLog {
     fn core(&self) -> &CoreLog {
         &self.core
     }
+
+    fn core_mut(&mut self) -> &mut CoreLog {
+        &mut self.core
+    }
 }
 
 #[cfg(test)]
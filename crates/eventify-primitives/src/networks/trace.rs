@@ -0,0 +1,437 @@
+use alloy_primitives::{Address, Bytes, FixedBytes, B256, U256, U64};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::Error as SqlError;
+use utoipa::ToSchema;
+
+use crate::{
+    networks::{NetworkKind, ResourceKind},
+    traits::{Emit, Insert},
+    EmitError,
+};
+
+/// A single frame of the `callTracer` output, as returned by `debug_traceBlockByNumber`.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash)]
+pub struct CallFrame {
+    #[serde(rename = "type")]
+    pub call_type: String,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: Option<U256>,
+    pub gas: Option<U256>,
+    #[serde(rename = "gasUsed")]
+    pub gas_used: Option<U256>,
+    #[serde(default)]
+    pub input: Bytes,
+    pub output: Option<Bytes>,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub calls: Vec<CallFrame>,
+}
+
+/// The trace of a single transaction in the `debug_traceBlockByNumber` response; older nodes
+/// leave out the `txHash`, in which case it's implied by the position in the block.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash)]
+pub struct BlockCallTrace {
+    #[serde(rename = "txHash")]
+    pub tx_hash: Option<B256>,
+    pub result: Option<CallFrame>,
+    pub error: Option<String>,
+}
+
+/// A single entry of the `trace_block` response served by Erigon/OpenEthereum-style nodes.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash)]
+pub struct ParityTrace {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub action: ParityAction,
+    pub result: Option<ParityResult>,
+    pub error: Option<String>,
+    #[serde(rename = "traceAddress")]
+    pub trace_address: Vec<u32>,
+    #[serde(rename = "transactionHash")]
+    pub tx_hash: Option<B256>,
+    #[serde(rename = "blockNumber")]
+    pub block_number: u64,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash)]
+pub struct ParityAction {
+    /// set on `call` traces
+    #[serde(rename = "callType")]
+    pub call_type: Option<String>,
+    pub from: Option<Address>,
+    pub to: Option<Address>,
+    pub value: Option<U256>,
+    pub gas: Option<U256>,
+    pub input: Option<Bytes>,
+
+    /// set on `suicide` traces
+    pub address: Option<Address>,
+    /// set on `suicide` traces
+    #[serde(rename = "refundAddress")]
+    pub refund_address: Option<Address>,
+    /// set on `suicide` traces
+    pub balance: Option<U256>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash)]
+pub struct ParityResult {
+    #[serde(rename = "gasUsed")]
+    pub gas_used: Option<U256>,
+    /// set on `create` traces
+    pub address: Option<Address>,
+}
+
+/// A single call of a transaction's call tree, flattened.
+///
+/// `trace_address` is the path of the call from the top-level one, i.e. `[]` is the transaction
+/// itself and `[1, 0]` the first call made by its second subcall.
+#[derive(
+    Clone, Debug, Default, serde::Deserialize, serde::Serialize, PartialEq, Eq, Hash, ToSchema,
+)]
+pub struct Trace {
+    pub network: NetworkKind,
+    #[serde(rename = "blockNumber")]
+    pub block_number: U64,
+    #[serde(rename = "transactionHash")]
+    pub tx_hash: B256,
+    #[serde(rename = "traceAddress")]
+    pub trace_address: Vec<u32>,
+    #[serde(rename = "callType")]
+    pub call_type: String,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: Option<U256>,
+    pub gas: Option<U256>,
+    #[serde(rename = "gasUsed")]
+    pub gas_used: Option<U256>,
+    /// first 4 bytes of the calldata, unset for contract creations
+    pub selector: Option<FixedBytes<4>>,
+    pub error: Option<String>,
+}
+
+impl Trace {
+    /// Walks the call tree of a transaction depth-first, one trace per call.
+    pub fn flatten(
+        network: NetworkKind,
+        block_number: U64,
+        tx_hash: B256,
+        frame: &CallFrame,
+    ) -> Vec<Trace> {
+        let mut traces = vec![];
+        let mut stack = vec![(vec![], frame)];
+
+        while let Some((trace_address, frame)) = stack.pop() {
+            for (i, call) in frame.calls.iter().enumerate().rev() {
+                let mut address = trace_address.clone();
+                address.push(i as u32);
+                stack.push((address, call));
+            }
+
+            let call_type = frame.call_type.to_uppercase();
+            traces.push(Trace {
+                network,
+                block_number,
+                tx_hash,
+                selector: selector(&call_type, &frame.input),
+                call_type,
+                trace_address,
+                from: frame.from,
+                to: frame.to,
+                value: frame.value,
+                gas: frame.gas,
+                gas_used: frame.gas_used,
+                error: frame.error.clone(),
+            });
+        }
+
+        traces
+    }
+
+    /// `None` for the traces not belonging to a transaction, i.e. block and uncle rewards.
+    pub fn from_parity(network: NetworkKind, trace: &ParityTrace) -> Option<Trace> {
+        let tx_hash = trace.tx_hash?;
+        let action = &trace.action;
+        let result = trace.result.as_ref();
+
+        let (call_type, from, to, value) = match trace.kind.as_str() {
+            "call" => (
+                action.call_type.as_deref().unwrap_or("call").to_uppercase(),
+                action.from?,
+                action.to,
+                action.value,
+            ),
+            "create" => (
+                "CREATE".to_string(),
+                action.from?,
+                result.and_then(|r| r.address),
+                action.value,
+            ),
+            "suicide" => (
+                "SELFDESTRUCT".to_string(),
+                action.address?,
+                action.refund_address,
+                action.balance,
+            ),
+            _ => return None,
+        };
+
+        Some(Trace {
+            network,
+            block_number: U64::from(trace.block_number),
+            tx_hash,
+            trace_address: trace.trace_address.clone(),
+            selector: action
+                .input
+                .as_ref()
+                .and_then(|input| selector(&call_type, input)),
+            call_type,
+            from,
+            to,
+            value,
+            gas: action.gas,
+            gas_used: result.and_then(|r| r.gas_used),
+            error: trace.error.clone(),
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.trace_address.len()
+    }
+
+    /// Whether the call moved native currency.
+    pub fn transfers_value(&self) -> bool {
+        self.error.is_none() && self.value.is_some_and(|v| !v.is_zero())
+    }
+}
+
+fn selector(call_type: &str, input: &Bytes) -> Option<FixedBytes<4>> {
+    if call_type.starts_with("CREATE") || input.len() < 4 {
+        return None;
+    }
+
+    Some(FixedBytes::from_slice(&input[..4]))
+}
+
+impl Insert for Trace {
+    async fn insert(&self, pool: &sqlx::PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let block_number = self.block_number.to::<i64>();
+        let tx_hash = self.tx_hash.as_slice();
+        let trace_address = self
+            .trace_address
+            .iter()
+            .map(|i| *i as i32)
+            .collect::<Vec<_>>();
+        let depth = self.depth() as i32;
+        let from = self.from.as_slice();
+        let to = self.to.as_ref().map(|v| v.as_slice());
+        let value = self.value.as_ref().map(|v| v.as_le_slice());
+        let gas = self.gas.as_ref().map(|v| v.as_le_slice());
+        let gas_used = self.gas_used.as_ref().map(|v| v.as_le_slice());
+        let selector = self.selector.as_ref().map(|v| v.as_slice());
+
+        let sql = r#"INSERT INTO trace (
+            network,
+            block_number,
+            tx_hash,
+            trace_address,
+            depth,
+            call_type,
+            "from",
+            "to",
+            "value",
+            gas,
+            gas_used,
+            selector,
+            error
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(self.network)
+            .bind(block_number)
+            .bind(tx_hash)
+            .bind(trace_address)
+            .bind(depth)
+            .bind(&self.call_type)
+            .bind(from)
+            .bind(to)
+            .bind(value)
+            .bind(gas)
+            .bind(gas_used)
+            .bind(selector)
+            .bind(&self.error)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for Trace {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Trace);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_call_tracer_output() {
+        let json = serde_json::json!(
+            {
+                "txHash": "0x8acd636a4e0a0165bfbf003aa202a87b1a8e17e05183650ad39415861555aa6e",
+                "result": {
+                    "type": "CALL",
+                    "from": "0x4d06a4779ae0ed965598a1ef2b86b95a41ad7e81",
+                    "to": "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+                    "value": "0xde0b6b3a7640000",
+                    "gas": "0x2a4e8",
+                    "gasUsed": "0x1d3f2",
+                    "input": "0x7ff36ab50000000000000000000000000000000000000000000000000000000000000001",
+                    "output": "0x",
+                    "calls": [
+                        {
+                            "type": "CALL",
+                            "from": "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+                            "to": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                            "value": "0xde0b6b3a7640000",
+                            "gas": "0x1f6b2",
+                            "gasUsed": "0x5da6",
+                            "input": "0xd0e30db0"
+                        },
+                        {
+                            "type": "STATICCALL",
+                            "from": "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+                            "to": "0xb4e16d0168e52d35cacd2c6185b44281ec28c9dc",
+                            "gas": "0x18a1c",
+                            "gasUsed": "0x9c4",
+                            "input": "0x0902f1ac",
+                            "calls": [
+                                {
+                                    "type": "DELEGATECALL",
+                                    "from": "0xb4e16d0168e52d35cacd2c6185b44281ec28c9dc",
+                                    "to": "0x11235534a66a33c366b84933d5202c841539d1c9",
+                                    "gas": "0x1700a",
+                                    "gasUsed": "0x0",
+                                    "input": "0x",
+                                    "error": "execution reverted"
+                                }
+                            ]
+                        }
+                    ]
+                }
+            }
+        );
+
+        let trace = serde_json::from_value::<BlockCallTrace>(json).unwrap();
+        let traces = Trace::flatten(
+            NetworkKind::Ethereum,
+            U64::from(19_000_000),
+            trace.tx_hash.unwrap(),
+            trace.result.as_ref().unwrap(),
+        );
+
+        let addresses = traces
+            .iter()
+            .map(|t| t.trace_address.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(addresses, vec![vec![], vec![0], vec![1], vec![1, 0]]);
+        assert_eq!(
+            traces[0].selector,
+            Some(FixedBytes::from([0x7f, 0xf3, 0x6a, 0xb5]))
+        );
+        assert!(traces[1].transfers_value());
+        assert_eq!(traces[2].call_type, "STATICCALL");
+        assert_eq!(traces[3].depth(), 2);
+        assert_eq!(traces[3].selector, None);
+        assert_eq!(traces[3].error.as_deref(), Some("execution reverted"));
+    }
+
+    #[test]
+    fn convert_parity_traces() {
+        let json = serde_json::json!([
+            {
+                "action": {
+                    "callType": "delegatecall",
+                    "from": "0x4d06a4779ae0ed965598a1ef2b86b95a41ad7e81",
+                    "gas": "0x1f6b2",
+                    "input": "0xa9059cbb0000000000000000000000000000000000000000000000000000000000000001",
+                    "to": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                    "value": "0x0"
+                },
+                "blockHash": "0xfad3e899227b47062b71c90e61eeb056a43052be544bc006031b10df8abc92f4",
+                "blockNumber": 19000000,
+                "result": { "gasUsed": "0x5da6", "output": "0x" },
+                "subtraces": 0,
+                "traceAddress": [0],
+                "transactionHash": "0x8acd636a4e0a0165bfbf003aa202a87b1a8e17e05183650ad39415861555aa6e",
+                "transactionPosition": 3,
+                "type": "call"
+            },
+            {
+                "action": {
+                    "from": "0x4d06a4779ae0ed965598a1ef2b86b95a41ad7e81",
+                    "gas": "0x5208",
+                    "init": "0x6080",
+                    "value": "0x0"
+                },
+                "blockNumber": 19000000,
+                "result": {
+                    "address": "0x11235534a66a33c366b84933d5202c841539d1c9",
+                    "code": "0x",
+                    "gasUsed": "0x5208"
+                },
+                "subtraces": 0,
+                "traceAddress": [],
+                "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+                "type": "create"
+            },
+            {
+                "action": {
+                    "author": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+                    "rewardType": "block",
+                    "value": "0x1bc16d674ec80000"
+                },
+                "blockNumber": 19000000,
+                "result": null,
+                "subtraces": 0,
+                "traceAddress": [],
+                "type": "reward"
+            }
+        ]);
+
+        let traces = serde_json::from_value::<Vec<ParityTrace>>(json)
+            .unwrap()
+            .iter()
+            .filter_map(|t| Trace::from_parity(NetworkKind::Ethereum, t))
+            .collect::<Vec<_>>();
+
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].call_type, "DELEGATECALL");
+        assert_eq!(
+            traces[0].selector,
+            Some(FixedBytes::from([0xa9, 0x05, 0x9c, 0xbb]))
+        );
+        assert!(!traces[0].transfers_value());
+        assert_eq!(traces[1].call_type, "CREATE");
+        assert_eq!(
+            traces[1].to,
+            Some(
+                "0x11235534a66a33c366b84933d5202c841539d1c9"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+}
@@ -1,5 +1,11 @@
 pub mod block;
 pub mod log;
+pub mod transaction;
 
 pub use block::ZksyncBlock;
 pub use log::ZksyncLog;
+pub use transaction::ZksyncTransaction;
+
+use crate::networks::core::core_receipt;
+
+core_receipt!(ZksyncReceipt, Zksync);
@@ -53,6 +53,7 @@ impl Insert for ZksyncLog {
             topic3,
             tx_index,
             tx_hash,
+            event_sig,
         ) = self.core().db_repr();
 
         let l1_batch_number = self.l1_batch_number.map(|v| v.to::<i64>());
@@ -73,12 +74,13 @@ impl Insert for ZksyncLog {
                 topic3,
                 tx_index,
                 tx_hash,
+                event_sig,
 
                 l1_batch_number,
                 tx_log_index,
                 log_type
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
             ON CONFLICT DO NOTHING
             "#;
 
@@ -96,6 +98,7 @@ impl Insert for ZksyncLog {
             .bind(topic3)
             .bind(tx_index)
             .bind(tx_hash)
+            .bind(event_sig)
             .bind(l1_batch_number)
             .bind(tx_log_index)
             .bind(&self.log_type)
@@ -115,7 +118,8 @@ impl Emit for ZksyncLog {
         let mut con = queue.get_async_connection().await?;
 
         let channel = format!("{}:{}", network, ResourceKind::Log(LogKind::Raw));
-        con.lpush(channel, serde_json::to_string(self)?).await?;
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
 
         Ok(())
     }
@@ -125,6 +129,10 @@ impl Log for ZksyncLog {
     fn core(&self) -> &CoreLog {
         &self.core
     }
+
+    fn core_mut(&mut self) -> &mut CoreLog {
+        &mut self.core
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,180 @@
+use alloy_primitives::{B256, U64};
+use eyre::Result;
+use redis::AsyncCommands;
+use sqlx::{Error as SqlError, FromRow};
+use utoipa::ToSchema;
+
+use crate::{
+    networks::{core::CoreTransaction, NetworkKind, ResourceKind},
+    traits::{Emit, Insert, Transaction},
+    EmitError,
+};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    PartialEq,
+    Eq,
+    Hash,
+    FromRow,
+    ToSchema,
+)]
+pub struct ZksyncTransaction {
+    #[serde(flatten)]
+    core: CoreTransaction,
+
+    #[serde(rename = "l1BatchNumber")]
+    pub l1_batch_number: Option<U64>,
+
+    #[serde(rename = "l1BatchTxIndex")]
+    pub l1_batch_tx_index: Option<U64>,
+}
+
+impl Transaction for ZksyncTransaction {
+    fn core(&self) -> &CoreTransaction {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut CoreTransaction {
+        &mut self.core
+    }
+}
+
+impl Insert for ZksyncTransaction {
+    async fn insert(&self, pool: &sqlx::PgPool, _: &Option<B256>) -> Result<(), SqlError> {
+        let (
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            from,
+            to,
+            value,
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
+        ) = self.core().db_repr();
+
+        let l1_batch_number = self.l1_batch_number.map(|v| v.to::<i64>());
+        let l1_batch_tx_index = self.l1_batch_tx_index.map(|v| v.to::<i64>());
+
+        let sql = r#"INSERT INTO transaction (
+            network,
+            hash,
+            nonce,
+            block_hash,
+            block_number,
+            tx_index,
+            "from",
+            "to",
+            "value",
+            gas_price,
+            gas,
+            input,
+            tx_type,
+            chain_id,
+            v,
+            r,
+            s,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+            fn_sig,
+
+            l1_batch_number,
+            l1_batch_tx_index
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                $19, $20::jsonb, $21, $22, $23
+            ) ON CONFLICT DO NOTHING"#;
+
+        sqlx::query(sql)
+            .bind(NetworkKind::Zksync)
+            .bind(hash)
+            .bind(nonce)
+            .bind(block_hash)
+            .bind(block_number)
+            .bind(tx_index)
+            .bind(from)
+            .bind(to)
+            .bind(value)
+            .bind(gas_price)
+            .bind(gas)
+            .bind(input)
+            .bind(tx_type)
+            .bind(chain_id)
+            .bind(v)
+            .bind(r)
+            .bind(s)
+            .bind(max_fee_per_gas)
+            .bind(max_priority_fee_per_gas)
+            .bind(access_list)
+            .bind(fn_sig)
+            .bind(l1_batch_number)
+            .bind(l1_batch_tx_index)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Emit for ZksyncTransaction {
+    async fn emit(&self, queue: &redis::Client, network: &NetworkKind) -> Result<(), EmitError> {
+        let mut con = queue.get_async_connection().await?;
+
+        let channel = format!("{}:{}", network, ResourceKind::Transaction);
+        con.lpush::<_, _, ()>(channel, serde_json::to_string(self)?)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_zksync_transaction() {
+        let json = serde_json::json!(
+            {
+                "blockHash": "0x5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d",
+                "blockNumber": "0x1b82257",
+                "from": "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984",
+                "gas": "0x2b4c9a",
+                "gasPrice": "0x2b275d0",
+                "maxFeePerGas": "0x2b275d0",
+                "maxPriorityFeePerGas": "0x0",
+                "hash": "0x8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a",
+                "input": "0x",
+                "l1BatchNumber": "0x714a1",
+                "l1BatchTxIndex": "0x4c",
+                "nonce": "0x10",
+                "to": "0x3355df6d4c9c3035724fd0e3914de96a5a83aaf4",
+                "transactionIndex": "0x2",
+                "value": "0x0",
+                "type": "0x2",
+                "chainId": "0x144",
+                "v": "0x0",
+                "r": "0x2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e",
+                "s": "0x7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b"
+            }
+        );
+
+        assert!(serde_json::from_value::<ZksyncTransaction>(json).is_ok());
+    }
+}
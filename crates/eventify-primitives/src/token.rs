@@ -0,0 +1,170 @@
+//! The metadata of the token contracts emitting the known events, as resolved from the
+//! contracts themselves and kept in the `token` table.
+
+use alloy_primitives::{Address, I256, U256};
+use sqlx::{Error as SqlError, PgPool};
+
+use crate::networks::NetworkKind;
+
+/// A `token` row: standard, name, symbol, decimals and total supply.
+type TokenRow = (
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<i16>,
+    Option<String>,
+);
+
+/// The standard a token contract implements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Standard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+impl Standard {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Standard::Erc20 => "erc20",
+            Standard::Erc721 => "erc721",
+            Standard::Erc1155 => "erc1155",
+        }
+    }
+}
+
+/// What a token contract tells about itself, each field being unset if the contract doesn't
+/// implement the function it comes from.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+    pub standard: Option<Standard>,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    /// as of the resolution
+    pub total_supply: Option<U256>,
+}
+
+impl Token {
+    /// `amount` of the token in its display unit, e.g. `1.5` for 1500000 of a 6 decimals
+    /// token, unset if the decimals are unknown.
+    pub fn format(&self, amount: U256) -> Option<String> {
+        self.decimals.map(|decimals| format_units(amount, decimals))
+    }
+
+    /// Stores the metadata of the token at `address`, replacing the one resolved earlier.
+    pub async fn upsert(
+        &self,
+        pool: &PgPool,
+        network: NetworkKind,
+        address: Address,
+    ) -> Result<(), SqlError> {
+        let sql = r#"INSERT INTO token (
+            network,
+            address,
+            standard,
+            name,
+            symbol,
+            decimals,
+            total_supply
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7::numeric
+            ) ON CONFLICT (network, address) DO UPDATE SET
+                standard = EXCLUDED.standard,
+                name = EXCLUDED.name,
+                symbol = EXCLUDED.symbol,
+                decimals = EXCLUDED.decimals,
+                total_supply = EXCLUDED.total_supply,
+                updated_at = NOW()"#;
+
+        sqlx::query(sql)
+            .bind(network)
+            .bind(address.as_slice())
+            .bind(self.standard.map(|s| s.as_str()))
+            .bind(self.name.as_deref())
+            .bind(self.symbol.as_deref())
+            .bind(self.decimals.map(i16::from))
+            .bind(self.total_supply.map(|v| v.to_string()))
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The metadata stored for the token at `address`, if resolved already.
+    pub async fn get(
+        pool: &PgPool,
+        network: NetworkKind,
+        address: Address,
+    ) -> Result<Option<Self>, SqlError> {
+        let row: Option<TokenRow> = sqlx::query_as(
+            r#"SELECT standard, name, symbol, decimals, total_supply::text
+                FROM token
+                WHERE network = $1 AND address = $2"#,
+        )
+        .bind(network)
+        .bind(address.as_slice())
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(
+            row.map(|(standard, name, symbol, decimals, total_supply)| Self {
+                standard: standard.and_then(|s| serde_json::from_value(s.into()).ok()),
+                name,
+                symbol,
+                decimals: decimals.and_then(|d| u8::try_from(d).ok()),
+                total_supply: total_supply.and_then(|s| s.parse().ok()),
+            }),
+        )
+    }
+}
+
+/// `amount` divided by 10^`decimals`, trailing zeros left out.
+pub fn format_units(amount: U256, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Same as [`format_units`], for a signed `amount` (e.g. a price feed's answer).
+pub fn format_signed_units(amount: I256, decimals: u8) -> String {
+    let units = format_units(amount.unsigned_abs(), decimals);
+
+    if amount.is_negative() {
+        format!("-{units}")
+    } else {
+        units
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_units() {
+        assert_eq!(format_units(U256::from(1_500_000), 6), "1.5");
+        assert_eq!(format_units(U256::from(42), 18), "0.000000000000000042");
+        assert_eq!(format_units(U256::from(7_000), 3), "7");
+        assert_eq!(format_units(U256::from(7), 0), "7");
+    }
+
+    #[test]
+    fn formats_signed_units() {
+        assert_eq!(
+            format_signed_units(I256::try_from(-150).unwrap(), 2),
+            "-1.5"
+        );
+        assert_eq!(format_signed_units(I256::try_from(150).unwrap(), 2), "1.5");
+        assert_eq!(format_signed_units(I256::ZERO, 8), "0");
+    }
+}
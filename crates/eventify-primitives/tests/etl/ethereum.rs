@@ -3,8 +3,11 @@ use alloy_primitives::B256;
 use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
 
 use eventify_primitives::{
+    abi::Abi,
+    events::decoded::DecodedEvent,
     networks::{
-        ethereum::{EthBlock, EthLog},
+        ethereum::{EthBlock, EthLog, EthReceipt, EthTransaction},
+        trace::{BlockCallTrace, Trace},
         NetworkKind,
     },
     EmitT, InsertT, LogT,
@@ -74,3 +77,158 @@ async fn test_insert_and_emit_ethereum_log() {
 
     teardown_test_db(pool, &db_name).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_insert_and_emit_ethereum_transaction() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let json = serde_json::json!(
+        {
+            "accessList": [],
+            "blockHash": "0x2d85ca6abdcdbf998730c7fa551b9bcf7bcaf5306785596c316a85606ada164e",
+            "blockNumber": "0x1286aa1",
+            "chainId": "0x1",
+            "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+            "gas": "0x5208",
+            "gasPrice": "0xde15854c5",
+            "hash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+            "input": "0x",
+            "maxFeePerGas": "0xde15854c5",
+            "maxPriorityFeePerGas": "0x0",
+            "nonce": "0x10a2f",
+            "r": "0x8b1b7a1c3e5f7a9b1d3f5a7c9e1b3d5f7a9c1e3f5a7c9e1b3d5f7a9c1e3f5a7c",
+            "s": "0x1c3e5f7a9b1d3f5a7c9e1b3d5f7a9c1e3f5a7c9e1b3d5f7a9c1e3f5a7c9e1b3d",
+            "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+            "transactionIndex": "0x8c",
+            "type": "0x2",
+            "v": "0x1",
+            "value": "0x3a4f6d6d9e5d1c",
+            "yParity": "0x1"
+        }
+    );
+
+    let tx = serde_json::from_value::<EthTransaction>(json).unwrap();
+    tx.insert(&pool, &None).await.unwrap();
+    tx.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_ethereum_receipt() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let json = serde_json::json!(
+        {
+            "blobGasUsed": "0x20000",
+            "blobGasPrice": "0x1",
+            "blockHash": "0x2d85ca6abdcdbf998730c7fa551b9bcf7bcaf5306785596c316a85606ada164e",
+            "blockNumber": "0x1286aa1",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x1c9c38",
+            "effectiveGasPrice": "0xde15854c5",
+            "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+            "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+            "transactionIndex": "0x8c",
+            "type": "0x2"
+        }
+    );
+
+    let receipt = serde_json::from_value::<EthReceipt>(json).unwrap();
+    receipt.insert(&pool, &None).await.unwrap();
+    receipt.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_ethereum_trace() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let json = serde_json::json!(
+        {
+            "txHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+            "result": {
+                "type": "CALL",
+                "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+                "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+                "value": "0x3a4f6d6d9e5d1c",
+                "gas": "0x5208",
+                "gasUsed": "0x5208",
+                "input": "0x",
+                "calls": [
+                    {
+                        "type": "CALL",
+                        "from": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+                        "to": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                        "value": "0x1",
+                        "gas": "0x1000",
+                        "gasUsed": "0x800",
+                        "input": "0xa9059cbb"
+                    }
+                ]
+            }
+        }
+    );
+
+    let trace = serde_json::from_value::<BlockCallTrace>(json).unwrap();
+    let traces = Trace::flatten(
+        NetworkKind::Ethereum,
+        alloy_primitives::U64::from(0x1286aa1),
+        trace.tx_hash.unwrap(),
+        trace.result.as_ref().unwrap(),
+    );
+    for trace in traces {
+        trace.insert(&pool, &None).await.unwrap();
+        trace.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    }
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_ethereum_decoded_event() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let abi = Abi::from_json(
+        r#"[{"type": "event", "name": "Transfer", "anonymous": false, "inputs": [
+            {"name": "from", "type": "address", "indexed": true},
+            {"name": "to", "type": "address", "indexed": true},
+            {"name": "value", "type": "uint256", "indexed": false}
+        ]}]"#,
+    )
+    .unwrap();
+    let json = serde_json::json!(
+        {
+            "address": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+            "topics": [
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                "0x00000000000000000000000095222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+                "0x000000000000000000000000388c818ca8b9251b393131c08a736a67ccb19297"
+            ],
+            "data": "0x00000000000000000000000000000000000000000000000000000000000f4240",
+            "blockNumber": "0x1286aa1",
+            "transactionHash": "0x5f6d4a0d5b2b8c3e7e2f0a1d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b",
+            "transactionIndex": "0x0",
+            "blockHash": "0x2d85ca6abdcdbf998730c7fa551b9bcf7bcaf5306785596c316a85606ada164e",
+            "logIndex": "0x3",
+            "removed": false
+        }
+    );
+
+    let log = serde_json::from_value::<EthLog>(json).unwrap();
+    let event = DecodedEvent::new(NetworkKind::Ethereum, log.core(), &abi.events[0]).unwrap();
+    event.insert(&pool, &None).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
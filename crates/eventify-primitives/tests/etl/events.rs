@@ -1 +1,35 @@
+mod admin;
+mod balance;
+mod chainlink;
+mod entry_point;
 mod erc;
+mod nft;
+mod ownership;
+mod permit2;
+mod safe;
+mod uniswap;
+mod vault;
+mod weth;
+
+use alloy_primitives::{Address, Bytes, B256, U64};
+use alloy_sol_types::SolEvent;
+
+use eventify_primitives::{
+    events::Emitted,
+    networks::{core::CoreLog, NetworkKind},
+};
+
+/// `event` as emitted by a contract and decoded back.
+pub(crate) fn emitted<E: SolEvent>(event: E) -> Emitted<E> {
+    let log = CoreLog {
+        address: Address::repeat_byte(0xaa),
+        block_number: Some(U64::from(19_000_000)),
+        tx_hash: Some(B256::repeat_byte(0xbb)),
+        log_index: Some(U64::from(7)),
+        topics: event.encode_topics().into_iter().map(|t| t.0).collect(),
+        data: Bytes::from(event.encode_data()),
+        ..Default::default()
+    };
+
+    Emitted::decode(NetworkKind::Ethereum, &log).unwrap()
+}
@@ -0,0 +1,140 @@
+use alloy_primitives::{keccak256, Address, B256};
+use redis::AsyncCommands;
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    ethereum::Log,
+    events::{AccessControl, Ownable, EIP1967},
+    networks::{Alert, Logs, NetworkKind},
+    EmitT, InsertT,
+};
+
+#[tokio::test]
+async fn test_insert_and_emit_eip1967_upgraded() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(EIP1967::Upgraded {
+        implementation: Address::repeat_byte(0x11),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_eip1967_admin_changed() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(EIP1967::AdminChanged {
+        previousAdmin: Address::repeat_byte(0x11),
+        newAdmin: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_eip1967_beacon_upgraded() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(EIP1967::BeaconUpgraded {
+        beacon: Address::repeat_byte(0x11),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_ownable_ownership_transferred() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(Ownable::OwnershipTransferred {
+        previousOwner: Address::repeat_byte(0x11),
+        newOwner: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_access_control_role_granted() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(AccessControl::RoleGranted {
+        role: keccak256("MINTER_ROLE"),
+        account: Address::repeat_byte(0x11),
+        sender: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_access_control_role_revoked() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(AccessControl::RoleRevoked {
+        role: keccak256("MINTER_ROLE"),
+        account: Address::repeat_byte(0x11),
+        sender: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_access_control_role_admin_changed() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(AccessControl::RoleAdminChanged {
+        role: keccak256("MINTER_ROLE"),
+        previousAdminRole: B256::ZERO,
+        newAdminRole: keccak256("ADMIN_ROLE"),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_emit_ownership_transferred_alert() {
+    let redis = setup_test_redis().await;
+
+    let event = Logs::<Log>::Ownable_OwnershipTransferred(emitted(Ownable::OwnershipTransferred {
+        previousOwner: Address::repeat_byte(0x11),
+        newOwner: Address::repeat_byte(0x33),
+    }));
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    Alert::new(&event)
+        .unwrap()
+        .emit(&redis, &NetworkKind::Ethereum)
+        .await
+        .unwrap();
+
+    let mut con = redis.get_async_connection().await.unwrap();
+    let alerts: Vec<String> = con.lrange("eth:alert", 0, -1).await.unwrap();
+    assert!(alerts.iter().any(|alert| {
+        alert.contains("log_ownable_ownership_transferred")
+            && alert.contains("0x3333333333333333333333333333333333333333")
+    }));
+}
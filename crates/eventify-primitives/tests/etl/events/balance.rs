@@ -0,0 +1,179 @@
+use alloy_primitives::{Address, B256, U256, U64};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{Emitted, ERC20, WETH},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+
+fn transfer(from: Address, to: Address, value: u64, block: u64) -> Emitted<ERC20::Transfer> {
+    let mut transfer = emitted(ERC20::Transfer {
+        from,
+        to,
+        value: U256::from(value),
+    });
+    transfer.block_hash = Some(B256::repeat_byte(block as u8));
+    transfer.block_number = Some(U64::from(block));
+    transfer.tx_hash = Some(B256::repeat_byte(0xb0 + block as u8));
+    transfer
+}
+
+async fn balances(pool: &sqlx::PgPool) -> Vec<(Vec<u8>, String)> {
+    sqlx::query_as("SELECT holder, balance::text FROM erc20_balance ORDER BY holder")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+async fn balance_at(pool: &sqlx::PgPool, holder: Address, block: i64) -> Option<String> {
+    sqlx::query_scalar(
+        "SELECT SUM(delta)::text FROM erc20_balance_change WHERE holder = $1 AND block_number <= $2",
+    )
+    .bind(holder.as_slice())
+    .bind(block)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_fold_erc20_transfers_into_balances() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let mint = transfer(Address::ZERO, ALICE, 1_000, 1);
+    let send = transfer(ALICE, BOB, 300, 2);
+    // the mint being delivered twice
+    for transfer in [&mint, &send, &mint] {
+        transfer.insert(&pool, &transfer.tx_hash).await.unwrap();
+        transfer.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    }
+    // a transfer to oneself changes nothing
+    let own = transfer(BOB, BOB, 50, 3);
+    own.insert(&pool, &own.tx_hash).await.unwrap();
+
+    assert_eq!(
+        balances(&pool).await,
+        vec![
+            (Address::ZERO.to_vec(), "-1000".to_string()),
+            (ALICE.to_vec(), "700".to_string()),
+            (BOB.to_vec(), "300".to_string()),
+        ]
+    );
+    let supply: String =
+        sqlx::query_scalar("SELECT supply::text FROM erc20_supply WHERE token = $1")
+            .bind(Address::repeat_byte(0xaa).as_slice())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(supply, "1000");
+    assert_eq!(balance_at(&pool, ALICE, 1).await, Some("1000".to_string()));
+    assert_eq!(balance_at(&pool, BOB, 1).await, None);
+
+    // the block of the send is reorged out
+    let mut removed = send.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    removed.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(
+        balances(&pool).await,
+        vec![
+            (Address::ZERO.to_vec(), "-1000".to_string()),
+            (ALICE.to_vec(), "1000".to_string()),
+            (BOB.to_vec(), "0".to_string()),
+        ]
+    );
+    assert_eq!(balance_at(&pool, BOB, 2).await, None);
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_fold_weth_deposits_and_withdrawals_into_balances() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    let mut deposit = emitted(WETH::Deposit {
+        dst: ALICE,
+        wad: U256::from(1_000),
+    });
+    deposit.block_hash = Some(B256::repeat_byte(1));
+    deposit.block_number = Some(U64::from(1));
+    let mut withdrawal = emitted(WETH::Withdrawal {
+        src: ALICE,
+        wad: U256::from(400),
+    });
+    withdrawal.block_hash = Some(B256::repeat_byte(2));
+    withdrawal.block_number = Some(U64::from(2));
+    withdrawal.log_index = Some(U64::from(1));
+    deposit.insert(&pool, &deposit.tx_hash).await.unwrap();
+    withdrawal.insert(&pool, &withdrawal.tx_hash).await.unwrap();
+
+    assert_eq!(
+        balances(&pool).await,
+        vec![
+            (Address::ZERO.to_vec(), "-600".to_string()),
+            (ALICE.to_vec(), "600".to_string()),
+        ]
+    );
+    assert_eq!(balance_at(&pool, ALICE, 1).await, Some("1000".to_string()));
+
+    // the withdrawal is reorged out
+    let mut removed = withdrawal.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert_eq!(
+        balances(&pool).await,
+        vec![
+            (Address::ZERO.to_vec(), "-1000".to_string()),
+            (ALICE.to_vec(), "1000".to_string()),
+        ]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_revert_erc20_transfers_of_replaced_blocks() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    let mint = transfer(Address::ZERO, ALICE, 1_000, 1);
+    let send = transfer(ALICE, BOB, 300, 2);
+    let later = transfer(ALICE, BOB, 100, 3);
+    for transfer in [&mint, &send, &later] {
+        transfer.insert(&pool, &transfer.tx_hash).await.unwrap();
+    }
+
+    // block 2 is replaced, its logs not being flagged removed (e.g. polled for), the block
+    // after it going along
+    let mut replacement = transfer(ALICE, BOB, 50, 2);
+    replacement.block_hash = Some(B256::repeat_byte(0x22));
+    replacement.tx_hash = Some(B256::repeat_byte(0xc2));
+    replacement
+        .insert(&pool, &replacement.tx_hash)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balances(&pool).await,
+        vec![
+            (Address::ZERO.to_vec(), "-1000".to_string()),
+            (ALICE.to_vec(), "950".to_string()),
+            (BOB.to_vec(), "50".to_string()),
+        ]
+    );
+    assert_eq!(balance_at(&pool, BOB, 3).await, Some("50".to_string()));
+
+    // the same block again changes nothing
+    replacement
+        .insert(&pool, &replacement.tx_hash)
+        .await
+        .unwrap();
+    assert_eq!(balance_at(&pool, BOB, 3).await, Some("50".to_string()));
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,52 @@
+use alloy_primitives::{Address, I256, U256};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{chainlink::PriceUpdate, Chainlink},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+#[tokio::test]
+async fn test_insert_and_emit_chainlink_answer_updated() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = PriceUpdate {
+        feed: "ETH/USD".to_string(),
+        decimals: 8,
+        update: emitted(Chainlink::AnswerUpdated {
+            current: I256::try_from(352_112_345_678i64).unwrap(),
+            roundId: U256::from(20_043),
+            updatedAt: U256::from(1_705_000_000),
+        }),
+    };
+    event.insert(&pool, &event.update.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let (feed, answered_at): (String, i64) =
+        sqlx::query_as("SELECT feed, answered_at FROM chainlink_price")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!((feed.as_str(), answered_at), ("ETH/USD", 1_705_000_000));
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_chainlink_new_round() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(Chainlink::NewRound {
+        roundId: U256::from(20_044),
+        startedBy: Address::repeat_byte(0x11),
+        startedAt: U256::from(1_705_003_600),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,76 @@
+use alloy_primitives::{Address, B256, U256};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{events::EntryPoint, networks::NetworkKind, EmitT, InsertT};
+
+#[tokio::test]
+async fn test_insert_and_emit_user_operation() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let user_op_hash = B256::repeat_byte(0x42);
+    let sender = Address::repeat_byte(0x11);
+
+    let deployed = emitted(EntryPoint::AccountDeployed {
+        userOpHash: user_op_hash,
+        sender,
+        factory: Address::repeat_byte(0x22),
+        paymaster: Address::ZERO,
+    });
+    deployed.insert(&pool, &deployed.tx_hash).await.unwrap();
+    deployed.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let reverted = emitted(EntryPoint::UserOperationRevertReason {
+        userOpHash: user_op_hash,
+        sender,
+        nonce: U256::ZERO,
+        revertReason: vec![0x08, 0xc3, 0x79, 0xa0],
+    });
+    reverted.insert(&pool, &reverted.tx_hash).await.unwrap();
+    reverted.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let executed = emitted(EntryPoint::UserOperationEvent {
+        userOpHash: user_op_hash,
+        sender,
+        paymaster: Address::ZERO,
+        nonce: U256::ZERO,
+        success: false,
+        actualGasCost: U256::from(31_254_000_000_000u64),
+        actualGasUsed: U256::from(156_270),
+    });
+    executed.insert(&pool, &executed.tx_hash).await.unwrap();
+    executed.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    // folded into a single row
+    type Row = (Option<Vec<u8>>, Option<bool>, Option<Vec<u8>>);
+    let rows: Vec<Row> = sqlx::query_as(
+        "SELECT factory, success, revert_reason FROM user_operation WHERE user_op_hash = $1",
+    )
+    .bind(user_op_hash.as_slice())
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        rows,
+        vec![(
+            Some(vec![0x22; 20]),
+            Some(false),
+            Some(vec![0x08, 0xc3, 0x79, 0xa0])
+        )]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_before_execution() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(EntryPoint::BeforeExecution {});
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
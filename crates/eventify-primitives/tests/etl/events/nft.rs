@@ -0,0 +1,143 @@
+use alloy_primitives::{Address, B256, U256};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{ERC6551Registry, ERC1155, ERC4906, ERC5192},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+#[tokio::test]
+async fn test_insert_and_emit_erc4906_metadata_updates() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(ERC4906::MetadataUpdate {
+        tokenId: U256::from(42),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let event = emitted(ERC4906::BatchMetadataUpdate {
+        fromTokenId: U256::from(1),
+        toTokenId: U256::from(10_000),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_erc5192_locks() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(ERC5192::Locked {
+        tokenId: U256::from(42),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let event = emitted(ERC5192::Unlocked {
+        tokenId: U256::from(42),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let (locked, unlocked): (i64, i64) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM erc5192_locked), (SELECT COUNT(*) FROM erc5192_unlocked)",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!((locked, unlocked), (1, 1));
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_erc6551_account_created() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(ERC6551Registry::ERC6551AccountCreated {
+        account: Address::repeat_byte(0x11),
+        implementation: Address::repeat_byte(0x22),
+        salt: B256::ZERO,
+        chainId: U256::from(1),
+        tokenContract: Address::repeat_byte(0x33),
+        tokenId: U256::from(42),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let account: Vec<u8> = sqlx::query_scalar(
+        "SELECT account FROM erc6551_account_created WHERE token_contract = $1 AND token_id = $2",
+    )
+    .bind(Address::repeat_byte(0x33).as_slice())
+    .bind(U256::from(42).as_le_slice())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(account, Address::repeat_byte(0x11).as_slice());
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+async fn queued_metadata(pool: &sqlx::PgPool) -> Vec<(String, Option<String>, String, i32)> {
+    sqlx::query_as(
+        "SELECT token_id::text, uri, status, attempts FROM nft_metadata ORDER BY token_id",
+    )
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_queue_erc1155_uri_metadata() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let uri = |value: &str| {
+        emitted(ERC1155::URI {
+            value: value.to_string(),
+            id: U256::from(42),
+        })
+    };
+    let event = uri("ipfs://QmXyz/{id}.json");
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(
+        queued_metadata(&pool).await,
+        vec![(
+            "42".to_string(),
+            Some("ipfs://QmXyz/{id}.json".to_string()),
+            "pending".to_string(),
+            0
+        )]
+    );
+
+    // given up on, then pointed elsewhere
+    sqlx::query("UPDATE nft_metadata SET status = 'failed', attempts = 5")
+        .execute(&pool)
+        .await
+        .unwrap();
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    assert_eq!(queued_metadata(&pool).await[0].2, "failed");
+
+    let event = uri("https://example.com/{id}.json");
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    assert_eq!(
+        queued_metadata(&pool).await,
+        vec![(
+            "42".to_string(),
+            Some("https://example.com/{id}.json".to_string()),
+            "pending".to_string(),
+            0
+        )]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,188 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use alloy_sol_types::SolEvent;
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{Emitted, ERC1155, ERC721},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+const ALICE: Address = Address::repeat_byte(0x11);
+const BOB: Address = Address::repeat_byte(0x22);
+const OPERATOR: Address = Address::repeat_byte(0x33);
+
+/// `event` emitted in the `log_index`th log of `block`.
+fn at<E: SolEvent>(event: E, block: u64, log_index: u64) -> Emitted<E> {
+    let mut emitted = emitted(event);
+    emitted.block_hash = Some(B256::repeat_byte(block as u8));
+    emitted.block_number = Some(U64::from(block));
+    emitted.tx_hash = Some(B256::repeat_byte(0xb0 + block as u8));
+    emitted.log_index = Some(U64::from(log_index));
+    emitted
+}
+
+async fn owners(pool: &sqlx::PgPool) -> Vec<(String, Vec<u8>, String)> {
+    sqlx::query_as(
+        r#"SELECT token_id::text, "owner", quantity::text FROM nft_owner
+        WHERE quantity > 0
+        ORDER BY token_id, "owner""#,
+    )
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_track_erc721_owners() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let transfer = |from, to, block| {
+        at(
+            ERC721::Transfer {
+                from,
+                to,
+                tokenId: U256::from(7),
+            },
+            block,
+            0,
+        )
+    };
+    let mint = transfer(Address::ZERO, ALICE, 1);
+    let sale = transfer(ALICE, BOB, 2);
+    for transfer in [&mint, &sale, &mint] {
+        transfer.insert(&pool, &transfer.tx_hash).await.unwrap();
+        transfer.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    }
+    assert_eq!(
+        owners(&pool).await,
+        vec![("7".to_string(), BOB.to_vec(), "1".to_string())]
+    );
+    let history: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM nft_transfer")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(history, 2);
+
+    // the block of the sale is reorged out
+    let mut removed = sale.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert_eq!(
+        owners(&pool).await,
+        vec![("7".to_string(), ALICE.to_vec(), "1".to_string())]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_track_erc1155_owners() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let mint = at(
+        ERC1155::TransferBatch {
+            operator: OPERATOR,
+            from: Address::ZERO,
+            to: ALICE,
+            ids: vec![U256::from(1), U256::from(2)],
+            values: vec![U256::from(10), U256::from(1)],
+        },
+        1,
+        0,
+    );
+    mint.insert(&pool, &mint.tx_hash).await.unwrap();
+    mint.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let send = at(
+        ERC1155::TransferSingle {
+            operator: OPERATOR,
+            from: ALICE,
+            to: BOB,
+            id: U256::from(1),
+            value: U256::from(4),
+        },
+        2,
+        0,
+    );
+    send.insert(&pool, &send.tx_hash).await.unwrap();
+    send.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    assert_eq!(
+        owners(&pool).await,
+        vec![
+            ("1".to_string(), ALICE.to_vec(), "6".to_string()),
+            ("1".to_string(), BOB.to_vec(), "4".to_string()),
+            ("2".to_string(), ALICE.to_vec(), "1".to_string()),
+        ]
+    );
+
+    let mut removed = mint.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert_eq!(
+        owners(&pool).await,
+        vec![("1".to_string(), BOB.to_vec(), "4".to_string())]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_revert_nft_transfers_of_replaced_blocks() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    let transfer = |from, to, block| {
+        at(
+            ERC721::Transfer {
+                from,
+                to,
+                tokenId: U256::from(7),
+            },
+            block,
+            0,
+        )
+    };
+    let mint = transfer(Address::ZERO, ALICE, 1);
+    let sale = transfer(ALICE, BOB, 2);
+    for transfer in [&mint, &sale] {
+        transfer.insert(&pool, &transfer.tx_hash).await.unwrap();
+    }
+
+    // pending logs come again once mined
+    let mut pending = transfer(BOB, OPERATOR, 3);
+    pending.block_hash = None;
+    pending.insert(&pool, &pending.tx_hash).await.unwrap();
+    assert_eq!(
+        owners(&pool).await,
+        vec![("7".to_string(), BOB.to_vec(), "1".to_string())]
+    );
+
+    // block 2 is replaced, its logs not being flagged removed (e.g. polled for), the sale
+    // being left out of the new one
+    let mut replacement = transfer(ALICE, OPERATOR, 2);
+    replacement.block_hash = Some(B256::repeat_byte(0x22));
+    replacement.tx_hash = Some(B256::repeat_byte(0xc2));
+    for _ in 0..2 {
+        replacement
+            .insert(&pool, &replacement.tx_hash)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        owners(&pool).await,
+        vec![("7".to_string(), OPERATOR.to_vec(), "1".to_string())]
+    );
+    let history: Vec<Vec<u8>> =
+        sqlx::query_scalar(r#"SELECT "to" FROM nft_transfer ORDER BY block_number"#)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(history, vec![ALICE.to_vec(), OPERATOR.to_vec()]);
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
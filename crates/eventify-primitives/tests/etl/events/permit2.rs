@@ -0,0 +1,208 @@
+use alloy_primitives::{Address, U256, U64};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{Emitted, Permit2, ERC20},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+const OWNER: Address = Address::repeat_byte(0x11);
+const TOKEN: Address = Address::repeat_byte(0x22);
+const SPENDER: Address = Address::repeat_byte(0x33);
+
+type Row = (String, Vec<u8>, Option<i64>, Option<i64>);
+
+async fn allowances(pool: &sqlx::PgPool) -> Vec<Row> {
+    sqlx::query_as(
+        r#"SELECT source, amount, expiration, nonce FROM allowance
+        WHERE token = $1 AND "owner" = $2 AND spender = $3
+        ORDER BY source"#,
+    )
+    .bind(TOKEN.as_slice())
+    .bind(OWNER.as_slice())
+    .bind(SPENDER.as_slice())
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_permit2_events() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let permit = emitted(Permit2::Permit {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+        amount: U256::from(1_000).to(),
+        expiration: 1_700_000_000,
+        nonce: 1,
+    });
+    permit.insert(&pool, &permit.tx_hash).await.unwrap();
+    permit.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(
+        allowances(&pool).await,
+        vec![(
+            "permit2".to_string(),
+            U256::from(1_000).to_le_bytes::<32>().to_vec(),
+            Some(1_700_000_000),
+            Some(1)
+        )]
+    );
+
+    let mut invalidation = emitted(Permit2::NonceInvalidation {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+        newNonce: 5,
+        oldNonce: 1,
+    });
+    invalidation.log_index = Some(U64::from(8));
+    invalidation
+        .insert(&pool, &invalidation.tx_hash)
+        .await
+        .unwrap();
+    invalidation
+        .emit(&redis, &NetworkKind::Ethereum)
+        .await
+        .unwrap();
+
+    let mut lockdown = emitted(Permit2::Lockdown {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+    });
+    lockdown.log_index = Some(U64::from(9));
+    lockdown.insert(&pool, &lockdown.tx_hash).await.unwrap();
+    lockdown.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(
+        allowances(&pool).await,
+        vec![(
+            "permit2".to_string(),
+            U256::ZERO.to_le_bytes::<32>().to_vec(),
+            Some(1_700_000_000),
+            Some(5)
+        )]
+    );
+
+    // an older approval, e.g. while backfilling, leaves the allowance be
+    let approval = emitted(Permit2::Approval {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+        amount: U256::from(2_000).to(),
+        expiration: 1_800_000_000,
+    });
+    approval.insert(&pool, &approval.tx_hash).await.unwrap();
+    approval.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(allowances(&pool).await[0].1, U256::ZERO.to_le_bytes::<32>());
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_erc20_approval_sets_allowance() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let approval = Emitted {
+        address: TOKEN,
+        ..emitted(ERC20::Approval {
+            owner: OWNER,
+            spender: SPENDER,
+            value: U256::MAX,
+        })
+    };
+    approval.insert(&pool, &approval.tx_hash).await.unwrap();
+    approval.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    assert_eq!(
+        allowances(&pool).await,
+        vec![(
+            "erc20".to_string(),
+            U256::MAX.to_le_bytes::<32>().to_vec(),
+            None,
+            None
+        )]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_revert_allowance_changes_removed_by_reorg() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    let approve = |value: u64, log_index: u64| {
+        let mut approval = Emitted {
+            address: TOKEN,
+            ..emitted(ERC20::Approval {
+                owner: OWNER,
+                spender: SPENDER,
+                value: U256::from(value),
+            })
+        };
+        approval.log_index = Some(U64::from(log_index));
+        approval
+    };
+    let first = approve(1_000, 1);
+    let second = approve(0, 2);
+    for approval in [&first, &second] {
+        approval.insert(&pool, &approval.tx_hash).await.unwrap();
+    }
+    assert_eq!(allowances(&pool).await[0].1, U256::ZERO.to_le_bytes::<32>());
+
+    // the latest approval is reorged out, the previous one holding again
+    let mut removed = second.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert_eq!(
+        allowances(&pool).await,
+        vec![(
+            "erc20".to_string(),
+            U256::from(1_000).to_le_bytes::<32>().to_vec(),
+            None,
+            None
+        )]
+    );
+
+    // as is the first one, leaving no allowance
+    let mut removed = first.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert!(allowances(&pool).await.is_empty());
+
+    // the changes of a Permit2 allowance are folded field by field
+    let mut permit = emitted(Permit2::Permit {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+        amount: U256::from(1_000).to(),
+        expiration: 1_700_000_000,
+        nonce: 1,
+    });
+    permit.log_index = Some(U64::from(3));
+    let mut lockdown = emitted(Permit2::Lockdown {
+        owner: OWNER,
+        token: TOKEN,
+        spender: SPENDER,
+    });
+    lockdown.log_index = Some(U64::from(4));
+    permit.insert(&pool, &permit.tx_hash).await.unwrap();
+    lockdown.insert(&pool, &lockdown.tx_hash).await.unwrap();
+    lockdown.removed = true;
+    lockdown.insert(&pool, &lockdown.tx_hash).await.unwrap();
+    assert_eq!(
+        allowances(&pool).await,
+        vec![(
+            "permit2".to_string(),
+            U256::from(1_000).to_le_bytes::<32>().to_vec(),
+            Some(1_700_000_000),
+            Some(1)
+        )]
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,127 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use redis::AsyncCommands;
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    ethereum::Log,
+    events::{Emitted, Safe},
+    networks::{Alert, Logs, NetworkKind},
+    EmitT, InsertT,
+};
+
+/// `event`, emitted by the `log_index`th log of the transaction.
+fn at<E>(log_index: u64, event: Emitted<E>) -> Emitted<E> {
+    Emitted {
+        log_index: Some(U64::from(log_index)),
+        ..event
+    }
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_safe_history() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let events = [
+        Logs::<Log>::Safe_ExecutionSuccess(at(
+            0,
+            emitted(Safe::ExecutionSuccess {
+                txHash: B256::repeat_byte(0x11),
+                payment: U256::ZERO,
+            }),
+        )),
+        Logs::<Log>::Safe_ExecutionFailure(at(
+            1,
+            emitted(Safe::ExecutionFailure {
+                txHash: B256::repeat_byte(0x22),
+                payment: U256::from(1_000),
+            }),
+        )),
+        Logs::<Log>::Safe_AddedOwner(at(
+            2,
+            emitted(Safe::AddedOwner {
+                owner: Address::repeat_byte(0x33),
+            }),
+        )),
+        Logs::<Log>::Safe_RemovedOwner(at(
+            3,
+            emitted(Safe::RemovedOwner {
+                owner: Address::repeat_byte(0x44),
+            }),
+        )),
+        Logs::<Log>::Safe_ChangedThreshold(at(
+            4,
+            emitted(Safe::ChangedThreshold {
+                threshold: U256::from(2),
+            }),
+        )),
+        Logs::<Log>::Safe_SafeReceived(at(
+            5,
+            emitted(Safe::SafeReceived {
+                sender: Address::repeat_byte(0x55),
+                value: U256::from(1_000_000_000_000_000_000u128),
+            }),
+        )),
+    ];
+    for event in &events {
+        event.insert(&pool, &None).await.unwrap();
+        event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+        if let Some(alert) = Alert::new(event) {
+            alert.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+        }
+    }
+
+    let kinds: Vec<String> =
+        sqlx::query_scalar("SELECT kind FROM safe_event WHERE safe = $1 ORDER BY log_index")
+            .bind(Address::repeat_byte(0xaa).as_slice())
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(
+        kinds,
+        [
+            "execution_success",
+            "execution_failure",
+            "added_owner",
+            "removed_owner",
+            "changed_threshold",
+            "safe_received"
+        ]
+    );
+
+    let mut con = redis.get_async_connection().await.unwrap();
+    let alerts: Vec<String> = con.lrange("eth:alert", 0, -1).await.unwrap();
+    assert!(alerts
+        .iter()
+        .any(|alert| alert.contains("log_safe_changed_threshold")));
+    assert!(!alerts
+        .iter()
+        .any(|alert| alert.contains("log_safe_execution_success")));
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_safe_proxy_creation() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(Safe::ProxyCreation {
+        proxy: Address::repeat_byte(0x11),
+        singleton: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    let (factory, singleton): (Vec<u8>, Vec<u8>) =
+        sqlx::query_as("SELECT address, singleton FROM safe_event WHERE safe = $1")
+            .bind(Address::repeat_byte(0x11).as_slice())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(factory, Address::repeat_byte(0xaa).as_slice());
+    assert_eq!(singleton, Address::repeat_byte(0x22).as_slice());
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
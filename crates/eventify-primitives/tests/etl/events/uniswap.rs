@@ -0,0 +1,169 @@
+use alloy_primitives::{Address, I256, U256};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{UniswapV2, UniswapV3},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v2_swap() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV2::Swap {
+        sender: Address::repeat_byte(0x11),
+        amount0In: U256::ZERO,
+        amount1In: U256::from(1_000_000_000_000_000_000u128),
+        amount0Out: U256::from(2_500_000_000u64),
+        amount1Out: U256::ZERO,
+        to: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v2_mint() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV2::Mint {
+        sender: Address::repeat_byte(0x11),
+        amount0: U256::from(2_500_000_000u64),
+        amount1: U256::from(1_000_000_000_000_000_000u128),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v2_burn() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV2::Burn {
+        sender: Address::repeat_byte(0x11),
+        amount0: U256::from(2_500_000_000u64),
+        amount1: U256::from(1_000_000_000_000_000_000u128),
+        to: Address::repeat_byte(0x22),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v2_sync() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV2::Sync {
+        reserve0: U256::from(40_321_945_512_671u64).to(),
+        reserve1: U256::from(17_005_381_716_154_712_451_083u128).to(),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v3_swap() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV3::Swap {
+        sender: Address::repeat_byte(0x11),
+        recipient: Address::repeat_byte(0x22),
+        amount0: I256::try_from(-2_500_000_000i64).unwrap(),
+        amount1: I256::try_from(1_000_000_000_000_000_000i64).unwrap(),
+        sqrtPriceX96: U256::from(1u128 << 96).to(),
+        liquidity: 24_019_611_537_802_218_540,
+        tick: 200_637,
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v3_mint() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV3::Mint {
+        sender: Address::repeat_byte(0x11),
+        owner: Address::repeat_byte(0x22),
+        tickLower: -887_220,
+        tickUpper: 887_220,
+        amount: 24_019_611_537_802_218_540,
+        amount0: U256::from(2_500_000_000u64),
+        amount1: U256::from(1_000_000_000_000_000_000u128),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v3_burn() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV3::Burn {
+        owner: Address::repeat_byte(0x22),
+        tickLower: -887_220,
+        tickUpper: 887_220,
+        amount: 24_019_611_537_802_218_540,
+        amount0: U256::from(2_500_000_000u64),
+        amount1: U256::from(1_000_000_000_000_000_000u128),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v3_collect() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV3::Collect {
+        owner: Address::repeat_byte(0x22),
+        recipient: Address::repeat_byte(0x11),
+        tickLower: -887_220,
+        tickUpper: 887_220,
+        amount0: 2_500_000_000,
+        amount1: 1_000_000_000_000_000_000,
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_uniswap_v3_initialize() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(UniswapV3::Initialize {
+        sqrtPriceX96: U256::from(1u128 << 96).to(),
+        tick: 0,
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
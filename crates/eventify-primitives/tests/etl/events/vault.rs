@@ -0,0 +1,133 @@
+use alloy_primitives::{Address, B256, U256, U64};
+use alloy_sol_types::SolEvent;
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{
+    events::{Emitted, ERC4626},
+    networks::NetworkKind,
+    EmitT, InsertT,
+};
+
+const ALICE: Address = Address::repeat_byte(0x11);
+
+fn at<E: SolEvent>(event: E, block: u64) -> Emitted<E> {
+    let mut emitted = emitted(event);
+    emitted.block_hash = Some(B256::repeat_byte(block as u8));
+    emitted.block_number = Some(U64::from(block));
+    emitted.tx_hash = Some(B256::repeat_byte(0xb0 + block as u8));
+    emitted
+}
+
+async fn share_prices(pool: &sqlx::PgPool) -> Vec<(i64, String)> {
+    sqlx::query_as(
+        "SELECT block_number, share_price::text FROM vault_share_price ORDER BY block_number",
+    )
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_record_erc4626_flows() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let deposit = at(
+        ERC4626::Deposit {
+            sender: ALICE,
+            owner: ALICE,
+            assets: U256::from(1_000),
+            shares: U256::from(1_000),
+        },
+        1,
+    );
+    let withdraw = at(
+        ERC4626::Withdraw {
+            sender: ALICE,
+            receiver: ALICE,
+            owner: ALICE,
+            assets: U256::from(550),
+            shares: U256::from(500),
+        },
+        2,
+    );
+    deposit.insert(&pool, &deposit.tx_hash).await.unwrap();
+    deposit.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+    withdraw.insert(&pool, &withdraw.tx_hash).await.unwrap();
+    withdraw.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    assert_eq!(
+        share_prices(&pool).await,
+        vec![(1, "1".to_string()), (2, "1.1".to_string())]
+    );
+    let totals: (String, String, i64, i64) = sqlx::query_as(
+        "SELECT (assets_deposited - assets_withdrawn)::text, (shares_minted - shares_burned)::text, deposits, withdrawals FROM vault_total",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(totals, ("450".to_string(), "500".to_string(), 1, 1));
+
+    // the block of the withdrawal is reorged out
+    let mut removed = withdraw.clone();
+    removed.removed = true;
+    removed.insert(&pool, &removed.tx_hash).await.unwrap();
+    assert_eq!(share_prices(&pool).await, vec![(1, "1".to_string())]);
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_erc4626_flows_of_replaced_blocks() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    let deposit = |assets: u64, shares: u64, block| {
+        at(
+            ERC4626::Deposit {
+                sender: ALICE,
+                owner: ALICE,
+                assets: U256::from(assets),
+                shares: U256::from(shares),
+            },
+            block,
+        )
+    };
+    for deposit in [deposit(1_000, 1_000, 1), deposit(1_000, 500, 2)] {
+        deposit.insert(&pool, &deposit.tx_hash).await.unwrap();
+    }
+
+    // pending logs come again once mined
+    let mut pending = deposit(1_000, 1, 3);
+    pending.block_hash = None;
+    pending.insert(&pool, &pending.tx_hash).await.unwrap();
+    assert_eq!(
+        share_prices(&pool).await,
+        vec![(1, "1".to_string()), (2, "2".to_string())]
+    );
+
+    // block 2 is replaced, its logs not being flagged removed (e.g. polled for)
+    let mut replacement = deposit(1_000, 800, 2);
+    replacement.block_hash = Some(B256::repeat_byte(0x22));
+    replacement.tx_hash = Some(B256::repeat_byte(0xc2));
+    for _ in 0..2 {
+        replacement
+            .insert(&pool, &replacement.tx_hash)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        share_prices(&pool).await,
+        vec![(1, "1".to_string()), (2, "1.25".to_string())]
+    );
+    let totals: (String, String, i64) = sqlx::query_as(
+        "SELECT assets_deposited::text, shares_minted::text, deposits FROM vault_total",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(totals, ("2000".to_string(), "1800".to_string(), 2));
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,35 @@
+use alloy_primitives::{Address, U256};
+
+use super::emitted;
+use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
+use eventify_primitives::{events::WETH, networks::NetworkKind, EmitT, InsertT};
+
+#[tokio::test]
+async fn test_insert_and_emit_weth_deposit() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(WETH::Deposit {
+        dst: Address::repeat_byte(0x11),
+        wad: U256::from(1_000_000_000_000_000_000u128),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_weth_withdrawal() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let event = emitted(WETH::Withdrawal {
+        src: Address::repeat_byte(0x11),
+        wad: U256::from(1_000_000_000_000_000_000u128),
+    });
+    event.insert(&pool, &event.tx_hash).await.unwrap();
+    event.emit(&redis, &NetworkKind::Ethereum).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
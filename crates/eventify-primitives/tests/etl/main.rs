@@ -5,5 +5,6 @@ mod events;
 mod linea;
 mod optimism;
 mod polygon;
+mod token;
 mod utils;
 mod zksync;
@@ -4,7 +4,7 @@ use crate::utils::{setup_test_db, setup_test_redis, teardown_test_db};
 
 use eventify_primitives::{
     networks::{
-        optimism::{OptimismBlock, OptimismLog},
+        optimism::{OptimismBlock, OptimismLog, OptimismReceipt, OptimismTransaction},
         NetworkKind,
     },
     EmitT, InsertT, LogT,
@@ -75,3 +75,72 @@ async fn test_insert_and_emit_optimism_log() {
 
     teardown_test_db(pool, &db_name).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_insert_and_emit_optimism_transaction() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let json = serde_json::json!(
+        {
+            "blockHash": "0x9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b",
+            "blockNumber": "0x6f8a1c2",
+            "from": "0xdeaddeaddeaddeaddeaddeaddeaddeaddead0001",
+            "gas": "0xf4240",
+            "gasPrice": "0x0",
+            "hash": "0x3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f",
+            "input": "0x440a5e20",
+            "mint": "0x0",
+            "nonce": "0x6f8a1c1",
+            "sourceHash": "0x6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c",
+            "to": "0x4200000000000000000000000000000000000015",
+            "transactionIndex": "0x0",
+            "value": "0x0",
+            "type": "0x7e",
+            "isSystemTx": false,
+            "v": "0x0",
+            "r": "0x0",
+            "s": "0x0"
+        }
+    );
+
+    let tx = serde_json::from_value::<OptimismTransaction>(json).unwrap();
+    tx.insert(&pool, &None).await.unwrap();
+    tx.emit(&redis, &NetworkKind::Optimism).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_insert_and_emit_optimism_receipt() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+    let redis = setup_test_redis().await;
+
+    let json = serde_json::json!(
+        {
+            "l1Fee": "0x1d0f9b8e3a4",
+            "l1GasPrice": "0x3b9aca07",
+            "l1GasUsed": "0x640",
+            "blockHash": "0x9c1f0e6d5b4a3928172635445362718091a2b3c4d5e6f708192a3b4c5d6e7f80",
+            "blockNumber": "0x6f1c2a8",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x1c9c38",
+            "effectiveGasPrice": "0xde15854c5",
+            "from": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0x388c818ca8b9251b393131c08a736a67ccb19297",
+            "transactionHash": "0x3e4d5c6b7a8990a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f6071829",
+            "transactionIndex": "0x8c",
+            "type": "0x2"
+        }
+    );
+
+    let receipt = serde_json::from_value::<OptimismReceipt>(json).unwrap();
+    receipt.insert(&pool, &None).await.unwrap();
+    receipt.emit(&redis, &NetworkKind::Optimism).await.unwrap();
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
@@ -0,0 +1,90 @@
+use alloy_primitives::{Address, U256};
+
+use crate::utils::{setup_test_db, teardown_test_db};
+use eventify_primitives::{
+    events::ERC20,
+    networks::NetworkKind,
+    token::{Standard, Token},
+};
+
+const USDC: Address = Address::repeat_byte(0xaa);
+
+#[tokio::test]
+async fn test_upsert_token() {
+    let (pool, db_name) = setup_test_db().await.unwrap();
+
+    assert_eq!(
+        Token::get(&pool, NetworkKind::Ethereum, USDC)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let mut token = Token {
+        standard: Some(Standard::Erc20),
+        name: Some("USD Coin".to_string()),
+        symbol: Some("USDC".to_string()),
+        decimals: Some(6),
+        total_supply: Some(U256::from(25_000_000_000_000_000u64)),
+    };
+    token
+        .upsert(&pool, NetworkKind::Ethereum, USDC)
+        .await
+        .unwrap();
+    assert_eq!(
+        Token::get(&pool, NetworkKind::Ethereum, USDC)
+            .await
+            .unwrap(),
+        Some(token.clone())
+    );
+    assert_eq!(
+        Token::get(&pool, NetworkKind::Polygon, USDC).await.unwrap(),
+        None
+    );
+
+    // a contract implementing none of the functions
+    token = Token {
+        standard: Some(Standard::Erc20),
+        ..Default::default()
+    };
+    token
+        .upsert(&pool, NetworkKind::Ethereum, USDC)
+        .await
+        .unwrap();
+    assert_eq!(
+        Token::get(&pool, NetworkKind::Ethereum, USDC)
+            .await
+            .unwrap(),
+        Some(token)
+    );
+
+    teardown_test_db(pool, &db_name).await.unwrap();
+}
+
+#[test]
+fn test_emitted_token() {
+    let mut emitted = super::events::emitted(ERC20::Transfer {
+        from: Address::ZERO,
+        to: Address::repeat_byte(0x11),
+        value: U256::from(1_500_000),
+    });
+    assert!(serde_json::to_value(&emitted)
+        .unwrap()
+        .get("token")
+        .is_none());
+
+    let token = Token {
+        standard: Some(Standard::Erc20),
+        symbol: Some("USDC".to_string()),
+        decimals: Some(6),
+        ..Default::default()
+    };
+    emitted.formatted_value = token.format(emitted.event.value);
+    emitted.token = Some(token);
+
+    let value = serde_json::to_value(&emitted).unwrap();
+    assert_eq!(value["token"]["standard"], "erc20");
+    assert_eq!(value["token"]["symbol"], "USDC");
+    assert_eq!(value["token"]["decimals"], 6);
+    assert_eq!(value["formattedValue"], "1.5");
+}
@@ -1,4 +1,4 @@
-use crate::subcommands::run;
+use crate::subcommands::{event_sig, fn_sig, run};
 
 #[derive(Clone, Debug, clap::Parser)]
 #[command(name = "eventify")]
@@ -12,4 +12,6 @@ pub(crate) struct Cmd {
 #[derive(Debug, Clone, clap::Subcommand)]
 pub(crate) enum SubCommand {
     Run(Box<run::Cmd>),
+    FnSig(fn_sig::Cmd),
+    EventSig(event_sig::Cmd),
 }
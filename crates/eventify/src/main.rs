@@ -6,7 +6,7 @@ use std::path::Path;
 
 use clap::Parser;
 use eyre::Result;
-use sqlx::{migrate::Migrator, postgres::PgPoolOptions};
+use sqlx::{migrate::Migrator, postgres::PgPoolOptions, PgPool};
 use tokio::{
     signal::{
         ctrl_c,
@@ -16,41 +16,40 @@ use tokio::{
         mpsc::{self},
         watch,
     },
+    task::JoinHandle,
+};
+use tracing::{
+    debug, error, info, info_span,
+    instrument::{Instrument, Instrumented},
+    warn,
 };
-use tracing::{debug, error, info, warn};
-use tracing_subscriber::EnvFilter;
 
 //--
 pub mod cmd;
 pub mod subcommands;
+mod telemetry;
 
-use crate::cmd::Cmd;
+use crate::{
+    cmd::Cmd,
+    subcommands::{event_sig, fn_sig, import::DatabaseArgs},
+};
 use eventify_configs::{
-    configs::{ApplicationConfig, CollectorConfig, ManagerConfig},
+    configs::{ApplicationConfig, CollectorConfig, ManagerConfig, NftMetadataConfig},
     database::DatabaseConfig,
-    Config,
+    Config, NetworkDetail,
 };
 use eventify_core::{
+    metrics,
     networks::{
         arbitrum::Arbitrum, avalanche::Avalanche, base::Base, bsc::Bsc, ethereum::Eth,
-        linea::Linea, optimism::Optimism, polygon::Polygon, zksync::Zksync,
+        linea::Linea, optimism::Optimism, polygon::Polygon, zksync::Zksync, NetworkClient,
     },
-    Manager,
+    nft_metadata::MetadataFetcher,
+    Manager, NetworkResource, NetworkT,
 };
 use eventify_primitives::{
-    networks::{
-        arbitrum::{ArbitrumBlock, ArbitrumLog},
-        avalanche::{AvalancheBlock, AvalancheLog},
-        base::{BaseBlock, BaseLog},
-        bsc::{BscBlock, BscLog},
-        ethereum::{EthBlock, EthLog},
-        linea::{LineaBlock, LineaLog},
-        optimism::{OptimismBlock, OptimismLog},
-        polygon::{PolygonBlock, PolygonLog},
-        zksync::{ZksyncBlock, ZksyncLog},
-        NetworkKind, Resource,
-    },
-    BlockT, EmitT, LogT,
+    networks::{NetworkKind, Resource, ResourceKind},
+    BlockT, EmitT, LogT, ReceiptT, TransactionT,
 };
 //--
 
@@ -63,15 +62,107 @@ async fn run_migrations(url: &str) -> Result<()> {
     Ok(())
 }
 
-async fn propagate<B: BlockT, L: LogT>(
+/// Connects to the database the signatures are imported into, once it's migrated.
+async fn import_pool(database: &DatabaseArgs) -> Result<PgPool> {
+    run_migrations(database.database_url()).await?;
+
+    Ok(PgPoolOptions::new()
+        .acquire_timeout(std::time::Duration::from_secs(5))
+        .connect(database.database_url())
+        .await?)
+}
+
+/// Spawns the collection of `network`: a stream per configured resource, the NFT metadata worker
+/// if enabled and the propagation of whatever is collected.
+async fn spawn_network<N: NetworkT>(
+    network: NetworkKind,
+    detail: &NetworkDetail,
+    config: &Config,
+    pool: &PgPool,
+    stop_signal: &watch::Receiver<bool>,
+) -> Result<Vec<JoinHandle<()>>> {
+    let (tx, rx) = mpsc::channel::<Instrumented<NetworkResource<N>>>(1500);
+
+    let manager_config = ManagerConfig::new(config.collect.clone());
+    let manager: Manager<N> =
+        Manager::new(manager_config, CollectorConfig::from((network, detail)), tx);
+    let mut tasks = manager.init_stream_tasks(stop_signal.clone()).await?;
+    tasks.extend(nft_metadata_task(
+        config.nft_metadata.as_ref(),
+        network,
+        detail,
+        pool,
+        stop_signal,
+    ));
+
+    let queue_url = config.queue_url.clone();
+    tasks.push(tokio::spawn(async move {
+        match propagate(&queue_url, &network, rx).await {
+            Ok(_) => info!("Propagation completed successfully."),
+            Err(e) => error!("Propagation failed with error: {:?}", e),
+        }
+    }));
+
+    Ok(tasks)
+}
+
+/// Spawns the worker fetching the metadata of the NFTs collected on `network`, if enabled.
+fn nft_metadata_task(
+    config: Option<&NftMetadataConfig>,
+    network: NetworkKind,
+    detail: &NetworkDetail,
+    pool: &PgPool,
+    stop_signal: &watch::Receiver<bool>,
+) -> Option<JoinHandle<()>> {
+    let config = config?.clone();
+    let url = detail.providers().into_iter().next()?;
+    let connection = detail.connection.clone();
+    let pool = pool.clone();
+    let stop_signal = stop_signal.clone();
+
+    Some(tokio::spawn(async move {
+        let fetched = async {
+            let client = NetworkClient::new(url, &connection).await?;
+            MetadataFetcher::new(network, client, pool, config)?
+                .run(stop_signal)
+                .await
+        };
+
+        match fetched.await {
+            Ok(()) => info!(?network, "NFT metadata fetching stopped."),
+            Err(e) => error!(?network, "NFT metadata fetching failed with error: {:?}", e),
+        }
+    }))
+}
+
+async fn propagate<B: BlockT, L: LogT, T: TransactionT, R: ReceiptT>(
     queue_url: &str,
     network: &NetworkKind,
-    mut receiver: mpsc::Receiver<Resource<B, L>>,
+    mut receiver: mpsc::Receiver<Instrumented<Resource<B, L, T, R>>>,
 ) -> Result<()> {
     let redis = redis::Client::open(queue_url)?;
+    let network_label = network.to_string();
 
     while let Some(rsrc) = receiver.recv().await {
-        rsrc.emit(&redis, network).await?;
+        metrics::CHANNEL_DEPTH
+            .with_label_values(&[&network_label])
+            .set(receiver.len() as i64);
+
+        let span = info_span!(parent: rsrc.span(), "emit");
+        let rsrc = rsrc.into_inner();
+        let resource = ResourceKind::from(&rsrc).to_string();
+        let timer = metrics::EMIT_DURATION
+            .with_label_values(&[&network_label, &resource])
+            .start_timer();
+        let emitted = rsrc.emit(&redis, network).instrument(span).await;
+        timer.observe_duration();
+
+        if emitted.is_err() {
+            metrics::EMIT_ERRORS
+                .with_label_values(&[&network_label, &resource])
+                .inc();
+        }
+        emitted?;
     }
 
     Ok(())
@@ -80,204 +171,83 @@ async fn propagate<B: BlockT, L: LogT>(
 #[tokio::main]
 async fn main() -> Result<()> {
     let cmd = Cmd::parse();
-    tracing_subscriber::fmt()
-        .with_thread_ids(true)
-        .with_target(true)
-        .with_env_filter(EnvFilter::builder().from_env_lossy())
-        .init();
+
+    // the run config carries the telemetry settings, so it's loaded before the subscriber is
+    // installed and the subcommand dispatched
+    let config: Option<Config> = match &cmd.subcmd {
+        cmd::SubCommand::Run(args) => Some(match args.config.as_ref() {
+            Some(file) => toml::from_str(std::fs::read_to_string(file.as_str())?.as_str())?,
+            None => Config::from(*args.clone()),
+        }),
+        _ => None,
+    };
+    telemetry::init(config.as_ref().and_then(|config| config.telemetry.as_ref()))?;
 
     match cmd.subcmd {
         cmd::SubCommand::Run(args) => {
+            let Some(config) = config else {
+                unreachable!("the run config is loaded before dispatching")
+            };
             run_migrations(args.database_url()).await?;
 
-            let config: Config = if let Some(file) = args.config {
-                info!(target:"eventify::cli", "Loading config from {}", file);
-                toml::from_str(std::fs::read_to_string(file.as_str())?.as_str())?
-            } else {
-                Config::from(*args)
-            };
+            if let Some(file) = args.config.as_ref() {
+                info!(target:"eventify::cli", "Loaded config from {}", file);
+            }
             debug!(target:"eventify::cli", ?config);
 
-            let database_config = DatabaseConfig::from(config.database_url);
+            let database_config = DatabaseConfig::from(config.database_url.clone());
             let pool = PgPoolOptions::new()
                 .acquire_timeout(std::time::Duration::from_secs(5))
                 .connect_lazy_with(database_config.with_db());
             let (signal_sender, signal_receiver) = watch::channel(false);
             let mut tasks = vec![];
 
-            if let Some(eth) = config.network.eth {
-                let network_kind = NetworkKind::Ethereum;
-                let (tx, rx) = mpsc::channel::<Resource<EthBlock, EthLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, eth.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Eth> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+            for (network, detail) in config.network.details() {
+                let spawned = match network {
+                    NetworkKind::Ethereum => {
+                        spawn_network::<Eth>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(zksync) = config.network.zksync {
-                let network_kind = NetworkKind::Zksync;
-                let (tx, rx) = mpsc::channel::<Resource<ZksyncBlock, ZksyncLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, zksync.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Zksync> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Zksync => {
+                        spawn_network::<Zksync>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(polygon) = config.network.polygon {
-                let network_kind = NetworkKind::Polygon;
-                let (tx, rx) = mpsc::channel::<Resource<PolygonBlock, PolygonLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, polygon.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Polygon> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Polygon => {
+                        spawn_network::<Polygon>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(optimism) = config.network.optimism {
-                let network_kind = NetworkKind::Optimism;
-                let (tx, rx) = mpsc::channel::<Resource<OptimismBlock, OptimismLog>>(1500);
-
-                let collector_config =
-                    CollectorConfig::new(network_kind, optimism.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Optimism> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Optimism => {
+                        spawn_network::<Optimism>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(arbitrum) = config.network.arbitrum {
-                let network_kind = NetworkKind::Arbitrum;
-                let (tx, rx) = mpsc::channel::<Resource<ArbitrumBlock, ArbitrumLog>>(1500);
-
-                let collector_config =
-                    CollectorConfig::new(network_kind, arbitrum.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Arbitrum> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Arbitrum => {
+                        spawn_network::<Arbitrum>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(linea) = config.network.linea {
-                let network_kind = NetworkKind::Linea;
-                let (tx, rx) = mpsc::channel::<Resource<LineaBlock, LineaLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, linea.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Linea> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Linea => {
+                        spawn_network::<Linea>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(avalanche) = config.network.avalanche {
-                let network_kind = NetworkKind::Avalanche;
-                let (tx, rx) = mpsc::channel::<Resource<AvalancheBlock, AvalancheLog>>(1500);
-
-                let collector_config =
-                    CollectorConfig::new(network_kind, avalanche.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Avalanche> =
-                    Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Avalanche => {
+                        spawn_network::<Avalanche>(
+                            network,
+                            detail,
+                            &config,
+                            &pool,
+                            &signal_receiver,
+                        )
+                        .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(bsc) = config.network.bsc {
-                let network_kind = NetworkKind::Bsc;
-                let (tx, rx) = mpsc::channel::<Resource<BscBlock, BscLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, bsc.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Bsc> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Bsc => {
+                        spawn_network::<Bsc>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
-            }
-
-            if let Some(base) = config.network.base {
-                let network_kind = NetworkKind::Base;
-                let (tx, rx) = mpsc::channel::<Resource<BaseBlock, BaseLog>>(1500);
-
-                let collector_config = CollectorConfig::new(network_kind, base.node_url.clone());
-                let manager_config = ManagerConfig::new(config.collect.clone());
-                let manager: Manager<Base> = Manager::new(manager_config, collector_config, tx);
-                tasks.extend(manager.init_stream_tasks(signal_receiver.clone()).await?);
-
-                let propagate_queue_url = config.queue_url.clone();
-                let propagate_task = tokio::spawn(async move {
-                    match propagate(&propagate_queue_url, &network_kind, rx).await {
-                        Ok(_) => info!("Propagation completed successfully."),
-                        Err(e) => error!("Propagation failed with error: {:?}", e),
+                    NetworkKind::Base => {
+                        spawn_network::<Base>(network, detail, &config, &pool, &signal_receiver)
+                            .await?
                     }
-                });
-                tasks.push(propagate_task);
+                };
+                tasks.extend(spawned);
             }
 
             if let Some(server_config) = config.server {
@@ -300,6 +270,7 @@ async fn main() -> Result<()> {
                 }) => {
                     warn!("Received SIGINT, shutting down..");
                     tokio::time::sleep(tokio::time::Duration::from_secs(6)).await; // give the streaming threads time to gracefully wind down
+                    telemetry::shutdown();
                     std::process::exit(0);
                 }
                 _ = tokio::spawn(async move {
@@ -312,8 +283,43 @@ async fn main() -> Result<()> {
                     warn!("Received SIGTERM, shutting down..");
                 }
             }
+            telemetry::shutdown();
 
             Ok(())
         }
+        cmd::SubCommand::FnSig(args) => match args.action {
+            fn_sig::Action::Import(args) => {
+                let pool = import_pool(&args.database).await?;
+                let file = std::io::BufReader::new(std::fs::File::open(&args.path)?);
+                let imported = eventify_core::fn_sig::import(&pool, file).await?;
+                info!(
+                    target:"eventify::cli",
+                    path = args.path,
+                    inserted = imported.inserted,
+                    existing = imported.existing,
+                    skipped = imported.skipped,
+                    "Imported function signatures"
+                );
+
+                Ok(())
+            }
+        },
+        cmd::SubCommand::EventSig(args) => match args.action {
+            event_sig::Action::Import(args) => {
+                let pool = import_pool(&args.database).await?;
+                let file = std::io::BufReader::new(std::fs::File::open(&args.path)?);
+                let imported = eventify_core::event_sig::import(&pool, file).await?;
+                info!(
+                    target:"eventify::cli",
+                    path = args.path,
+                    inserted = imported.inserted,
+                    existing = imported.existing,
+                    skipped = imported.skipped,
+                    "Imported event signatures"
+                );
+
+                Ok(())
+            }
+        },
     }
 }
@@ -1 +1,4 @@
+pub(crate) mod event_sig;
+pub(crate) mod fn_sig;
+pub(crate) mod import;
 pub(crate) mod run;
@@ -0,0 +1,44 @@
+use clap::{Args, Parser, Subcommand};
+
+use super::import::DatabaseArgs;
+
+#[derive(Clone, Debug, Parser)]
+#[command(about = "Manage the event signatures raw logs are labelled with")]
+pub(crate) struct Cmd {
+    #[command(subcommand)]
+    pub(crate) action: Action,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub(crate) enum Action {
+    /// Load a file of signatures, one per line, optionally as `<topic0>,"<signature>"` (e.g. the
+    /// output of `scripts/fn-sig.lua --events`)
+    Import(ImportArgs),
+}
+
+#[derive(Args, Clone, Debug)]
+pub(crate) struct ImportArgs {
+    #[arg(
+        help = "Path to the file to import",
+        default_value = "etc/data/event-sig.csv"
+    )]
+    pub(crate) path: String,
+
+    #[command(flatten)]
+    pub(crate) database: DatabaseArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_path() {
+        let Action::Import(args) = Cmd::parse_from(["event-sig", "import"]).action;
+        assert_eq!(args.path, "etc/data/event-sig.csv");
+
+        let Action::Import(args) =
+            Cmd::parse_from(["event-sig", "import", "/tmp/event-sig.csv"]).action;
+        assert_eq!(args.path, "/tmp/event-sig.csv");
+    }
+}
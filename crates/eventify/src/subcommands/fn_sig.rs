@@ -0,0 +1,42 @@
+use clap::{Args, Parser, Subcommand};
+
+use super::import::DatabaseArgs;
+
+#[derive(Clone, Debug, Parser)]
+#[command(about = "Manage the function signatures selectors are resolved with")]
+pub(crate) struct Cmd {
+    #[command(subcommand)]
+    pub(crate) action: Action,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub(crate) enum Action {
+    /// Load a `<selector>,"<signature>"` CSV (e.g. the output of `scripts/fn-sig.lua`)
+    Import(ImportArgs),
+}
+
+#[derive(Args, Clone, Debug)]
+pub(crate) struct ImportArgs {
+    #[arg(
+        help = "Path to the CSV to import",
+        default_value = "etc/data/fn-sig.csv"
+    )]
+    pub(crate) path: String,
+
+    #[command(flatten)]
+    pub(crate) database: DatabaseArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_path() {
+        let Action::Import(args) = Cmd::parse_from(["fn-sig", "import"]).action;
+        assert_eq!(args.path, "etc/data/fn-sig.csv");
+
+        let Action::Import(args) = Cmd::parse_from(["fn-sig", "import", "/tmp/fn-sig.csv"]).action;
+        assert_eq!(args.path, "/tmp/fn-sig.csv");
+    }
+}
@@ -3,7 +3,10 @@ use std::{collections::HashSet, str::FromStr};
 use clap::{Args, Parser};
 use secrecy::{ExposeSecret, Secret};
 
-use eventify_configs::{configs::ServerConfig, Config, Network, NetworkDetail};
+use eventify_configs::{
+    configs::{ServerConfig, TelemetryConfig},
+    Config, Network, NetworkDetail,
+};
 use eventify_primitives::networks::{NetworkKind, ResourceKind};
 
 #[derive(Clone, Debug, Parser)]
@@ -60,6 +63,9 @@ pub(crate) struct Cmd {
 
     #[clap(flatten)]
     pub(crate) server: Option<ServerSettings>,
+
+    #[clap(flatten)]
+    pub(crate) telemetry: Option<TelemetrySettings>,
 }
 
 impl Cmd {
@@ -89,53 +95,43 @@ impl From<Cmd> for Config {
             }),
             None => None,
         };
+        let telemetry = settings.telemetry.clone().map(|t| TelemetryConfig {
+            otlp_endpoint: t.otlp_endpoint,
+            service_name: t.service_name,
+            sampling_ratio: t.sampling_ratio,
+        });
 
         let mut network = Network::default();
         match settings.network {
             NetworkKind::Ethereum => {
-                network.eth = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.eth = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Zksync => {
-                network.zksync = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.zksync = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Polygon => {
-                network.polygon = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.polygon = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Optimism => {
-                network.optimism = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.optimism =
+                    Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Arbitrum => {
-                network.arbitrum = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.arbitrum =
+                    Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Linea => {
-                network.linea = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.linea = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Avalanche => {
-                network.avalanche = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.avalanche =
+                    Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Bsc => {
-                network.bsc = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.bsc = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
             NetworkKind::Base => {
-                network.base = Some(NetworkDetail {
-                    node_url: settings.clone().node_url().to_string(),
-                });
+                network.base = Some(NetworkDetail::new(settings.clone().node_url().to_string()));
             }
         };
 
@@ -144,6 +140,8 @@ impl From<Cmd> for Config {
             queue_url: settings.queue_url().to_string(),
             collect: settings.collect(),
             server,
+            telemetry,
+            nft_metadata: None,
             network,
         }
     }
@@ -177,6 +175,33 @@ pub(crate) struct ServerSettings {
     pub(crate) port: u16,
 }
 
+#[derive(Args, Clone, Debug, PartialEq)]
+pub(crate) struct TelemetrySettings {
+    #[arg(
+        long = "otlp.endpoint",
+        env = "EVENTIFY_OTLP_ENDPOINT",
+        help = "The OTLP collector to export the traces to",
+        default_value = "http://localhost:4317"
+    )]
+    pub(crate) otlp_endpoint: String,
+
+    #[arg(
+        long = "otlp.service-name",
+        env = "EVENTIFY_OTLP_SERVICE_NAME",
+        help = "The service name the traces are reported under",
+        default_value = "eventify"
+    )]
+    pub(crate) service_name: String,
+
+    #[arg(
+        long = "otlp.sampling-ratio",
+        env = "EVENTIFY_OTLP_SAMPLING_RATIO",
+        help = "Ratio of the traces to sample, between 0.0 and 1.0",
+        default_value_t = 1.0
+    )]
+    pub(crate) sampling_ratio: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +276,27 @@ mod tests {
         assert_eq!(args.node_url(), "wss://eth.llamarpc.com");
         assert_eq!(args.collect, "blocks,logs");
         assert_eq!(args.server, None);
+        assert_eq!(args.telemetry, None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_telemetry_settings_env_values() {
+        set_var("EVENTIFY_OTLP_ENDPOINT", "http://collector:4317");
+        set_var("EVENTIFY_OTLP_SAMPLING_RATIO", "0.1");
+
+        let args = CommandParser::<Cmd>::parse_from(["run"]).args;
+        assert_eq!(
+            args.telemetry,
+            Some(TelemetrySettings {
+                otlp_endpoint: "http://collector:4317".to_string(),
+                service_name: "eventify".to_string(),
+                sampling_ratio: 0.1,
+            })
+        );
+
+        remove_var("EVENTIFY_OTLP_ENDPOINT");
+        remove_var("EVENTIFY_OTLP_SAMPLING_RATIO");
     }
 
     #[test]
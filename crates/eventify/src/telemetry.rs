@@ -0,0 +1,60 @@
+use eyre::Result;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    runtime,
+    trace::{self, Sampler},
+    Resource,
+};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+use eventify_configs::configs::TelemetryConfig;
+
+/// Sets up the global subscriber; the spans are additionally exported over OTLP when `config` is
+/// present.
+pub(crate) fn init(config: Option<&TelemetryConfig>) -> Result<()> {
+    let otlp = match config {
+        Some(config) => Some(tracing_opentelemetry::layer().with_tracer(tracer(config)?)),
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::builder().from_env_lossy())
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_thread_ids(true)
+                .with_target(true),
+        )
+        .with(otlp)
+        .init();
+
+    Ok(())
+}
+
+/// Flushes the spans that haven't been exported yet.
+pub(crate) fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+fn tracer(config: &TelemetryConfig) -> Result<trace::Tracer> {
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(config.sampling_ratio)));
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(config.otlp_endpoint.clone()),
+        )
+        .with_trace_config(
+            trace::config()
+                .with_sampler(sampler)
+                .with_resource(Resource::new(vec![KeyValue::new(
+                    "service.name",
+                    config.service_name.clone(),
+                )])),
+        )
+        .install_batch(runtime::Tokio)?;
+
+    Ok(tracer)
+}
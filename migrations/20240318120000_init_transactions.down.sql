@@ -0,0 +1,7 @@
+-- indexes
+DROP INDEX IF EXISTS transaction_block_number_index;
+DROP INDEX IF EXISTS transaction_from_index;
+DROP INDEX IF EXISTS transaction_to_index;
+
+-- tables
+DROP TABLE IF EXISTS transaction;
@@ -0,0 +1,49 @@
+/*
+ * Tables
+ */
+CREATE TABLE IF NOT EXISTS transaction (
+    -- core
+    network network_type,
+    hash BYTEA UNIQUE,
+    nonce BIGINT,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_index INTEGER,
+    "from" BYTEA NOT NULL,
+    "to" BYTEA,
+    "value" BYTEA,
+    gas_price BYTEA,
+    gas BYTEA,
+    input BYTEA,
+    tx_type INTEGER,
+    chain_id BIGINT,
+    v BYTEA,
+    r BYTEA,
+    s BYTEA,
+    max_fee_per_gas BYTEA,
+    max_priority_fee_per_gas BYTEA,
+    access_list JSONB,
+    --
+    -- additional
+    max_fee_per_blob_gas BYTEA,
+    blob_versioned_hashes BYTEA[],
+    source_hash BYTEA,
+    mint BYTEA,
+    is_system_tx BOOLEAN,
+    l1_batch_number BIGINT,
+    l1_batch_tx_index BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY(hash)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS transaction_block_number_index ON transaction (block_number);
+CREATE INDEX IF NOT EXISTS transaction_from_index ON transaction ("from");
+CREATE INDEX IF NOT EXISTS transaction_to_index ON transaction ("to");
+---
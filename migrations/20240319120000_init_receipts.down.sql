@@ -0,0 +1,7 @@
+-- indexes
+DROP INDEX IF EXISTS receipt_block_number_index;
+DROP INDEX IF EXISTS receipt_contract_address_index;
+DROP INDEX IF EXISTS receipt_status_index;
+
+-- tables
+DROP TABLE IF EXISTS receipt;
@@ -0,0 +1,43 @@
+/*
+ * Tables
+ */
+CREATE TABLE IF NOT EXISTS receipt (
+    -- core
+    network network_type,
+    tx_hash BYTEA UNIQUE,
+    tx_index INTEGER,
+    block_hash BYTEA,
+    block_number BIGINT,
+    "from" BYTEA NOT NULL,
+    "to" BYTEA,
+    cumulative_gas_used BYTEA,
+    gas_used BYTEA,
+    effective_gas_price BYTEA,
+    contract_address BYTEA,
+    tx_type INTEGER,
+    status INTEGER,
+    root BYTEA,
+    blob_gas_used BYTEA,
+    blob_gas_price BYTEA,
+    --
+    -- additional
+    l1_fee BYTEA,
+    l1_gas_used BYTEA,
+    l1_gas_price BYTEA,
+    gas_used_for_l1 BYTEA,
+    l1_block_number BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY(tx_hash)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS receipt_block_number_index ON receipt (block_number);
+CREATE INDEX IF NOT EXISTS receipt_contract_address_index ON receipt (contract_address);
+CREATE INDEX IF NOT EXISTS receipt_status_index ON receipt (status);
+---
@@ -0,0 +1,7 @@
+-- indexes
+DROP INDEX IF EXISTS trace_block_number_index;
+DROP INDEX IF EXISTS trace_from_index;
+DROP INDEX IF EXISTS trace_to_index;
+
+-- tables
+DROP TABLE IF EXISTS trace;
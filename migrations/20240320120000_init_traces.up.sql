@@ -0,0 +1,32 @@
+/*
+ * Tables
+ */
+CREATE TABLE IF NOT EXISTS trace (
+    network network_type,
+    block_number BIGINT NOT NULL,
+    tx_hash BYTEA NOT NULL,
+    trace_address INTEGER[] NOT NULL,
+    depth INTEGER NOT NULL,
+    call_type TEXT NOT NULL,
+    "from" BYTEA NOT NULL,
+    "to" BYTEA,
+    "value" BYTEA,
+    gas BYTEA,
+    gas_used BYTEA,
+    selector BYTEA,
+    error TEXT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY(network, tx_hash, trace_address)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS trace_block_number_index ON trace (block_number);
+CREATE INDEX IF NOT EXISTS trace_from_index ON trace ("from");
+CREATE INDEX IF NOT EXISTS trace_to_index ON trace ("to");
+---
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS fn_sig_hex_sig_index;
+
+ALTER TABLE transaction DROP COLUMN IF EXISTS fn_sig;
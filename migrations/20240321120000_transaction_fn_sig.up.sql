@@ -0,0 +1,3 @@
+ALTER TABLE transaction ADD COLUMN IF NOT EXISTS fn_sig TEXT;
+
+CREATE INDEX IF NOT EXISTS fn_sig_hex_sig_index ON fn_sig (hex_sig);
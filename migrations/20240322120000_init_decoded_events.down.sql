@@ -0,0 +1,9 @@
+-- indexes
+DROP INDEX IF EXISTS abi_network_index;
+DROP INDEX IF EXISTS decoded_event_address_index;
+DROP INDEX IF EXISTS decoded_event_topic0_index;
+DROP INDEX IF EXISTS decoded_event_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS decoded_event;
+DROP TABLE IF EXISTS abi;
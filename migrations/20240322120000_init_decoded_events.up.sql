@@ -0,0 +1,42 @@
+/*
+ * Tables
+ */
+CREATE TABLE IF NOT EXISTS abi (
+    id SERIAL PRIMARY KEY,
+    -- NULL for ABIs applying to every network
+    network network_type,
+    -- NULL for ABIs matched by topic0 regardless of the emitting contract
+    address BYTEA,
+    abi JSONB NOT NULL,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS decoded_event (
+    network network_type,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    name TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    topic0 BYTEA NOT NULL,
+    params JSONB NOT NULL,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS abi_network_index ON abi (network);
+CREATE INDEX IF NOT EXISTS decoded_event_address_index ON decoded_event (address);
+CREATE INDEX IF NOT EXISTS decoded_event_topic0_index ON decoded_event (topic0);
+CREATE INDEX IF NOT EXISTS decoded_event_block_number_index ON decoded_event (block_number);
+---
@@ -0,0 +1,6 @@
+-- indexes
+DROP INDEX IF EXISTS event_sig_hex_sig_index;
+
+-- tables
+ALTER TABLE log DROP COLUMN IF EXISTS event_sig;
+DROP TABLE IF EXISTS event_sig;
@@ -0,0 +1,25 @@
+/*
+ * Tables
+ */
+--- event signatures
+CREATE TABLE IF NOT EXISTS event_sig (
+    id uuid DEFAULT gen_random_uuid() NOT NULL,
+    hex_sig BYTEA NOT NULL,
+    text_sig TEXT UNIQUE NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY(id)
+);
+comment on table event_sig is 'Event signatures, e.g. taken from https://4byte.directory, raw logs are labelled with';
+comment on column event_sig.hex_sig is 'keccak256 of the signature, the topic0 of the logs emitting the event';
+comment on column event_sig.text_sig is 'human readable event signature';
+
+ALTER TABLE log ADD COLUMN IF NOT EXISTS event_sig TEXT;
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS event_sig_hex_sig_index ON event_sig (hex_sig);
+---
@@ -0,0 +1,30 @@
+-- indexes
+DROP INDEX IF EXISTS uniswap_v2_swap_address_index;
+DROP INDEX IF EXISTS uniswap_v2_swap_block_number_index;
+DROP INDEX IF EXISTS uniswap_v2_mint_address_index;
+DROP INDEX IF EXISTS uniswap_v2_mint_block_number_index;
+DROP INDEX IF EXISTS uniswap_v2_burn_address_index;
+DROP INDEX IF EXISTS uniswap_v2_burn_block_number_index;
+DROP INDEX IF EXISTS uniswap_v2_sync_address_index;
+DROP INDEX IF EXISTS uniswap_v2_sync_block_number_index;
+DROP INDEX IF EXISTS uniswap_v3_swap_address_index;
+DROP INDEX IF EXISTS uniswap_v3_swap_block_number_index;
+DROP INDEX IF EXISTS uniswap_v3_mint_address_index;
+DROP INDEX IF EXISTS uniswap_v3_mint_block_number_index;
+DROP INDEX IF EXISTS uniswap_v3_burn_address_index;
+DROP INDEX IF EXISTS uniswap_v3_burn_block_number_index;
+DROP INDEX IF EXISTS uniswap_v3_collect_address_index;
+DROP INDEX IF EXISTS uniswap_v3_collect_block_number_index;
+DROP INDEX IF EXISTS uniswap_v3_initialize_address_index;
+DROP INDEX IF EXISTS uniswap_v3_initialize_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS uniswap_v2_swap;
+DROP TABLE IF EXISTS uniswap_v2_mint;
+DROP TABLE IF EXISTS uniswap_v2_burn;
+DROP TABLE IF EXISTS uniswap_v2_sync;
+DROP TABLE IF EXISTS uniswap_v3_swap;
+DROP TABLE IF EXISTS uniswap_v3_mint;
+DROP TABLE IF EXISTS uniswap_v3_burn;
+DROP TABLE IF EXISTS uniswap_v3_collect;
+DROP TABLE IF EXISTS uniswap_v3_initialize;
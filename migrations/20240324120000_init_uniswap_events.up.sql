@@ -0,0 +1,198 @@
+/*
+ * Tables
+ */
+-- Uniswap V2 (and forks) pair events, `address` being the pair
+CREATE TABLE IF NOT EXISTS uniswap_v2_swap (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sender BYTEA,
+    amount0_in BYTEA,
+    amount1_in BYTEA,
+    amount0_out BYTEA,
+    amount1_out BYTEA,
+    "to" BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v2_mint (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sender BYTEA,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v2_burn (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sender BYTEA,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    "to" BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v2_sync (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    reserve0 BYTEA,
+    reserve1 BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+-- Uniswap V3 (and forks) pool events, `address` being the pool; amounts are little endian
+-- 32 bytes, the signed ones (swap amounts) in two's complement
+CREATE TABLE IF NOT EXISTS uniswap_v3_swap (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sender BYTEA,
+    recipient BYTEA,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    sqrt_price_x96 BYTEA,
+    liquidity BYTEA,
+    tick INTEGER,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v3_mint (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sender BYTEA,
+    "owner" BYTEA,
+    tick_lower INTEGER,
+    tick_upper INTEGER,
+    amount BYTEA,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v3_burn (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    tick_lower INTEGER,
+    tick_upper INTEGER,
+    amount BYTEA,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v3_collect (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    recipient BYTEA,
+    tick_lower INTEGER,
+    tick_upper INTEGER,
+    amount0 BYTEA,
+    amount1 BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS uniswap_v3_initialize (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    sqrt_price_x96 BYTEA,
+    tick INTEGER,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS uniswap_v2_swap_address_index ON uniswap_v2_swap (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_swap_block_number_index ON uniswap_v2_swap (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_mint_address_index ON uniswap_v2_mint (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_mint_block_number_index ON uniswap_v2_mint (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_burn_address_index ON uniswap_v2_burn (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_burn_block_number_index ON uniswap_v2_burn (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_sync_address_index ON uniswap_v2_sync (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v2_sync_block_number_index ON uniswap_v2_sync (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_swap_address_index ON uniswap_v3_swap (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_swap_block_number_index ON uniswap_v3_swap (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_mint_address_index ON uniswap_v3_mint (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_mint_block_number_index ON uniswap_v3_mint (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_burn_address_index ON uniswap_v3_burn (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_burn_block_number_index ON uniswap_v3_burn (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_collect_address_index ON uniswap_v3_collect (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_collect_block_number_index ON uniswap_v3_collect (block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_initialize_address_index ON uniswap_v3_initialize (address, block_number);
+CREATE INDEX IF NOT EXISTS uniswap_v3_initialize_block_number_index ON uniswap_v3_initialize (block_number);
+---
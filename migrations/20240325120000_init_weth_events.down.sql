@@ -0,0 +1,9 @@
+-- indexes
+DROP INDEX IF EXISTS weth_deposit_dst_index;
+DROP INDEX IF EXISTS weth_deposit_block_number_index;
+DROP INDEX IF EXISTS weth_withdrawal_src_index;
+DROP INDEX IF EXISTS weth_withdrawal_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS weth_deposit;
+DROP TABLE IF EXISTS weth_withdrawal;
@@ -0,0 +1,45 @@
+/*
+ * Tables
+ */
+-- wrapped native token (WETH, WMATIC, WBNB, ...) events, `address` being the token
+CREATE TABLE IF NOT EXISTS weth_deposit (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    dst BYTEA,
+    wad BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS weth_withdrawal (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    src BYTEA,
+    wad BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS weth_deposit_dst_index ON weth_deposit (dst);
+CREATE INDEX IF NOT EXISTS weth_deposit_block_number_index ON weth_deposit (block_number);
+CREATE INDEX IF NOT EXISTS weth_withdrawal_src_index ON weth_withdrawal (src);
+CREATE INDEX IF NOT EXISTS weth_withdrawal_block_number_index ON weth_withdrawal (block_number);
+---
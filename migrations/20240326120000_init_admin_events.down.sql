@@ -0,0 +1,24 @@
+-- indexes
+DROP INDEX IF EXISTS eip1967_upgraded_address_index;
+DROP INDEX IF EXISTS eip1967_upgraded_block_number_index;
+DROP INDEX IF EXISTS eip1967_admin_changed_address_index;
+DROP INDEX IF EXISTS eip1967_admin_changed_block_number_index;
+DROP INDEX IF EXISTS eip1967_beacon_upgraded_address_index;
+DROP INDEX IF EXISTS eip1967_beacon_upgraded_block_number_index;
+DROP INDEX IF EXISTS ownable_ownership_transferred_address_index;
+DROP INDEX IF EXISTS ownable_ownership_transferred_new_owner_index;
+DROP INDEX IF EXISTS access_control_role_granted_address_index;
+DROP INDEX IF EXISTS access_control_role_granted_account_index;
+DROP INDEX IF EXISTS access_control_role_revoked_address_index;
+DROP INDEX IF EXISTS access_control_role_revoked_account_index;
+DROP INDEX IF EXISTS access_control_role_admin_changed_address_index;
+DROP INDEX IF EXISTS access_control_role_admin_changed_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS eip1967_upgraded;
+DROP TABLE IF EXISTS eip1967_admin_changed;
+DROP TABLE IF EXISTS eip1967_beacon_upgraded;
+DROP TABLE IF EXISTS ownable_ownership_transferred;
+DROP TABLE IF EXISTS access_control_role_granted;
+DROP TABLE IF EXISTS access_control_role_revoked;
+DROP TABLE IF EXISTS access_control_role_admin_changed;
@@ -0,0 +1,137 @@
+/*
+ * Tables
+ */
+-- EIP-1967 proxy, Ownable and AccessControl events, `address` being the contract whose control
+-- changed
+CREATE TABLE IF NOT EXISTS eip1967_upgraded (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    implementation BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS eip1967_admin_changed (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    previous_admin BYTEA,
+    new_admin BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS eip1967_beacon_upgraded (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    beacon BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS ownable_ownership_transferred (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    previous_owner BYTEA,
+    new_owner BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS access_control_role_granted (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    role BYTEA,
+    account BYTEA,
+    sender BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS access_control_role_revoked (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    role BYTEA,
+    account BYTEA,
+    sender BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS access_control_role_admin_changed (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    role BYTEA,
+    previous_admin_role BYTEA,
+    new_admin_role BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS eip1967_upgraded_address_index ON eip1967_upgraded (address, block_number);
+CREATE INDEX IF NOT EXISTS eip1967_upgraded_block_number_index ON eip1967_upgraded (block_number);
+CREATE INDEX IF NOT EXISTS eip1967_admin_changed_address_index ON eip1967_admin_changed (address, block_number);
+CREATE INDEX IF NOT EXISTS eip1967_admin_changed_block_number_index ON eip1967_admin_changed (block_number);
+CREATE INDEX IF NOT EXISTS eip1967_beacon_upgraded_address_index ON eip1967_beacon_upgraded (address, block_number);
+CREATE INDEX IF NOT EXISTS eip1967_beacon_upgraded_block_number_index ON eip1967_beacon_upgraded (block_number);
+CREATE INDEX IF NOT EXISTS ownable_ownership_transferred_address_index ON ownable_ownership_transferred (address, block_number);
+CREATE INDEX IF NOT EXISTS ownable_ownership_transferred_new_owner_index ON ownable_ownership_transferred (new_owner);
+CREATE INDEX IF NOT EXISTS access_control_role_granted_address_index ON access_control_role_granted (address, block_number);
+CREATE INDEX IF NOT EXISTS access_control_role_granted_account_index ON access_control_role_granted (account);
+CREATE INDEX IF NOT EXISTS access_control_role_revoked_address_index ON access_control_role_revoked (address, block_number);
+CREATE INDEX IF NOT EXISTS access_control_role_revoked_account_index ON access_control_role_revoked (account);
+CREATE INDEX IF NOT EXISTS access_control_role_admin_changed_address_index ON access_control_role_admin_changed (address, block_number);
+CREATE INDEX IF NOT EXISTS access_control_role_admin_changed_block_number_index ON access_control_role_admin_changed (block_number);
+---
@@ -0,0 +1,8 @@
+-- indexes
+DROP INDEX IF EXISTS user_operation_sender_index;
+DROP INDEX IF EXISTS user_operation_paymaster_index;
+DROP INDEX IF EXISTS user_operation_tx_hash_index;
+DROP INDEX IF EXISTS user_operation_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS user_operation;
@@ -0,0 +1,40 @@
+/*
+ * Tables
+ */
+-- ERC-4337 user operations, folded from the EntryPoint's `UserOperationEvent`, `AccountDeployed`,
+-- `UserOperationRevertReason` and `PostOpRevertReason` events
+CREATE TABLE IF NOT EXISTS user_operation (
+    network network_type NOT NULL,
+    entry_point BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    -- of the `UserOperationEvent`, NULL until it's been collected
+    log_index BIGINT,
+    user_op_hash BYTEA NOT NULL,
+    sender BYTEA NOT NULL,
+    paymaster BYTEA,
+    nonce BYTEA,
+    success BOOLEAN,
+    actual_gas_cost BYTEA,
+    actual_gas_used BYTEA,
+    -- set for the user operations deploying their account
+    factory BYTEA,
+    revert_reason BYTEA,
+    post_op_revert_reason BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, user_op_hash)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS user_operation_sender_index ON user_operation (sender);
+CREATE INDEX IF NOT EXISTS user_operation_paymaster_index ON user_operation (paymaster);
+CREATE INDEX IF NOT EXISTS user_operation_tx_hash_index ON user_operation (tx_hash);
+CREATE INDEX IF NOT EXISTS user_operation_block_number_index ON user_operation (block_number);
+---
@@ -0,0 +1,19 @@
+-- indexes
+DROP INDEX IF EXISTS erc4906_metadata_update_address_index;
+DROP INDEX IF EXISTS erc4906_metadata_update_block_number_index;
+DROP INDEX IF EXISTS erc4906_batch_metadata_update_address_index;
+DROP INDEX IF EXISTS erc4906_batch_metadata_update_block_number_index;
+DROP INDEX IF EXISTS erc5192_locked_token_index;
+DROP INDEX IF EXISTS erc5192_locked_block_number_index;
+DROP INDEX IF EXISTS erc5192_unlocked_token_index;
+DROP INDEX IF EXISTS erc5192_unlocked_block_number_index;
+DROP INDEX IF EXISTS erc6551_account_created_account_index;
+DROP INDEX IF EXISTS erc6551_account_created_token_index;
+DROP INDEX IF EXISTS erc6551_account_created_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS erc4906_metadata_update;
+DROP TABLE IF EXISTS erc4906_batch_metadata_update;
+DROP TABLE IF EXISTS erc5192_locked;
+DROP TABLE IF EXISTS erc5192_unlocked;
+DROP TABLE IF EXISTS erc6551_account_created;
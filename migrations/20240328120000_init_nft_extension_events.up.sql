@@ -0,0 +1,103 @@
+/*
+ * Tables
+ */
+-- ERC-4906 metadata updates, `address` being the NFT contract
+CREATE TABLE IF NOT EXISTS erc4906_metadata_update (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    token_id BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS erc4906_batch_metadata_update (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    from_token_id BYTEA,
+    to_token_id BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+-- ERC-5192 soulbound locks, `address` being the NFT contract
+CREATE TABLE IF NOT EXISTS erc5192_locked (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    token_id BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS erc5192_unlocked (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    token_id BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+-- ERC-6551 token bound accounts, `address` being the registry
+CREATE TABLE IF NOT EXISTS erc6551_account_created (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    account BYTEA,
+    implementation BYTEA,
+    salt BYTEA,
+    chain_id BYTEA,
+    token_contract BYTEA,
+    token_id BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS erc4906_metadata_update_address_index ON erc4906_metadata_update (address, block_number);
+CREATE INDEX IF NOT EXISTS erc4906_metadata_update_block_number_index ON erc4906_metadata_update (block_number);
+CREATE INDEX IF NOT EXISTS erc4906_batch_metadata_update_address_index ON erc4906_batch_metadata_update (address, block_number);
+CREATE INDEX IF NOT EXISTS erc4906_batch_metadata_update_block_number_index ON erc4906_batch_metadata_update (block_number);
+CREATE INDEX IF NOT EXISTS erc5192_locked_token_index ON erc5192_locked (address, token_id);
+CREATE INDEX IF NOT EXISTS erc5192_locked_block_number_index ON erc5192_locked (block_number);
+CREATE INDEX IF NOT EXISTS erc5192_unlocked_token_index ON erc5192_unlocked (address, token_id);
+CREATE INDEX IF NOT EXISTS erc5192_unlocked_block_number_index ON erc5192_unlocked (block_number);
+CREATE INDEX IF NOT EXISTS erc6551_account_created_account_index ON erc6551_account_created (account);
+CREATE INDEX IF NOT EXISTS erc6551_account_created_token_index ON erc6551_account_created (token_contract, token_id);
+CREATE INDEX IF NOT EXISTS erc6551_account_created_block_number_index ON erc6551_account_created (block_number);
+---
@@ -0,0 +1,21 @@
+-- indexes
+DROP INDEX IF EXISTS allowance_owner_index;
+DROP INDEX IF EXISTS allowance_spender_index;
+DROP INDEX IF EXISTS allowance_token_index;
+DROP INDEX IF EXISTS allowance_change_allowance_index;
+DROP INDEX IF EXISTS permit2_approval_owner_index;
+DROP INDEX IF EXISTS permit2_approval_block_number_index;
+DROP INDEX IF EXISTS permit2_permit_owner_index;
+DROP INDEX IF EXISTS permit2_permit_block_number_index;
+DROP INDEX IF EXISTS permit2_lockdown_owner_index;
+DROP INDEX IF EXISTS permit2_lockdown_block_number_index;
+DROP INDEX IF EXISTS permit2_nonce_invalidation_owner_index;
+DROP INDEX IF EXISTS permit2_nonce_invalidation_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS allowance;
+DROP TABLE IF EXISTS allowance_change;
+DROP TABLE IF EXISTS permit2_approval;
+DROP TABLE IF EXISTS permit2_permit;
+DROP TABLE IF EXISTS permit2_lockdown;
+DROP TABLE IF EXISTS permit2_nonce_invalidation;
@@ -0,0 +1,137 @@
+/*
+ * Tables
+ */
+-- Uniswap Permit2 events, `address` being Permit2
+CREATE TABLE IF NOT EXISTS permit2_approval (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    token BYTEA,
+    spender BYTEA,
+    amount BYTEA,
+    expiration BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS permit2_permit (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    token BYTEA,
+    spender BYTEA,
+    amount BYTEA,
+    expiration BIGINT,
+    nonce BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS permit2_lockdown (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    token BYTEA,
+    spender BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+CREATE TABLE IF NOT EXISTS permit2_nonce_invalidation (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    "owner" BYTEA,
+    token BYTEA,
+    spender BYTEA,
+    new_nonce BIGINT,
+    old_nonce BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+-- the latest allowance `owner` gave `spender` over `token`, through the token itself (`erc20`)
+-- or Permit2 (`permit2`)
+CREATE TABLE IF NOT EXISTS allowance (
+    network network_type NOT NULL,
+    token BYTEA NOT NULL,
+    "owner" BYTEA NOT NULL,
+    spender BYTEA NOT NULL,
+    source TEXT NOT NULL,
+    amount BYTEA,
+    expiration BIGINT,
+    nonce BIGINT,
+    -- the log of the latest change
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, token, "owner", spender, source)
+);
+
+-- every change of the allowances, from which they're recomputed when one is removed by a reorg
+CREATE TABLE IF NOT EXISTS allowance_change (
+    network network_type NOT NULL,
+    token BYTEA NOT NULL,
+    "owner" BYTEA NOT NULL,
+    spender BYTEA NOT NULL,
+    source TEXT NOT NULL,
+    amount BYTEA,
+    expiration BIGINT,
+    nonce BIGINT,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index, token, "owner", spender, source)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS permit2_approval_owner_index ON permit2_approval ("owner");
+CREATE INDEX IF NOT EXISTS permit2_approval_block_number_index ON permit2_approval (block_number);
+CREATE INDEX IF NOT EXISTS permit2_permit_owner_index ON permit2_permit ("owner");
+CREATE INDEX IF NOT EXISTS permit2_permit_block_number_index ON permit2_permit (block_number);
+CREATE INDEX IF NOT EXISTS permit2_lockdown_owner_index ON permit2_lockdown ("owner");
+CREATE INDEX IF NOT EXISTS permit2_lockdown_block_number_index ON permit2_lockdown (block_number);
+CREATE INDEX IF NOT EXISTS permit2_nonce_invalidation_owner_index ON permit2_nonce_invalidation ("owner");
+CREATE INDEX IF NOT EXISTS permit2_nonce_invalidation_block_number_index ON permit2_nonce_invalidation (block_number);
+CREATE INDEX IF NOT EXISTS allowance_owner_index ON allowance ("owner");
+CREATE INDEX IF NOT EXISTS allowance_spender_index ON allowance (spender);
+CREATE INDEX IF NOT EXISTS allowance_token_index ON allowance (token);
+CREATE INDEX IF NOT EXISTS allowance_change_allowance_index ON allowance_change (network, token, "owner", spender, source, block_number);
+---
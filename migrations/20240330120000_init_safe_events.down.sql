@@ -0,0 +1,7 @@
+-- indexes
+DROP INDEX IF EXISTS safe_event_safe_index;
+DROP INDEX IF EXISTS safe_event_owner_index;
+DROP INDEX IF EXISTS safe_event_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS safe_event;
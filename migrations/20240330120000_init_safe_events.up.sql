@@ -0,0 +1,43 @@
+/*
+ * Tables
+ */
+-- the history of Safes (formerly Gnosis Safe), `address` being the emitting contract, i.e. the
+-- Safe itself or, for `proxy_creation`, the factory creating it
+CREATE TABLE IF NOT EXISTS safe_event (
+    network network_type NOT NULL,
+    safe BYTEA NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    -- execution_success, execution_failure, added_owner, removed_owner, changed_threshold,
+    -- safe_received or proxy_creation
+    kind TEXT NOT NULL,
+    -- execution_success, execution_failure
+    safe_tx_hash BYTEA,
+    payment BYTEA,
+    -- added_owner, removed_owner
+    "owner" BYTEA,
+    -- changed_threshold
+    threshold BYTEA,
+    -- safe_received
+    sender BYTEA,
+    "value" BYTEA,
+    -- proxy_creation
+    singleton BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS safe_event_safe_index ON safe_event (safe, block_number);
+CREATE INDEX IF NOT EXISTS safe_event_owner_index ON safe_event ("owner");
+CREATE INDEX IF NOT EXISTS safe_event_block_number_index ON safe_event (block_number);
+---
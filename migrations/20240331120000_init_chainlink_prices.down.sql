@@ -0,0 +1,9 @@
+-- indexes
+DROP INDEX IF EXISTS chainlink_price_feed_block_number_index;
+DROP INDEX IF EXISTS chainlink_price_feed_answered_at_index;
+DROP INDEX IF EXISTS chainlink_new_round_address_index;
+DROP INDEX IF EXISTS chainlink_new_round_block_number_index;
+
+-- tables
+DROP TABLE IF EXISTS chainlink_price;
+DROP TABLE IF EXISTS chainlink_new_round;
@@ -0,0 +1,50 @@
+/*
+ * Tables
+ */
+-- the answers of Chainlink feeds, `address` being the aggregator reporting them
+CREATE TABLE IF NOT EXISTS chainlink_price (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    feed TEXT NOT NULL,
+    decimals SMALLINT NOT NULL,
+    round_id BYTEA,
+    answer BYTEA,
+    answered_at BIGINT,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+
+-- the rounds started on the aggregators of Chainlink feeds
+CREATE TABLE IF NOT EXISTS chainlink_new_round (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    round_id BYTEA,
+    started_by BYTEA,
+    started_at BYTEA,
+    --
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS chainlink_price_feed_block_number_index ON chainlink_price (network, feed, block_number);
+CREATE INDEX IF NOT EXISTS chainlink_price_feed_answered_at_index ON chainlink_price (network, feed, answered_at);
+CREATE INDEX IF NOT EXISTS chainlink_new_round_address_index ON chainlink_new_round (address, block_number);
+CREATE INDEX IF NOT EXISTS chainlink_new_round_block_number_index ON chainlink_new_round (block_number);
+---
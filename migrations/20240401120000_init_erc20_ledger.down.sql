@@ -0,0 +1,14 @@
+-- views
+DROP VIEW IF EXISTS erc20_supply;
+
+-- indexes
+DROP INDEX IF EXISTS erc20_balance_change_token_holder_index;
+DROP INDEX IF EXISTS erc20_balance_change_holder_index;
+DROP INDEX IF EXISTS erc20_balance_change_token_index;
+DROP INDEX IF EXISTS erc20_balance_change_block_index;
+DROP INDEX IF EXISTS erc20_balance_holder_index;
+DROP INDEX IF EXISTS erc20_balance_token_balance_index;
+
+-- tables
+DROP TABLE IF EXISTS erc20_balance;
+DROP TABLE IF EXISTS erc20_balance_change;
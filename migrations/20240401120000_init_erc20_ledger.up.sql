@@ -0,0 +1,51 @@
+/*
+ * Tables
+ */
+-- the change of a holder's balance by a transfer, from which balances at any block are summed
+CREATE TABLE IF NOT EXISTS erc20_balance_change (
+    network network_type NOT NULL,
+    token BYTEA NOT NULL,
+    holder BYTEA NOT NULL,
+    -- the changes of a block replaced by a reorg are told apart by its hash
+    block_hash BYTEA NOT NULL,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    delta NUMERIC(78, 0) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index, holder, block_hash)
+);
+---
+
+-- the latest balances, the zero address holding the opposite of the supply
+CREATE TABLE IF NOT EXISTS erc20_balance (
+    network network_type NOT NULL,
+    token BYTEA NOT NULL,
+    holder BYTEA NOT NULL,
+    balance NUMERIC(78, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, token, holder)
+);
+---
+
+/*
+ * Views
+ */
+CREATE OR REPLACE VIEW erc20_supply AS
+    SELECT network, token, -balance AS supply, updated_at
+    FROM erc20_balance
+    WHERE holder = '\x0000000000000000000000000000000000000000';
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS erc20_balance_change_token_holder_index ON erc20_balance_change (network, token, holder, block_number);
+CREATE INDEX IF NOT EXISTS erc20_balance_change_holder_index ON erc20_balance_change (network, holder, block_number);
+CREATE INDEX IF NOT EXISTS erc20_balance_change_token_index ON erc20_balance_change (network, token, block_number);
+CREATE INDEX IF NOT EXISTS erc20_balance_change_block_index ON erc20_balance_change (network, block_number);
+CREATE INDEX IF NOT EXISTS erc20_balance_holder_index ON erc20_balance (network, holder);
+CREATE INDEX IF NOT EXISTS erc20_balance_token_balance_index ON erc20_balance (network, token, balance DESC);
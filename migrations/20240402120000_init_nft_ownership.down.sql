@@ -0,0 +1,9 @@
+-- indexes
+DROP INDEX IF EXISTS nft_transfer_token_index;
+DROP INDEX IF EXISTS nft_transfer_from_index;
+DROP INDEX IF EXISTS nft_transfer_to_index;
+DROP INDEX IF EXISTS nft_owner_owner_index;
+
+-- tables
+DROP TABLE IF EXISTS nft_owner;
+DROP TABLE IF EXISTS nft_transfer;
@@ -0,0 +1,46 @@
+/*
+ * Tables
+ */
+-- the history of ERC721 and ERC1155 tokens, one row per token moved by a transfer
+CREATE TABLE IF NOT EXISTS nft_transfer (
+    network network_type NOT NULL,
+    contract BYTEA NOT NULL,
+    token_id NUMERIC(78, 0) NOT NULL,
+    standard TEXT NOT NULL,
+    operator BYTEA,
+    "from" BYTEA NOT NULL,
+    "to" BYTEA NOT NULL,
+    quantity NUMERIC(78, 0) NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    -- the position of the token within an ERC1155 `TransferBatch`
+    batch_index INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index, batch_index, block_hash)
+);
+---
+
+-- the current owners of each token, with the quantity they hold
+CREATE TABLE IF NOT EXISTS nft_owner (
+    network network_type NOT NULL,
+    contract BYTEA NOT NULL,
+    token_id NUMERIC(78, 0) NOT NULL,
+    "owner" BYTEA NOT NULL,
+    quantity NUMERIC(78, 0) NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, contract, token_id, "owner")
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS nft_transfer_token_index ON nft_transfer (network, contract, token_id, block_number);
+CREATE INDEX IF NOT EXISTS nft_transfer_from_index ON nft_transfer ("from");
+CREATE INDEX IF NOT EXISTS nft_transfer_to_index ON nft_transfer ("to");
+CREATE INDEX IF NOT EXISTS nft_owner_owner_index ON nft_owner ("owner", network, contract);
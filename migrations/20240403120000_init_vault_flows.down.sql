@@ -0,0 +1,10 @@
+-- views
+DROP VIEW IF EXISTS vault_share_price;
+DROP VIEW IF EXISTS vault_total;
+
+-- indexes
+DROP INDEX IF EXISTS vault_flow_vault_index;
+DROP INDEX IF EXISTS vault_flow_owner_index;
+
+-- tables
+DROP TABLE IF EXISTS vault_flow;
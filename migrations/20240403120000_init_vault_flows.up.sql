@@ -0,0 +1,60 @@
+/*
+ * Tables
+ */
+-- the deposits into and withdrawals from ERC4626 vaults, amounts being unsigned
+CREATE TABLE IF NOT EXISTS vault_flow (
+    network network_type NOT NULL,
+    vault BYTEA NOT NULL,
+    direction TEXT NOT NULL,
+    sender BYTEA NOT NULL,
+    "receiver" BYTEA,
+    "owner" BYTEA NOT NULL,
+    "assets" NUMERIC(78, 0) NOT NULL,
+    shares NUMERIC(78, 0) NOT NULL,
+    block_hash BYTEA,
+    block_number BIGINT,
+    tx_hash BYTEA,
+    log_index BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, tx_hash, log_index, block_hash)
+);
+---
+
+/*
+ * Views
+ */
+-- the assets and shares each vault has taken in and paid out
+CREATE OR REPLACE VIEW vault_total AS
+    SELECT
+        network,
+        vault,
+        COALESCE(SUM("assets") FILTER (WHERE direction = 'deposit'), 0) AS assets_deposited,
+        COALESCE(SUM("assets") FILTER (WHERE direction = 'withdraw'), 0) AS assets_withdrawn,
+        COALESCE(SUM(shares) FILTER (WHERE direction = 'deposit'), 0) AS shares_minted,
+        COALESCE(SUM(shares) FILTER (WHERE direction = 'withdraw'), 0) AS shares_burned,
+        COUNT(*) FILTER (WHERE direction = 'deposit') AS deposits,
+        COUNT(*) FILTER (WHERE direction = 'withdraw') AS withdrawals,
+        MAX(block_number) AS block_number
+    FROM vault_flow
+    GROUP BY network, vault;
+---
+
+-- the assets paid per share by the flows of each block, in the assets' and shares' base units
+CREATE OR REPLACE VIEW vault_share_price AS
+    SELECT
+        network,
+        vault,
+        block_number,
+        SUM("assets") AS "assets",
+        SUM(shares) AS shares,
+        trim_scale(ROUND(SUM("assets") / NULLIF(SUM(shares), 0), 18)) AS share_price
+    FROM vault_flow
+    GROUP BY network, vault, block_number;
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS vault_flow_vault_index ON vault_flow (network, vault, block_number);
+CREATE INDEX IF NOT EXISTS vault_flow_owner_index ON vault_flow ("owner", network, vault);
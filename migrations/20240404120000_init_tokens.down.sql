@@ -0,0 +1,2 @@
+-- tables
+DROP TABLE IF EXISTS token;
@@ -0,0 +1,18 @@
+/*
+ * Tables
+ */
+-- the metadata of the token contracts, as resolved from the contracts the first time they emit a known event
+CREATE TABLE IF NOT EXISTS token (
+    network network_type NOT NULL,
+    address BYTEA NOT NULL,
+    standard TEXT,
+    name TEXT,
+    symbol TEXT,
+    decimals SMALLINT,
+    total_supply NUMERIC(78, 0),
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, address)
+);
+---
@@ -0,0 +1,5 @@
+-- indexes
+DROP INDEX IF EXISTS nft_metadata_pending_index;
+
+-- tables
+DROP TABLE IF EXISTS nft_metadata;
@@ -0,0 +1,29 @@
+/*
+ * Tables
+ */
+-- the JSON metadata of ERC721 and ERC1155 tokens, fetched from the URI each token points to
+CREATE TABLE IF NOT EXISTS nft_metadata (
+    network network_type NOT NULL,
+    contract BYTEA NOT NULL,
+    token_id NUMERIC(78, 0) NOT NULL,
+    standard TEXT NOT NULL,
+    -- as returned by `tokenURI`/`uri` or emitted by `URI`, before the `{id}` substitution
+    uri TEXT,
+    -- `pending` until fetched, `failed` once given up on
+    status TEXT NOT NULL DEFAULT 'pending',
+    metadata JSONB,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    error TEXT,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    fetched_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+
+    UNIQUE(network, contract, token_id)
+);
+---
+
+/*
+ * Indexes
+ */
+CREATE INDEX IF NOT EXISTS nft_metadata_pending_index ON nft_metadata (network, next_attempt_at) WHERE status = 'pending';
@@ -0,0 +1,5 @@
+UPDATE erc20_approval SET "value" = (
+    SELECT STRING_AGG(SUBSTRING("value" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("value")) AS i
+)
+WHERE "value" IS NOT NULL;
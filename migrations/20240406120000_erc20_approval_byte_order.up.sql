@@ -0,0 +1,8 @@
+-- ERC20 approval values used to be read from the logs little endian, the (big endian) bytes of
+-- the logs thus being stored as they are; they're now read big endian and stored little endian
+-- like every other amount, so the bytes of the values stored until now are reversed
+UPDATE erc20_approval SET "value" = (
+    SELECT STRING_AGG(SUBSTRING("value" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("value")) AS i
+)
+WHERE "value" IS NOT NULL;
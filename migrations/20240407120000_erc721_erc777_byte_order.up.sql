@@ -0,0 +1,24 @@
+-- ERC721 approval tokenIds and ERC777 amounts used to be read from the logs little endian, the
+-- (big endian) bytes of the logs thus being stored as they are; they're now read big endian and
+-- stored little endian like every other amount, so the bytes of the values stored until now are
+-- reversed
+UPDATE erc721_approval SET token_id = (
+    SELECT STRING_AGG(SUBSTRING(token_id FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(token_id)) AS i
+)
+WHERE token_id IS NOT NULL;
+UPDATE erc777_sent SET amount = (
+    SELECT STRING_AGG(SUBSTRING(amount FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(amount)) AS i
+)
+WHERE amount IS NOT NULL;
+UPDATE erc777_minted SET amount = (
+    SELECT STRING_AGG(SUBSTRING(amount FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(amount)) AS i
+)
+WHERE amount IS NOT NULL;
+UPDATE erc777_burned SET amount = (
+    SELECT STRING_AGG(SUBSTRING(amount FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(amount)) AS i
+)
+WHERE amount IS NOT NULL;
@@ -0,0 +1,35 @@
+-- ERC721 transfer tokenIds and ERC1155 transfer ids and values used to be read from the logs
+-- little endian, the (big endian) bytes of the logs thus being stored as they are; they're now
+-- read big endian and stored little endian like every other amount, so the bytes of the values
+-- stored until now are reversed
+UPDATE erc721_transfer SET token_id = (
+    SELECT STRING_AGG(SUBSTRING(token_id FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(token_id)) AS i
+)
+WHERE token_id IS NOT NULL;
+UPDATE erc1155_transfer_single SET id = (
+    SELECT STRING_AGG(SUBSTRING(id FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(id)) AS i
+)
+WHERE id IS NOT NULL;
+UPDATE erc1155_transfer_single SET "value" = (
+    SELECT STRING_AGG(SUBSTRING("value" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("value")) AS i
+)
+WHERE "value" IS NOT NULL;
+UPDATE erc1155_transfer_batch SET ids = COALESCE((
+    SELECT ARRAY_AGG((
+        SELECT STRING_AGG(SUBSTRING(element FROM i FOR 1), ''::bytea ORDER BY i DESC)
+        FROM GENERATE_SERIES(1, LENGTH(element)) AS i
+    ) ORDER BY n)
+    FROM UNNEST(ids) WITH ORDINALITY AS elements(element, n)
+), '{}')
+WHERE ids IS NOT NULL;
+UPDATE erc1155_transfer_batch SET "values" = COALESCE((
+    SELECT ARRAY_AGG((
+        SELECT STRING_AGG(SUBSTRING(element FROM i FOR 1), ''::bytea ORDER BY i DESC)
+        FROM GENERATE_SERIES(1, LENGTH(element)) AS i
+    ) ORDER BY n)
+    FROM UNNEST("values") WITH ORDINALITY AS elements(element, n)
+), '{}')
+WHERE "values" IS NOT NULL;
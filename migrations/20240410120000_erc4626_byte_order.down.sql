@@ -0,0 +1,20 @@
+UPDATE erc4626_deposit SET "assets" = (
+    SELECT STRING_AGG(SUBSTRING("assets" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("assets")) AS i
+)
+WHERE "assets" IS NOT NULL;
+UPDATE erc4626_deposit SET shares = (
+    SELECT STRING_AGG(SUBSTRING(shares FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(shares)) AS i
+)
+WHERE shares IS NOT NULL;
+UPDATE erc4626_withdraw SET "assets" = (
+    SELECT STRING_AGG(SUBSTRING("assets" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("assets")) AS i
+)
+WHERE "assets" IS NOT NULL;
+UPDATE erc4626_withdraw SET shares = (
+    SELECT STRING_AGG(SUBSTRING(shares FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(shares)) AS i
+)
+WHERE shares IS NOT NULL;
@@ -0,0 +1,24 @@
+-- ERC4626 deposit and withdrawal assets and shares used to be read from the logs little endian,
+-- the (big endian) bytes of the logs thus being stored as they are; they're now read big endian
+-- and stored little endian like every other amount, so the bytes of the values stored until now
+-- are reversed
+UPDATE erc4626_deposit SET "assets" = (
+    SELECT STRING_AGG(SUBSTRING("assets" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("assets")) AS i
+)
+WHERE "assets" IS NOT NULL;
+UPDATE erc4626_deposit SET shares = (
+    SELECT STRING_AGG(SUBSTRING(shares FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(shares)) AS i
+)
+WHERE shares IS NOT NULL;
+UPDATE erc4626_withdraw SET "assets" = (
+    SELECT STRING_AGG(SUBSTRING("assets" FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH("assets")) AS i
+)
+WHERE "assets" IS NOT NULL;
+UPDATE erc4626_withdraw SET shares = (
+    SELECT STRING_AGG(SUBSTRING(shares FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(shares)) AS i
+)
+WHERE shares IS NOT NULL;
@@ -0,0 +1,5 @@
+UPDATE erc1155_uri SET id = (
+    SELECT STRING_AGG(SUBSTRING(id FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(id)) AS i
+)
+WHERE id IS NOT NULL;
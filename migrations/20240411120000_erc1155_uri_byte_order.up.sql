@@ -0,0 +1,8 @@
+-- ERC1155 URI ids used to be read from the logs little endian, the (big endian) bytes of the
+-- logs thus being stored as they are; they're now read big endian and stored little endian like
+-- every other amount, so the bytes of the ids stored until now are reversed
+UPDATE erc1155_uri SET id = (
+    SELECT STRING_AGG(SUBSTRING(id FROM i FOR 1), ''::bytea ORDER BY i DESC)
+    FROM GENERATE_SERIES(1, LENGTH(id)) AS i
+)
+WHERE id IS NOT NULL;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS nft_transfer_block_index;
+
+ALTER TABLE nft_transfer ALTER COLUMN block_hash DROP NOT NULL;
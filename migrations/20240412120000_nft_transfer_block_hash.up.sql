@@ -0,0 +1,27 @@
+-- transfers of pending logs (i.e. without a block hash) are no longer recorded, as they'd be
+-- applied again once mined; the ones recorded until now are deleted and reverted
+WITH transfer AS (
+    DELETE FROM nft_transfer
+    WHERE block_hash IS NULL
+    RETURNING network, contract, token_id, "from", "to", quantity
+), change AS (
+    SELECT network, contract, token_id, "owner", SUM(delta) AS delta FROM (
+        SELECT network, contract, token_id, "from" AS "owner", -quantity AS delta FROM transfer
+        UNION ALL
+        SELECT network, contract, token_id, "to", quantity FROM transfer
+    ) c
+    GROUP BY network, contract, token_id, "owner"
+)
+UPDATE nft_owner o SET
+    quantity = o.quantity - change.delta,
+    updated_at = NOW()
+FROM change
+WHERE o.network = change.network
+    AND o.contract = change.contract
+    AND o.token_id = change.token_id
+    AND o."owner" = change."owner";
+
+ALTER TABLE nft_transfer ALTER COLUMN block_hash SET NOT NULL;
+
+-- the transfers of a replaced block are looked up by block number
+CREATE INDEX IF NOT EXISTS nft_transfer_block_index ON nft_transfer (network, block_number);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS vault_flow_block_index;
+
+ALTER TABLE vault_flow ALTER COLUMN block_hash DROP NOT NULL;
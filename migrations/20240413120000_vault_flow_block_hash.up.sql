@@ -0,0 +1,8 @@
+-- flows of pending logs (i.e. without a block hash) are no longer recorded, as they'd be counted
+-- again once mined; the ones recorded until now are deleted
+DELETE FROM vault_flow WHERE block_hash IS NULL;
+
+ALTER TABLE vault_flow ALTER COLUMN block_hash SET NOT NULL;
+
+-- the flows of a replaced block are looked up by block number
+CREATE INDEX IF NOT EXISTS vault_flow_block_index ON vault_flow (network, block_number);
@@ -2,7 +2,8 @@
 Function calls in the Ethereum Virtual Machine are specified by the first four bytes of data sent with a transaction.
 These 4-byte signatures are defined as the first four bytes of the Keccak hash (SHA3) of the canonical representation of the function signature.
 
-Fetch all submitted/well-known Function Signatures from the public database https://4byte.directory & save them in a csv that'll be imported during the initial execution of the eventify binary (or db_init).
+Fetch all submitted/well-known Function Signatures from the public database https://4byte.directory & save them in a csv that's loaded with `eventify fn-sig import`.
+With `--events`, fetch the Event Signatures instead (keyed by the full 32-byte topic0), loaded with `eventify event-sig import`.
 ]]
 
 function script_directory()
@@ -24,13 +25,18 @@ local argparse = require("argparse")
 local parser = argparse("Fetch https://4byte.directory Function Signatures into a csv")
 parser:option("-f --from-page", "Start from page", "1")
 parser:option("-t --to-page", "End at page", "10000000")
-parser:option("-o --output", "Output file",
-    string.format("%s../etc/data/fn-sig.csv", script_directory()))
+parser:option("-o --output", "Output file")
+parser:flag("-e --events", "Fetch event signatures instead of function signatures")
 local args = parser:parse()
+
+local resource = args.events and "event-signatures" or "signatures"
+if args.output == nil then
+    args.output = string.format("%s../etc/data/%s.csv", script_directory(), args.events and "event-sig" or "fn-sig")
+end
 print(string.format("Fetching pages %s - %s into file '%s'.", args.from_page, args.to_page, args.output))
 
 --- setup the starting point
-ENDPOINT = "http://www.4byte.directory:443/api/v1/signatures/?page=" .. args.from_page
+ENDPOINT = "http://www.4byte.directory:443/api/v1/" .. resource .. "/?page=" .. args.from_page
 
 --- cleanup the output file
 io.open(args.output, "w"):close()
@@ -54,7 +60,7 @@ while true do
     end
     file:close()
 
-    if json_data["next"] == nil or json_data["next"] == "http://www.4byte.directory:443/api/v1/signatures/?page=" .. args.to_page then
+    if json_data["next"] == nil or json_data["next"] == "http://www.4byte.directory:443/api/v1/" .. resource .. "/?page=" .. args.to_page then
         print("reached last page")
         break
     end